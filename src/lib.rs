@@ -11,13 +11,33 @@
 //! - 🔍 Parses `git clone` compatible urls into [`GitUrl`](crate::types::GitUrl)
 //!   - Supports multiple Git URL schemes (SSH, HTTP, HTTPS, File)
 //!   - Inspired by [RFC 3986](https://datatracker.ietf.org/doc/html/rfc3986) with adaptations to support Git urls
+//!   - Typed [`Transport`](crate::types::Transport) parts for Radicle and Keybase urls
 //!
 //! - 🏗️ Host provider info extraction
 //!   - Easy to implement trait [`GitProvider`](crate::types::provider::GitProvider) for custom provider parsing
 //!   - Built-in support for multiple Git hosting providers
 //!       * [Generic](crate::types::provider::generic::GenericProvider) (`git@host:owner/repo.git` style urls)
+//!       * [GitHub](crate::types::provider::github::GitHubProvider)
+//!       * [Gitea / Forgejo / Codeberg](crate::types::provider::gitea::GiteaProvider)
 //!       * [GitLab](crate::types::provider::gitlab::GitLabProvider)
 //!       * [Azure DevOps](crate::types::provider::azure_devops::AzureDevOpsProvider)
+//!       * [AWS CodeCommit](crate::types::provider::codecommit::CodeCommitProvider)
+//!       * [Gerrit](crate::types::provider::gerrit::GerritProvider)
+//!       * [Google Cloud Source Repositories / Secure Source Manager](crate::types::provider::google_source::GoogleSourceProvider)
+//!       * [Hugging Face Hub](crate::types::provider::huggingface::HuggingFaceProvider)
+//!       * [cgit / gitweb](crate::types::provider::cgit::CgitProvider)
+//!       * [Launchpad](crate::types::provider::launchpad::LaunchpadProvider)
+//!       * [Pagure / Fedora dist-git](crate::types::provider::pagure::PagureProvider)
+//!       * [SourceHut](crate::types::provider::sourcehut::SourceHutProvider)
+//!       * [Bitbucket Cloud](crate::types::provider::bitbucket::BitbucketCloudProvider) and [Bitbucket Server](crate::types::provider::bitbucket::BitbucketServerProvider)
+//!   - Auto-detect the built-in provider with [`GitUrl::detect_provider()`](crate::types::GitUrl::detect_provider)
+//!   - Map self-hosted instances to providers with [`ProviderRegistry`](crate::types::provider::ProviderRegistry)
+//!   - Declare providers from path patterns with [`PatternProvider`](crate::types::provider::PatternProvider)
+//!   - Render provider info back into clone urls with [`ToGitUrl`](crate::types::provider::ToGitUrl)
+//!   - Link to files, commits and comparisons with [`ToWebUrl`](crate::types::provider::ToWebUrl)
+//!   - Download single files without cloning from [`raw_file_url()`](crate::types::provider::ToRawFileUrl::raw_file_url) urls
+//!   - Derive REST API endpoints of repos, branches, pull requests and files with [`ToApiUrl`](crate::types::provider::ToApiUrl)
+//!   - Split pasted browser links into clone url, ref, path and lines with [`WebLink`](crate::types::provider::WebLink)
 //!
 //! ## Quick Example
 //!
@@ -87,6 +107,7 @@
 //! Enable for internal `debug!` output from [log](https://docs.rs/log/latest)
 //! #### `serde`
 //! Enable for [serde](https://docs.rs/serde/latest/) `Serialize`/`Deserialize` on [`GitUrl`](crate::types::GitUrl)
+//! and provider types. A [`ProviderRegistry`](crate::types::provider::ProviderRegistry) can be loaded from a config file
 //! #### `url`
 //! (**enabled by default**)
 //!
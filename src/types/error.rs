@@ -32,10 +32,38 @@ pub enum GitUrlParseError {
     #[error("Filelike urls expect only scheme and/or path")]
     InvalidFilePattern,
 
+    /// `GitUrl` is not file-like, and has no filesystem path
+    #[error("GitUrl is not a file path")]
+    FilePathUnsupported,
+
+    /// Filesystem path can not be represented as a `GitUrl`
+    #[error("Invalid file path: {0}")]
+    InvalidFilePath(String),
+
+    /// Radicle or Keybase url doesn't follow the layout of the transport
+    #[error("Invalid transport url: {0}")]
+    InvalidTransport(String),
+
     /// `GitUrl`not supported by the [`GitProvider`](crate::types::provider::GitProvider)
     #[error("GitUrl not supported by provider")]
     ProviderUnsupported,
 
+    /// Owner, group or repo name doesn't follow the naming rules of the provider
+    #[error("Invalid name for provider: {0}")]
+    ProviderInvalidName(String),
+
+    /// Owner, group or repo name is reserved by the provider
+    #[error("Reserved name for provider: {0}")]
+    ProviderReservedName(String),
+
+    /// Host pattern for the provider registry could not be parsed
+    #[error("Invalid host pattern: {0}")]
+    InvalidHostPattern(String),
+
+    /// Path pattern for a pattern provider could not be parsed
+    #[error("Invalid path pattern: {0}")]
+    InvalidPathPattern(String),
+
     /// Detected null bytes in the input url
     #[error("Found null bytes within input url before parsing")]
     FoundNullBytes,
@@ -4,14 +4,19 @@
 //!
 
 mod error;
+mod path;
 mod spec;
 use spec::*;
 pub mod provider;
+mod transport;
 
 pub use error::GitUrlParseError;
+pub use path::FilePathKind;
+pub use transport::{KeybaseTransport, KeybaseVisibility, RadicleTransport, Transport};
 
 use core::str;
 use std::fmt;
+use std::path::{Path, PathBuf};
 use url::Url;
 
 use getset::{CopyGetters, Getters, Setters};
@@ -146,6 +151,10 @@ impl GitUrl {
         };
 
         let (port, path) = match (self.hint(), self.port(), self.path()) {
+            // Http paths already start with `/`
+            (GitUrlParseHint::Httplike, Some(port), path) if path.starts_with('/') => {
+                (format!(":{port}"), path.to_string())
+            }
             (GitUrlParseHint::Httplike, Some(port), path) => {
                 (format!(":{port}"), format!("/{path}"))
             }
@@ -206,6 +215,31 @@ impl GitUrl {
             return Err(GitUrlParseError::FoundNullBytes);
         }
 
+        // Windows paths don't follow url patterns, so they are identified before url parsing
+        if let Some((path, print_scheme)) = path::windows_file_path(input) {
+            git_url_result.set_scheme(Some("file".to_string()));
+            git_url_result.set_path(path.to_string());
+            git_url_result.set_print_scheme(print_scheme);
+            git_url_result.set_hint(GitUrlParseHint::Filelike);
+
+            git_url_result.is_valid()?;
+
+            return Ok(git_url_result);
+        }
+
+        // Radicle urls may not have a path, so they are identified before url parsing
+        if let Some((rid, nid)) = transport::radicle_url(input) {
+            git_url_result.set_scheme(Some(transport::RADICLE_SCHEME.to_string()));
+            git_url_result.set_host(Some(rid.to_string()));
+            git_url_result.set_path(nid.map(|nid| format!("/{nid}")).unwrap_or_default());
+            git_url_result.set_print_scheme(true);
+            git_url_result.set_hint(GitUrlParseHint::Httplike);
+
+            git_url_result.is_valid()?;
+
+            return Ok(git_url_result);
+        }
+
         let (_input, url_spec_parser) = UrlSpecParser::parse(input).finish().unwrap_or_default();
 
         let scheme = url_spec_parser.scheme();
@@ -258,11 +292,21 @@ impl GitUrl {
         // Skip the first character
         if hint == GitUrlParseHint::Sshlike {
             git_url_result.set_scheme(Some("ssh".to_string()));
-            git_url_result.set_path(path[1..].to_string());
+            let mut path_chars = path.chars();
+            path_chars.next();
+            git_url_result.set_path(path_chars.as_str().to_string());
         }
 
         if hint == GitUrlParseHint::Filelike {
             git_url_result.set_scheme(Some("file".to_string()));
+
+            // `file://localhost/path` refers to the local machine, same as `file:///path`
+            if host
+                .as_ref()
+                .is_some_and(|h| h.eq_ignore_ascii_case("localhost"))
+            {
+                git_url_result.set_host(None);
+            }
         }
 
         git_url_result.set_print_scheme(print_scheme);
@@ -273,6 +317,208 @@ impl GitUrl {
         Ok(git_url_result)
     }
 
+    /// The style of filesystem path, if `GitUrl` is file-like
+    ///
+    /// ```
+    /// use git_url_parse::GitUrl;
+    /// use git_url_parse::types::FilePathKind;
+    ///
+    /// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+    /// let file_url = GitUrl::parse(r"..\path\to\repo.git")?;
+    /// assert_eq!(file_url.file_path_kind(), Some(FilePathKind::WindowsRelative));
+    ///
+    /// let ssh_url = GitUrl::parse("git@github.com:tjtelan/git-url-parse-rs.git")?;
+    /// assert_eq!(ssh_url.file_path_kind(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn file_path_kind(&self) -> Option<FilePathKind> {
+        if self.hint() == GitUrlParseHint::Filelike {
+            Some(FilePathKind::from_path(self.path()))
+        } else {
+            None
+        }
+    }
+
+    /// The typed parts of remote helper transports like Radicle and Keybase
+    ///
+    /// ```
+    /// use git_url_parse::GitUrl;
+    /// use git_url_parse::types::{KeybaseVisibility, Transport};
+    ///
+    /// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+    /// let keybase_url = GitUrl::parse("keybase://private/alice/notes")?;
+    /// let Some(Transport::Keybase(keybase)) = keybase_url.transport() else {
+    ///     panic!("Keybase transport expected");
+    /// };
+    /// assert_eq!(keybase.visibility(), KeybaseVisibility::Private);
+    /// assert_eq!(keybase.owner(), "alice");
+    ///
+    /// let ssh_url = GitUrl::parse("git@github.com:tjtelan/git-url-parse-rs.git")?;
+    /// assert_eq!(ssh_url.transport(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn transport(&self) -> Option<Transport> {
+        Transport::from_git_url(self).and_then(Result::ok)
+    }
+
+    /// Returns a file-like `GitUrl` from a filesystem path
+    ///
+    /// The path is kept as written, and is printed without a `file://` scheme
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use git_url_parse::GitUrl;
+    ///
+    /// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+    /// let file_url = GitUrl::from_file_path(Path::new("/path/to/repo.git"))?;
+    ///
+    /// assert_eq!(file_url.scheme(), Some("file"));
+    /// assert_eq!(file_url.path(), "/path/to/repo.git");
+    /// assert_eq!(file_url.to_string(), "/path/to/repo.git");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_file_path(path: &Path) -> Result<Self, GitUrlParseError> {
+        let path = path.to_str().ok_or_else(|| {
+            GitUrlParseError::InvalidFilePath("path is not valid UTF-8".to_string())
+        })?;
+
+        if path.contains('\0') {
+            return Err(GitUrlParseError::FoundNullBytes);
+        }
+
+        let git_url = GitUrl {
+            scheme: Some("file".to_string()),
+            path: path.to_string(),
+            hint: GitUrlParseHint::Filelike,
+            ..Default::default()
+        };
+
+        git_url.is_valid()?;
+
+        Ok(git_url)
+    }
+
+    /// Returns the filesystem path of a file-like `GitUrl`
+    ///
+    /// Supports `file://` urls (including `file://localhost/`), bare paths,
+    /// and each of the windows path styles in [`FilePathKind`].
+    /// A leading `~` is kept as written. See [`GitUrl::to_file_path_with_home`] to expand it.
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    /// use git_url_parse::GitUrl;
+    ///
+    /// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+    /// let file_url = GitUrl::parse("file://localhost/path/to/repo.git")?;
+    /// assert_eq!(file_url.to_file_path()?, PathBuf::from("/path/to/repo.git"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_file_path(&self) -> Result<PathBuf, GitUrlParseError> {
+        if self.hint() != GitUrlParseHint::Filelike {
+            return Err(GitUrlParseError::FilePathUnsupported);
+        }
+
+        Ok(path::url_path_to_file_path(self.path(), None))
+    }
+
+    /// Returns the filesystem path of a file-like `GitUrl`, expanding a leading `~` with `home`
+    ///
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    /// use git_url_parse::GitUrl;
+    ///
+    /// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+    /// let file_url = GitUrl::parse("~/path/to/repo.git")?;
+    /// let path = file_url.to_file_path_with_home(Path::new("/home/user"))?;
+    ///
+    /// assert_eq!(path, PathBuf::from("/home/user/path/to/repo.git"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_file_path_with_home(&self, home: &Path) -> Result<PathBuf, GitUrlParseError> {
+        if self.hint() != GitUrlParseHint::Filelike {
+            return Err(GitUrlParseError::FilePathUnsupported);
+        }
+
+        Ok(path::url_path_to_file_path(self.path(), Some(home)))
+    }
+
+    /// Returns `GitUrl` after removing `.` and `..` segments from the path
+    ///
+    /// Follows [RFC 3986 `remove_dot_segments`](https://datatracker.ietf.org/doc/html/rfc3986#section-5.2.4)
+    /// for http-like urls. Relative ssh and file paths keep their leading `..` segments.
+    /// To resolve relative file paths, see [`GitUrl::resolve_file_path`]
+    ///
+    /// ```
+    /// use git_url_parse::GitUrl;
+    ///
+    /// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+    /// let http_url = GitUrl::parse("https://host.tld/owner/../other/./repo.git")?;
+    /// assert_eq!(http_url.normalize_path().path(), "/other/repo.git");
+    ///
+    /// let file_url = GitUrl::parse("./path/to/../repo.git/")?;
+    /// assert_eq!(file_url.normalize_path().path(), "path/repo.git/");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn normalize_path(&self) -> GitUrl {
+        let path = match self.hint() {
+            GitUrlParseHint::Filelike => path::normalize_file_path(self.path()),
+            _ => path::remove_dot_segments(self.path(), |c| c == '/', '/'),
+        };
+
+        let mut new_giturl = self.clone();
+        new_giturl.set_path(path);
+        #[cfg(feature = "log")]
+        debug!("{new_giturl:?}");
+        new_giturl
+    }
+
+    /// Returns a file-like `GitUrl` with a relative path resolved against the `base` directory
+    ///
+    /// Dot segments are removed from the result, so the same repo referenced from different
+    /// working directories will compare equal. Absolute paths are only normalized.
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use git_url_parse::GitUrl;
+    ///
+    /// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+    /// let from_home = GitUrl::parse("./src/repo.git")?.resolve_file_path(Path::new("/home/user"))?;
+    /// let from_src = GitUrl::parse("../src/repo.git")?.resolve_file_path(Path::new("/home/user/src"))?;
+    ///
+    /// assert_eq!(from_home.path(), "/home/user/src/repo.git");
+    /// assert_eq!(from_home, from_src);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn resolve_file_path(&self, base: &Path) -> Result<GitUrl, GitUrlParseError> {
+        if self.hint() != GitUrlParseHint::Filelike {
+            return Err(GitUrlParseError::FilePathUnsupported);
+        }
+
+        let joined = if path::is_absolute_file_path(self.path()) {
+            self.path().to_string()
+        } else {
+            let base = base.to_str().ok_or_else(|| {
+                GitUrlParseError::InvalidFilePath("base path is not valid UTF-8".to_string())
+            })?;
+
+            path::join_file_path(base, self.path())
+        };
+
+        let mut new_giturl = self.clone();
+        new_giturl.set_path(path::normalize_file_path(&joined));
+
+        new_giturl.is_valid()?;
+
+        Ok(new_giturl)
+    }
+
     /// Normalize input into form that can be used by [`Url::parse`](https://docs.rs/url/latest/url/struct.Url.html#method.parse)
     ///
     /// ```
@@ -317,6 +563,57 @@ impl GitUrl {
         T::from_git_url(self)
     }
 
+    /// Returns the built-in provider info detected from the url host and path shape
+    ///
+    /// Known hosts (i.e. `gitlab.com`, `dev.azure.com`) are detected with [`Confidence::High`](provider::Confidence::High).
+    /// Otherwise host naming and path patterns are checked, before falling back to [`GenericProvider`](provider::GenericProvider)
+    ///
+    /// ```
+    /// use git_url_parse::GitUrl;
+    /// use git_url_parse::types::provider::{Confidence, ProviderKind};
+    ///
+    /// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+    /// let url = GitUrl::parse("git@ssh.dev.azure.com:v3/CompanyName/ProjectName/RepoName")?;
+    /// let detected = url.detect_provider()?;
+    /// assert_eq!(detected.kind(), ProviderKind::AzureDevOps);
+    /// assert_eq!(detected.confidence(), Confidence::High);
+    ///
+    /// let url = GitUrl::parse("https://git.example.com/user/repo.git")?;
+    /// let detected = url.detect_provider()?;
+    /// assert_eq!(detected.kind(), ProviderKind::Generic);
+    /// assert_eq!(detected.confidence(), Confidence::Low);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn detect_provider(&self) -> Result<provider::DetectedProvider, GitUrlParseError> {
+        provider::detect::detect(self, &provider::ProviderRegistry::new())
+    }
+
+    /// Returns the built-in provider info detected from `registry`, then the same checks as [`GitUrl::detect_provider`]
+    ///
+    /// Hosts in `registry` are detected with [`Confidence::High`](provider::Confidence::High)
+    ///
+    /// ```
+    /// use git_url_parse::GitUrl;
+    /// use git_url_parse::types::provider::{ProviderKind, ProviderRegistry};
+    ///
+    /// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+    /// let mut registry = ProviderRegistry::new();
+    /// registry.register("*.corp.example", ProviderKind::AzureDevOps)?;
+    ///
+    /// let url = GitUrl::parse("https://tfs.corp.example/org/project/_git/repo")?;
+    /// let detected = url.detect_provider_with(&registry)?;
+    /// assert_eq!(detected.kind(), ProviderKind::AzureDevOps);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn detect_provider_with(
+        &self,
+        registry: &provider::ProviderRegistry,
+    ) -> Result<provider::DetectedProvider, GitUrlParseError> {
+        provider::detect::detect(self, registry)
+    }
+
     /// This is called as the last step before returning a `GitUrl` to the user
     fn is_valid(&self) -> Result<(), GitUrlParseError> {
         // Last chance validation
@@ -324,10 +621,16 @@ impl GitUrl {
         #[cfg(feature = "log")]
         debug!("Validating parsing results {self:#?}");
 
-        if self.path().is_empty() {
+        // Radicle urls only need a repo id
+        if self.path().is_empty() && self.scheme() != Some(transport::RADICLE_SCHEME) {
             return Err(GitUrlParseError::InvalidPathEmpty);
         }
 
+        // Transports have their own rules for host and path
+        if let Some(transport) = Transport::from_git_url(self) {
+            transport?;
+        }
+
         // There's an edge case we don't properly cover: ssh urls using ports + absolute paths
         // https://mslinn.com/git/040-git-urls.html - describes this pattern, if we decide to parse for it
 
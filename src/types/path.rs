@@ -0,0 +1,269 @@
+//! # GitUrl file path helpers
+//!
+//! Helpers for classifying file-like Git url paths, and converting them to and from filesystem paths.
+//! Windows paths are handled as plain strings, so they are classified the same on every platform.
+//!
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// The style of filesystem path used by a file-like [`GitUrl`](crate::GitUrl)
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::FilePathKind;
+///
+/// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+/// let posix = GitUrl::parse("/path/to/repo.git")?;
+/// assert_eq!(posix.file_path_kind(), Some(FilePathKind::Posix));
+///
+/// let drive = GitUrl::parse("C:/path/to/repo.git")?;
+/// assert_eq!(drive.file_path_kind(), Some(FilePathKind::WindowsDrive));
+///
+/// let unc = GitUrl::parse(r"\\server\share\repo.git")?;
+/// assert_eq!(unc.file_path_kind(), Some(FilePathKind::WindowsUnc));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FilePathKind {
+    /// Absolute or relative unix-style path (i.e. `/path/to/repo`, `../repo`, `~/repo`)
+    Posix,
+    /// Windows path starting with a drive letter (i.e. `C:\repo`, `C:/repo`, `file:///C:/repo`)
+    WindowsDrive,
+    /// Windows UNC network share (i.e. `\\server\share\repo`, `file:////server/share/repo`)
+    WindowsUnc,
+    /// Windows verbatim or device path (i.e. `\\?\C:\repo`, `\\?\UNC\server\share`, `\\.\C:\repo`)
+    WindowsVerbatim,
+    /// Windows relative path using `\` separators (i.e. `..\repo`)
+    WindowsRelative,
+}
+
+impl FilePathKind {
+    /// Classify the path of a file-like url
+    pub(crate) fn from_path(path: &str) -> Self {
+        if ["\\\\?\\", "\\\\.\\", "//?/", "//./"]
+            .iter()
+            .any(|prefix| path.starts_with(prefix))
+        {
+            FilePathKind::WindowsVerbatim
+        } else if path.starts_with("\\\\") || (path.starts_with("//") && !path.starts_with("///")) {
+            FilePathKind::WindowsUnc
+        } else if has_drive_prefix(path) || path.strip_prefix('/').is_some_and(has_drive_prefix) {
+            FilePathKind::WindowsDrive
+        } else if path.contains('\\') {
+            FilePathKind::WindowsRelative
+        } else {
+            FilePathKind::Posix
+        }
+    }
+
+    /// Returns true for all of the windows path kinds
+    pub fn is_windows(&self) -> bool {
+        *self != FilePathKind::Posix
+    }
+}
+
+/// Returns true if `path` starts with a windows drive letter (i.e. `C:`, `C:\`, `C:/`)
+pub(crate) fn has_drive_prefix(path: &str) -> bool {
+    let mut chars = path.chars();
+
+    match (chars.next(), chars.next(), chars.next()) {
+        (Some(letter), Some(':'), None) => letter.is_ascii_alphabetic(),
+        (Some(letter), Some(':'), Some(sep)) => {
+            letter.is_ascii_alphabetic() && (sep == '/' || sep == '\\')
+        }
+        _ => false,
+    }
+}
+
+/// Look for a windows path in the raw url input, before url spec parsing
+///
+/// Returns the path, and whether a `file://` scheme was provided.
+/// Only inputs that can't be parsed as urls are matched:
+/// bare drive letter paths, UNC and verbatim paths, or any path using `\` separators
+pub(crate) fn windows_file_path(input: &str) -> Option<(&str, bool)> {
+    let (path, has_scheme) = match input.get(..7) {
+        Some(scheme) if scheme.eq_ignore_ascii_case("file://") => (&input[7..], true),
+        _ => (input, false),
+    };
+
+    if !has_scheme && input.contains("://") {
+        return None;
+    }
+
+    let windows = if path.contains('\\') {
+        // Bare `host:path\to\repo` is still ssh-like
+        has_scheme || has_drive_prefix(path) || path.starts_with("\\\\") || !path.contains(':')
+    } else {
+        !has_scheme && has_drive_prefix(path)
+    };
+
+    windows.then_some((path, has_scheme))
+}
+
+/// Convert the path of a file-like url into a local filesystem path
+///
+/// * `file:///C:/repo` paths drop the leading `/` before the drive letter
+/// * A leading `~` is replaced with `home`, if provided
+/// * On windows, `/` separators are replaced with `\`, except for verbatim paths
+pub(crate) fn url_path_to_file_path(path: &str, home: Option<&Path>) -> PathBuf {
+    let kind = FilePathKind::from_path(path);
+
+    if kind == FilePathKind::WindowsVerbatim {
+        return PathBuf::from(path);
+    }
+
+    let path = match path.strip_prefix('/') {
+        Some(stripped) if has_drive_prefix(stripped) => stripped,
+        _ => path,
+    };
+
+    if let Some(home) = home {
+        if path == "~" {
+            return home.to_path_buf();
+        }
+
+        if let Some(rest) = path.strip_prefix("~/").or_else(|| path.strip_prefix("~\\")) {
+            return home.join(native_separators(rest));
+        }
+    }
+
+    PathBuf::from(native_separators(path))
+}
+
+/// Replace `/` separators with the platform separator
+fn native_separators(path: &str) -> String {
+    if cfg!(windows) {
+        path.replace('/', "\\")
+    } else {
+        path.to_string()
+    }
+}
+
+/// RFC 3986 `remove_dot_segments`, splitting the path on any char matching `is_sep`
+///
+/// Absolute paths drop `..` segments that would climb above the root.
+/// Relative paths keep their leading `..` segments, since they are resolved against a directory we don't know.
+/// Relative paths normalizing to nothing (i.e. `a/..`) are returned empty
+pub(crate) fn remove_dot_segments(path: &str, is_sep: impl Fn(char) -> bool, sep: char) -> String {
+    let absolute = path.starts_with(&is_sep);
+    let body = if absolute { &path[1..] } else { path };
+
+    let mut segments: Vec<&str> = Vec::new();
+    let mut trailing_sep = false;
+
+    for segment in body.split(&is_sep) {
+        trailing_sep = false;
+
+        match segment {
+            "." => trailing_sep = true,
+            ".." => {
+                if segments.last().is_some_and(|s| *s != "..") {
+                    segments.pop();
+                    trailing_sep = true;
+                } else if absolute {
+                    trailing_sep = true;
+                } else {
+                    segments.push(segment);
+                }
+            }
+            _ => segments.push(segment),
+        }
+    }
+
+    let mut normalized = String::new();
+    if absolute {
+        normalized.push(sep);
+    }
+
+    normalized.push_str(&segments.join(&sep.to_string()));
+
+    if trailing_sep && !segments.is_empty() && !normalized.ends_with(sep) {
+        normalized.push(sep);
+    }
+
+    normalized
+}
+
+/// Remove dot segments from the path of a file-like url
+///
+/// Windows paths accept both `\` and `/` as separators, and keep the first one used.
+/// The drive letter or UNC `\\server\share` prefix is never removed. Verbatim paths are not modified.
+pub(crate) fn normalize_file_path(path: &str) -> String {
+    let kind = FilePathKind::from_path(path);
+
+    if kind == FilePathKind::Posix {
+        return current_dir_if_empty(remove_dot_segments(path, |c| c == '/', '/'));
+    }
+
+    if kind == FilePathKind::WindowsVerbatim {
+        return path.to_string();
+    }
+
+    let is_sep = |c: char| c == '/' || c == '\\';
+    let sep = path.chars().find(|c| is_sep(*c)).unwrap_or('\\');
+
+    let prefix_len = match kind {
+        FilePathKind::WindowsDrive => {
+            if path.starts_with(is_sep) {
+                3
+            } else {
+                2
+            }
+        }
+        FilePathKind::WindowsUnc => {
+            // Leading separators, then the `server` and `share` components
+            let mut seps = path.match_indices(is_sep).map(|(i, _)| i).skip(3);
+            seps.next().unwrap_or(path.len())
+        }
+        _ => 0,
+    };
+
+    let (prefix, rest) = path.split_at(prefix_len);
+
+    if rest.is_empty() {
+        return prefix.to_string();
+    }
+
+    current_dir_if_empty(format!(
+        "{prefix}{}",
+        remove_dot_segments(rest, is_sep, sep)
+    ))
+}
+
+/// Relative file paths normalizing to nothing refer to the current directory
+fn current_dir_if_empty(path: String) -> String {
+    if path.is_empty() {
+        ".".to_string()
+    } else {
+        path
+    }
+}
+
+/// Join a relative file-like url path onto the `base` directory
+pub(crate) fn join_file_path(base: &str, path: &str) -> String {
+    let base_kind = FilePathKind::from_path(base);
+    let sep = if base_kind.is_windows() { '\\' } else { '/' };
+
+    if base.ends_with('/') || base.ends_with('\\') {
+        format!("{base}{path}")
+    } else {
+        format!("{base}{sep}{path}")
+    }
+}
+
+/// Returns true if the path of a file-like url doesn't depend on the working directory
+pub(crate) fn is_absolute_file_path(path: &str) -> bool {
+    match FilePathKind::from_path(path) {
+        FilePathKind::Posix => path.starts_with('/') || path == "~" || path.starts_with("~/"),
+        FilePathKind::WindowsDrive => {
+            let drive_path = path.strip_prefix('/').unwrap_or(path);
+            drive_path.len() > 2
+        }
+        FilePathKind::WindowsUnc | FilePathKind::WindowsVerbatim => true,
+        FilePathKind::WindowsRelative => path.starts_with("~\\"),
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{GitUrl, GitUrlParseError};
+
+/// A resource of the REST API of a repo host
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ApiResource<'a> {
+    /// The repo
+    Repo,
+    /// Branches of the repo
+    Branches,
+    /// Pull requests, or merge requests for GitLab
+    PullRequests,
+    /// Content of a file at a ref
+    File {
+        /// Branch, tag or commit
+        git_ref: &'a str,
+        /// File within the repo
+        path: &'a str,
+    },
+}
+
+/// Render REST API urls of a repo on the host of its parsed url
+///
+/// Self-hosted instances keep the scheme and port of http urls (i.e. `http://git.lan:3000`).
+/// Ssh urls are served over https on the same host, and public ssh hosts (i.e. `ssh.github.com`, `ssh.dev.azure.com`)
+/// are mapped to their API host. Path-like ids, such as GitLab project paths, are percent-encoded.
+/// Azure DevOps urls don't include the `api-version` query
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::provider::{ApiResource, GitHubProvider, GitLabProvider, ToApiUrl};
+///
+/// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+/// let url = GitUrl::parse("git@github.com:tjtelan/git-url-parse-rs.git")?;
+/// let provider: GitHubProvider = url.provider_info()?;
+/// assert_eq!(
+///     provider.api_url(&url, ApiResource::Repo)?,
+///     "https://api.github.com/repos/tjtelan/git-url-parse-rs"
+/// );
+///
+/// let url = GitUrl::parse("https://gitlab.corp.example:8443/group/sub/repo.git")?;
+/// let provider: GitLabProvider = url.provider_info()?;
+/// assert_eq!(provider.api_base_url(&url)?, "https://gitlab.corp.example:8443/api/v4");
+/// assert_eq!(
+///     provider.api_url(&url, ApiResource::PullRequests)?,
+///     "https://gitlab.corp.example:8443/api/v4/projects/group%2Fsub%2Frepo/merge_requests"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub trait ToApiUrl {
+    /// Base url of the REST API serving the repo of `url`.
+    /// Returns [`GitUrlParseError::ProviderUnsupported`] for urls without a host
+    fn api_base_url(&self, url: &GitUrl) -> Result<String, GitUrlParseError>;
+
+    /// Url of `resource` in the REST API serving the repo of `url`.
+    /// Returns [`GitUrlParseError::ProviderUnsupported`] if the API doesn't have the resource
+    fn api_url(&self, url: &GitUrl, resource: ApiResource) -> Result<String, GitUrlParseError>;
+}
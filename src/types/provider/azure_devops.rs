@@ -1,23 +1,51 @@
-use super::GitProvider;
+use super::api::{ApiResource, ToApiUrl};
+use super::web::{
+    LineRange, RefKind, ToRawFileUrl, ToWebUrl, WebPage, WebPath, WebTarget, encode_component,
+    encode_path, query_value, ref_kind, web_origin,
+};
+use super::{CloneProtocol, GitProvider, ToGitUrl};
 use crate::types::GitUrlParseHint;
 use crate::{GitUrl, GitUrlParseError};
 
-use getset::Getters;
-use nom::Parser;
-use nom::branch::alt;
-use nom::bytes::complete::{is_not, tag, take_until};
-use nom::combinator::opt;
-use nom::sequence::{preceded, separated_pair, terminated};
+use getset::{CopyGetters, Getters};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "url")]
 use url::Url;
 
+/// Path segments between the project and the repo name
+const REPO_SEGMENTS: &[&str] = &["_git", "_ssh"];
+
+/// Path segments after [`REPO_SEGMENTS`] for clone optimized variants
+const OPTIMIZED_SEGMENTS: &[&str] = &["_optimized", "_full"];
+
+/// The type of host serving an Azure DevOps repo
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum AzureDevOpsHost {
+    /// `dev.azure.com` and `ssh.dev.azure.com`
+    #[default]
+    DevAzure,
+    /// Legacy `<org>.visualstudio.com` and `vs-ssh.visualstudio.com`
+    VisualStudio,
+    /// On-prem Azure DevOps Server or TFS
+    Server,
+}
+
 /// Azure DevOps repository provider
 /// ## Supported URL Formats
 ///
 /// - `https://dev.azure.com/org/project/_git/repo`
 /// - `git@ssh.dev.azure.com:v3/org/project/repo`
+/// - `https://org.visualstudio.com/DefaultCollection/project/_git/repo`
+/// - `org@vs-ssh.visualstudio.com:v3/org/project/repo`
+/// - `https://tfs.corp/tfs/collection/project/_git/repo` (Azure DevOps Server / TFS)
+/// - `https://dev.azure.com/org/project/_git/_optimized/repo`
+/// - `ssh://host:22/tfs/collection/project/_ssh/repo`
+/// - `https://dev.azure.com/org/_git/repo` (project-less, the project has the repo name)
+///
+/// On Azure DevOps Server, the collection is used as [`AzureDevOpsProvider::org()`]
 ///
 /// Example:
 ///
@@ -36,107 +64,515 @@ use url::Url;
 /// assert_eq!(provider_info.fullname(), "CompanyName/ProjectName/RepoName");
 /// ```
 ///
-#[derive(Debug, PartialEq, Eq, Clone, Getters)]
+/// Legacy hosts can be migrated to `dev.azure.com`:
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::provider::{AzureDevOpsHost, AzureDevOpsProvider};
+///
+/// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+/// let url = GitUrl::parse("https://contoso.visualstudio.com/DefaultCollection/Fabrikam/_git/Fiber")?;
+/// let provider: AzureDevOpsProvider = url.provider_info()?;
+///
+/// assert_eq!(provider.host_kind(), AzureDevOpsHost::VisualStudio);
+/// assert_eq!(provider.org(), "contoso");
+/// assert_eq!(provider.collection(), Some("DefaultCollection"));
+/// assert_eq!(
+///     provider.canonical_https_url().as_deref(),
+///     Some("https://dev.azure.com/contoso/Fabrikam/_git/Fiber")
+/// );
+/// assert_eq!(
+///     provider.canonical_ssh_url().as_deref(),
+///     Some("git@ssh.dev.azure.com:v3/contoso/Fabrikam/Fiber")
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Getters, CopyGetters)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[getset(get = "pub")]
 pub struct AzureDevOpsProvider {
-    /// Azure Devops organization name
+    /// Azure Devops organization name, or collection name on Azure DevOps Server
+    #[getset(get = "pub")]
     org: String,
     /// Azure Devops project name
+    #[getset(get = "pub")]
     project: String,
     /// Azure Devops repo name
+    #[getset(get = "pub")]
     repo: String,
+    /// Project collection, for legacy and on-prem hosts
+    collection: Option<String>,
+    /// Virtual directory of older Azure DevOps Server installs, without leading or trailing `/`
+    base_path: Option<String>,
+    /// Type of host serving the repo
+    #[getset(get_copy = "pub")]
+    host_kind: AzureDevOpsHost,
 }
 
 impl AzureDevOpsProvider {
+    /// Provider info of a `dev.azure.com` repo
+    pub fn new(org: &str, project: &str, repo: &str) -> Result<Self, GitUrlParseError> {
+        let path = super::join_names(&[org, project, "_git", repo])?;
+        Self::parse_path(Some("dev.azure.com"), &path)
+    }
+
+    /// Project collection, for legacy and on-prem hosts (i.e. `DefaultCollection`)
+    pub fn collection(&self) -> Option<&str> {
+        self.collection.as_deref()
+    }
+
+    /// Virtual directory of older Azure DevOps Server installs (i.e. `tfs`)
+    pub fn base_path(&self) -> Option<&str> {
+        self.base_path.as_deref()
+    }
+
     /// Helper method to get the full name of a repo: `{org}/{project}/{repo}`
     pub fn fullname(&self) -> String {
         format!("{}/{}/{}", self.org, self.project, self.repo)
     }
 
-    /// Parse the path of a http url for Azure Devops patterns
-    fn parse_http_path(input: &str) -> Result<(&str, AzureDevOpsProvider), GitUrlParseError> {
-        // Handle optional leading /
-        let (input, _) = opt(tag("/")).parse(input)?;
+    /// `https://dev.azure.com` clone url of the repo, for migrating from legacy hosts.
+    /// Returns `None` for Azure DevOps Server
+    pub fn canonical_https_url(&self) -> Option<String> {
+        (self.host_kind != AzureDevOpsHost::Server).then(|| {
+            format!(
+                "https://dev.azure.com/{}/{}/_git/{}",
+                self.org, self.project, self.repo
+            )
+        })
+    }
 
-        // Parse org/project/repo
-        let (input, (org, (project, repo))) = separated_pair(
-            is_not("/"),
-            tag("/"),
-            separated_pair(
-                is_not("/"),
-                tag("/"),
-                preceded(opt(tag("_git/")), is_not("")),
-            ),
-        )
-        .parse(input)?;
+    /// `ssh.dev.azure.com` clone url of the repo, for migrating from legacy hosts.
+    /// Returns `None` for Azure DevOps Server
+    pub fn canonical_ssh_url(&self) -> Option<String> {
+        (self.host_kind != AzureDevOpsHost::Server).then(|| {
+            format!(
+                "git@ssh.dev.azure.com:v3/{}/{}/{}",
+                self.org, self.project, self.repo
+            )
+        })
+    }
+
+    /// Parse the host and path of url for Azure Devops patterns
+    fn parse_path(
+        host: Option<&str>,
+        input: &str,
+    ) -> Result<AzureDevOpsProvider, GitUrlParseError> {
+        let host = host.unwrap_or_default().to_ascii_lowercase();
 
-        Ok((
-            input,
-            AzureDevOpsProvider {
+        let host_kind = if host == "dev.azure.com" || host == "ssh.dev.azure.com" {
+            AzureDevOpsHost::DevAzure
+        } else if host.ends_with(".visualstudio.com") {
+            AzureDevOpsHost::VisualStudio
+        } else {
+            AzureDevOpsHost::Server
+        };
+
+        let parts: Vec<&str> = input.split('/').filter(|s| !s.is_empty()).collect();
+
+        // `v3/org/project/repo` ssh paths
+        if let ["v3", org, project, repo] = parts.as_slice() {
+            return Ok(AzureDevOpsProvider {
                 org: org.to_string(),
                 project: project.to_string(),
-                repo: repo.to_string(),
-            },
-        ))
+                repo: repo.strip_suffix(".git").unwrap_or(repo).to_string(),
+                collection: None,
+                base_path: None,
+                host_kind,
+            });
+        }
+
+        let Some(marker) = parts.iter().position(|p| REPO_SEGMENTS.contains(p)) else {
+            // `org/project/repo` without `_git`
+            return match parts.as_slice() {
+                [org, project, repo] => Ok(AzureDevOpsProvider {
+                    org: org.to_string(),
+                    project: project.to_string(),
+                    repo: repo.to_string(),
+                    collection: None,
+                    base_path: None,
+                    host_kind,
+                }),
+                _ => Err(GitUrlParseError::ProviderParseFail(
+                    "Path expected: ex. '/org/project/_git/repo'".into(),
+                )),
+            };
+        };
+
+        let (prefix, rest) = parts.split_at(marker);
+
+        let repo = match &rest[1..] {
+            [optimized, repo, ..] if OPTIMIZED_SEGMENTS.contains(optimized) => *repo,
+            [repo, ..] => *repo,
+            [] => {
+                return Err(GitUrlParseError::ProviderParseFail(
+                    "Path needs a repo after '_git/'".into(),
+                ));
+            }
+        };
+
+        // Split the path before `_git` into org, collection and project
+        let mut base_path = None;
+        let (org, collection, project) = match (host_kind, prefix) {
+            (AzureDevOpsHost::DevAzure, [org]) => (*org, None, None),
+            (AzureDevOpsHost::DevAzure, [org, project]) => (*org, None, Some(*project)),
+            (AzureDevOpsHost::VisualStudio, _) => {
+                let org = host.split('.').next().unwrap_or_default();
+                match prefix {
+                    [] => (org, None, None),
+                    [collection] if collection.eq_ignore_ascii_case("DefaultCollection") => {
+                        (org, Some(*collection), None)
+                    }
+                    [project] => (org, None, Some(*project)),
+                    [collection, project] => (org, Some(*collection), Some(*project)),
+                    _ => {
+                        return Err(GitUrlParseError::ProviderParseFail(
+                            "Path expected: ex. '/DefaultCollection/project/_git/repo'".into(),
+                        ));
+                    }
+                }
+            }
+            (AzureDevOpsHost::Server, _) => {
+                // The `tfs` virtual directory of older installs
+                let prefix = match prefix {
+                    [tfs, rest @ ..] if tfs.eq_ignore_ascii_case("tfs") && !rest.is_empty() => {
+                        base_path = Some(tfs.to_string());
+                        rest
+                    }
+                    _ => prefix,
+                };
+
+                match prefix {
+                    [collection] => (*collection, Some(*collection), None),
+                    [collection, project] => (*collection, Some(*collection), Some(*project)),
+                    _ => {
+                        return Err(GitUrlParseError::ProviderParseFail(
+                            "Path expected: ex. '/tfs/collection/project/_git/repo'".into(),
+                        ));
+                    }
+                }
+            }
+            _ => {
+                return Err(GitUrlParseError::ProviderParseFail(
+                    "Path expected: ex. '/org/project/_git/repo'".into(),
+                ));
+            }
+        };
+
+        if org.is_empty() {
+            return Err(GitUrlParseError::ProviderParseFail(
+                "Azure DevOps url needs an organization".into(),
+            ));
+        }
+
+        Ok(AzureDevOpsProvider {
+            org: org.to_string(),
+            // Project-less repos are in the project with the same name
+            project: project.unwrap_or(repo).to_string(),
+            repo: repo.to_string(),
+            collection: collection.map(|c| c.to_string()),
+            base_path,
+            host_kind,
+        })
     }
 
-    /// Parse the path of an ssh url for Azure Devops patterns
-    fn parse_ssh_path(input: &str) -> Result<(&str, AzureDevOpsProvider), GitUrlParseError> {
-        // Handle optional leading /v3/ or v3/ prefix
-        let (input, _) =
-            opt(alt((preceded(tag("/"), tag("v3/")), take_until("/")))).parse(input)?;
+    /// Split a web url path and query into the repo and the page within it.
+    /// Files and directories are in the `path` and `version` query
+    pub(crate) fn split_web_path(parts: &[String], query: &[(String, String)]) -> Option<WebPath> {
+        let marker = parts.iter().position(|p| p == "_git")?;
+        let optimized = parts
+            .get(marker + 1)
+            .is_some_and(|p| OPTIMIZED_SEGMENTS.contains(&p.as_str()));
+        let repo_end = marker + 2 + usize::from(optimized);
 
-        let (input, _) = opt(tag("/")).parse(input)?;
+        if parts.len() < repo_end {
+            return None;
+        }
 
-        // Parse org/project/repo
-        let (input, (org, (project, repo))) = separated_pair(
-            is_not("/"),
-            tag("/"),
-            separated_pair(
-                is_not("/"),
-                tag("/"),
-                terminated(is_not("."), opt(tag(".git"))),
-            ),
-        )
-        .parse(input)?;
+        let clone_path = parts[..repo_end].to_vec();
 
-        Ok((
-            input,
-            AzureDevOpsProvider {
-                org: org.to_string(),
-                project: project.to_string(),
-                repo: repo.to_string(),
-            },
-        ))
+        match &parts[repo_end..] {
+            [] => {}
+            [commit, rest @ ..] if commit == "commit" => {
+                return Some(WebPath::new(clone_path, WebPage::Commit, rest));
+            }
+            _ => return Some(WebPath::new(clone_path, WebPage::Other, &[])),
+        }
+
+        let path = query_value(query, "path").map(|p| p.trim_matches('/').to_string());
+        let git_ref = query_value(query, "version").map(Self::parse_version);
+
+        let page = match query_value(query, "_a") {
+            Some("blame") => WebPage::Blame,
+            Some("contents") if path.is_some() => WebPage::Blob,
+            _ if path.is_some() || git_ref.is_some() => WebPage::Tree,
+            _ => WebPage::Repo,
+        };
+
+        Some(WebPath {
+            clone_path,
+            page,
+            git_ref,
+            path,
+            lines: Self::parse_line_query(query),
+        })
     }
-}
 
-impl GitProvider<GitUrl, GitUrlParseError> for AzureDevOpsProvider {
-    fn from_git_url(url: &GitUrl) -> Result<Self, GitUrlParseError> {
-        let path = url.path();
+    /// Ref of a version query value. Tags are returned as `refs/tags/{tag}`
+    fn parse_version(version: &str) -> String {
+        match version.get(..2) {
+            Some("GT") => format!("refs/tags/{}", &version[2..]),
+            Some("GB" | "GC") => version[2..].to_string(),
+            _ => version.to_string(),
+        }
+    }
+
+    /// Lines of the `line` and `lineEnd` query values
+    fn parse_line_query(query: &[(String, String)]) -> Option<LineRange> {
+        let line = |key| {
+            query_value(query, key)
+                .and_then(|l| l.parse::<u32>().ok())
+                .filter(|l| *l > 0)
+        };
+
+        let start = line("line")?;
+        let end = line("lineEnd").unwrap_or(start);
 
-        let parsed = if url.hint() == GitUrlParseHint::Httplike {
-            Self::parse_http_path(path)
+        // Whole lines are selected up to column 1 of the line after the range
+        let end = if end > start && query_value(query, "lineEndColumn") == Some("1") {
+            end - 1
         } else {
-            Self::parse_ssh_path(path)
+            end
         };
 
-        parsed.map(|(_, provider)| provider)
+        Some(LineRange::new(start, end))
+    }
+}
+
+impl GitProvider<GitUrl, GitUrlParseError> for AzureDevOpsProvider {
+    fn from_git_url(url: &GitUrl) -> Result<Self, GitUrlParseError> {
+        if url.hint() == GitUrlParseHint::Filelike {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Self::parse_path(url.host(), url.path())
     }
 }
 
 #[cfg(feature = "url")]
 impl GitProvider<Url, GitUrlParseError> for AzureDevOpsProvider {
     fn from_git_url(url: &Url) -> Result<Self, GitUrlParseError> {
-        let path = url.path();
+        if url.scheme() == "file" {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
 
-        let parsed = if url.scheme().contains("http") {
-            Self::parse_http_path(path)
-        } else {
-            Self::parse_ssh_path(path)
+        Self::parse_path(url.host_str(), url.path())
+    }
+}
+
+impl ToGitUrl for AzureDevOpsProvider {
+    /// Azure DevOps Server paths start with the virtual directory, if any, then the collection
+    fn clone_path(&self, protocol: CloneProtocol) -> String {
+        let (org, project, repo) = (&self.org, &self.project, &self.repo);
+        let base = self
+            .base_path
+            .as_ref()
+            .map(|b| format!("{b}/"))
+            .unwrap_or_default();
+
+        match (self.host_kind, protocol) {
+            (AzureDevOpsHost::Server, CloneProtocol::Https) => {
+                format!("{base}{org}/{project}/_git/{repo}")
+            }
+            (AzureDevOpsHost::Server, CloneProtocol::Ssh) => {
+                format!("{base}{org}/{project}/_ssh/{repo}")
+            }
+            (AzureDevOpsHost::VisualStudio, CloneProtocol::Https) => match &self.collection {
+                Some(collection) => format!("{collection}/{project}/_git/{repo}"),
+                None => format!("{project}/_git/{repo}"),
+            },
+            (AzureDevOpsHost::DevAzure, CloneProtocol::Https) => {
+                format!("{org}/{project}/_git/{repo}")
+            }
+            (_, CloneProtocol::Ssh) => format!("v3/{org}/{project}/{repo}"),
+        }
+    }
+
+    /// Legacy hosts authenticate ssh as the organization
+    fn ssh_user(&self) -> Option<&str> {
+        match self.host_kind {
+            AzureDevOpsHost::VisualStudio => Some(&self.org),
+            _ => Some("git"),
+        }
+    }
+
+    /// `dev.azure.com` and `visualstudio.com` serve ssh from their own hosts
+    fn clone_host(&self, host: &str, protocol: CloneProtocol) -> String {
+        match (self.host_kind, protocol) {
+            (AzureDevOpsHost::DevAzure, CloneProtocol::Https) => "dev.azure.com".to_string(),
+            (AzureDevOpsHost::DevAzure, CloneProtocol::Ssh) => "ssh.dev.azure.com".to_string(),
+            (AzureDevOpsHost::VisualStudio, CloneProtocol::Https) => {
+                format!("{}.visualstudio.com", self.org)
+            }
+            (AzureDevOpsHost::VisualStudio, CloneProtocol::Ssh) => {
+                "vs-ssh.visualstudio.com".to_string()
+            }
+            (AzureDevOpsHost::Server, _) => host.to_string(),
+        }
+    }
+}
+
+impl AzureDevOpsProvider {
+    /// Version query value of a ref: `GB{branch}`, `GT{tag}` or `GC{commit}`
+    fn version(git_ref: &str) -> String {
+        let (kind, name) = ref_kind(git_ref);
+        let prefix = match kind {
+            RefKind::Branch => "GB",
+            RefKind::Tag => "GT",
+            RefKind::Commit => "GC",
+        };
+
+        format!("{prefix}{}", encode_component(name))
+    }
+
+    /// Query of a file page, with line selection
+    fn file_query(git_ref: &str, path: &str, lines: Option<LineRange>) -> String {
+        let mut query = format!(
+            "?path=/{}&version={}",
+            encode_path(path),
+            Self::version(git_ref)
+        );
+
+        // Whole lines are selected up to column 1 of the line after the range
+        if let Some(lines) = lines {
+            query.push_str(&format!(
+                "&line={}&lineEnd={}&lineStartColumn=1&lineEndColumn=1&lineStyle=plain",
+                lines.start(),
+                lines.last() + 1
+            ));
+        }
+
+        query
+    }
+
+    /// Query of a file in the REST API, selecting the version of a ref
+    fn items_query(git_ref: &str, path: &str) -> String {
+        let (kind, name) = ref_kind(git_ref);
+        let version_type = match kind {
+            RefKind::Branch => "branch",
+            RefKind::Tag => "tag",
+            RefKind::Commit => "commit",
         };
 
-        parsed.map(|(_, provider)| provider)
+        format!(
+            "?path=/{}&versionDescriptor.version={}&versionDescriptor.versionType={version_type}",
+            encode_path(path),
+            encode_component(name)
+        )
+    }
+}
+
+impl ToWebUrl for AzureDevOpsProvider {
+    /// `dev.azure.com` and legacy `visualstudio.com` repos link to their https host,
+    /// Azure DevOps Server repos to the host of `url`
+    fn web_url(&self, url: &GitUrl, target: WebTarget) -> Result<String, GitUrlParseError> {
+        let origin = web_origin(url)?;
+        let origin = match self.host_kind {
+            AzureDevOpsHost::Server => origin,
+            _ => format!(
+                "https://{}",
+                self.clone_host(url.host().unwrap_or_default(), CloneProtocol::Https)
+            ),
+        };
+        let base = format!("{origin}/{}", self.clone_path(CloneProtocol::Https));
+
+        let url = match target {
+            WebTarget::Repo => base,
+            WebTarget::Tree { git_ref, path } => format!(
+                "{base}?path=/{}&version={}",
+                encode_path(path.unwrap_or_default()),
+                Self::version(git_ref)
+            ),
+            WebTarget::Blob {
+                git_ref,
+                path,
+                lines,
+            } => format!(
+                "{base}{}&_a=contents",
+                Self::file_query(git_ref, path, lines)
+            ),
+            WebTarget::Commit { sha } => format!("{base}/commit/{}", encode_path(sha)),
+            WebTarget::Compare { base: from, head } => format!(
+                "{base}/branchCompare?baseVersion={}&targetVersion={}",
+                Self::version(from),
+                Self::version(head)
+            ),
+            WebTarget::Blame {
+                git_ref,
+                path,
+                lines,
+            } => format!("{base}{}&_a=blame", Self::file_query(git_ref, path, lines)),
+        };
+
+        Ok(url)
+    }
+}
+
+impl ToApiUrl for AzureDevOpsProvider {
+    /// `dev.azure.com` and legacy `visualstudio.com` orgs are served from the org host,
+    /// Azure DevOps Server from the collection, under any virtual directory, on the host of `url`
+    fn api_base_url(&self, url: &GitUrl) -> Result<String, GitUrlParseError> {
+        let origin = web_origin(url)?;
+        let org = encode_component(&self.org);
+
+        let base = match self.host_kind {
+            AzureDevOpsHost::DevAzure => format!("https://dev.azure.com/{org}"),
+            AzureDevOpsHost::VisualStudio => match &self.collection {
+                Some(collection) => format!(
+                    "https://{org}.visualstudio.com/{}",
+                    encode_component(collection)
+                ),
+                None => format!("https://{org}.visualstudio.com"),
+            },
+            AzureDevOpsHost::Server => match &self.base_path {
+                Some(base_path) => format!("{origin}/{base_path}/{org}"),
+                None => format!("{origin}/{org}"),
+            },
+        };
+
+        Ok(base)
+    }
+
+    fn api_url(&self, url: &GitUrl, resource: ApiResource) -> Result<String, GitUrlParseError> {
+        let repo = format!(
+            "{}/{}/_apis/git/repositories/{}",
+            self.api_base_url(url)?,
+            encode_component(&self.project),
+            encode_component(&self.repo)
+        );
+
+        let url = match resource {
+            ApiResource::Repo => repo,
+            ApiResource::Branches => format!("{repo}/refs?filter=heads/"),
+            ApiResource::PullRequests => format!("{repo}/pullrequests"),
+            ApiResource::File { git_ref, path } => {
+                format!("{repo}/items{}", Self::items_query(git_ref, path))
+            }
+        };
+
+        Ok(url)
+    }
+}
+
+impl ToRawFileUrl for AzureDevOpsProvider {
+    /// Files are downloaded from the items resource of the REST API
+    fn raw_file_url(
+        &self,
+        url: &GitUrl,
+        git_ref: &str,
+        path: &str,
+    ) -> Result<String, GitUrlParseError> {
+        self.api_url(url, ApiResource::File { git_ref, path })
     }
 }
@@ -0,0 +1,519 @@
+use super::api::{ApiResource, ToApiUrl};
+use super::web::{
+    LineRange, ToRawFileUrl, ToWebUrl, WebPage, WebPath, WebTarget, encode_component, encode_path,
+    query_value, web_origin,
+};
+use super::{CloneProtocol, GitProvider, ToGitUrl};
+use crate::types::GitUrlParseHint;
+use crate::{GitUrl, GitUrlParseError};
+
+use getset::{CopyGetters, Getters};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "url")]
+use url::Url;
+
+/// ## Bitbucket Cloud repository provider
+///
+/// ## Supported URL Formats
+///
+/// - `https://user@bitbucket.org/workspace/repo.git`
+/// - `git@bitbucket.org:workspace/repo.git`
+/// - `ssh://git@bitbucket.org/workspace/repo.git`
+///
+/// Example:
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::provider::BitbucketCloudProvider;
+///
+/// let test_url = "https://your_username@bitbucket.org/workspace_ID/repo_name.git";
+/// let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+///
+/// let provider_info: BitbucketCloudProvider = parsed.provider_info().unwrap();
+///
+/// assert_eq!(provider_info.workspace(), "workspace_ID");
+/// assert_eq!(provider_info.repo(), "repo_name");
+/// assert_eq!(provider_info.fullname(), "workspace_ID/repo_name");
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Getters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[getset(get = "pub")]
+pub struct BitbucketCloudProvider {
+    /// Bitbucket workspace id
+    workspace: String,
+    /// Repo slug
+    repo: String,
+}
+
+impl BitbucketCloudProvider {
+    /// Provider info of the `workspace/repo` repo
+    pub fn new(workspace: &str, repo: &str) -> Result<Self, GitUrlParseError> {
+        Self::parse_path(&super::join_names(&[workspace, repo])?)
+    }
+
+    /// Helper method to get the full name of a repo: `{workspace}/{repo}`
+    pub fn fullname(&self) -> String {
+        format!("{}/{}", self.workspace, self.repo)
+    }
+
+    /// Parse the path of url for Bitbucket Cloud patterns
+    fn parse_path(input: &str) -> Result<BitbucketCloudProvider, GitUrlParseError> {
+        let parts = split_path(input);
+
+        match parts.as_slice() {
+            [workspace, repo] => Ok(BitbucketCloudProvider {
+                workspace: workspace.to_string(),
+                repo: trim_git_suffix(repo).to_string(),
+            }),
+            _ => Err(GitUrlParseError::ProviderParseFail(
+                "Path needs 2 parts: ex. '/workspace/repo'".into(),
+            )),
+        }
+    }
+
+    /// Split a web url path into the repo and the page within it
+    pub(crate) fn split_web_path(parts: &[String]) -> Option<WebPath> {
+        let [workspace, repo, rest @ ..] = parts else {
+            return None;
+        };
+        let clone_path = vec![workspace.clone(), format!("{}.git", trim_git_suffix(repo))];
+
+        let (page, rest) = match rest {
+            [] => (WebPage::Repo, rest),
+            [page, rest @ ..] => match page.as_str() {
+                "src" => (WebPage::Tree, rest),
+                "annotate" => (WebPage::Blame, rest),
+                "commits" if !rest.is_empty() => (WebPage::Commit, rest),
+                _ => (WebPage::Other, rest),
+            },
+        };
+
+        Some(WebPath::new(clone_path, page, rest))
+    }
+}
+
+impl GitProvider<GitUrl, GitUrlParseError> for BitbucketCloudProvider {
+    fn from_git_url(url: &GitUrl) -> Result<Self, GitUrlParseError> {
+        if url.hint() == GitUrlParseHint::Filelike {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Self::parse_path(url.path())
+    }
+}
+
+#[cfg(feature = "url")]
+impl GitProvider<Url, GitUrlParseError> for BitbucketCloudProvider {
+    fn from_git_url(url: &Url) -> Result<Self, GitUrlParseError> {
+        if url.scheme() == "file" {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Self::parse_path(url.path())
+    }
+}
+
+/// ## Bitbucket Server / Data Center repository provider
+///
+/// ## Supported URL Formats
+///
+/// - `https://host/scm/PROJ/repo.git`
+/// - `https://host/context/scm/PROJ/repo.git`
+/// - `https://host/scm/~user/repo.git` (personal repo)
+/// - `ssh://git@host:7999/proj/repo.git`
+/// - `ssh://git@host:7999/~user/repo.git` (personal repo)
+/// - `https://host/projects/PROJ/repos/repo/browse`
+/// - `https://host/users/user/repos/repo/browse` (personal repo)
+///
+/// Personal repos keep the `~` in [`BitbucketServerProvider::project_key()`], same as the Bitbucket Server REST API
+///
+/// Example:
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::provider::BitbucketServerProvider;
+///
+/// fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+///     let url = GitUrl::parse("https://bitbucket.corp.example/scm/PROJ/repo.git")?;
+///     let provider: BitbucketServerProvider = url.provider_info()?;
+///     assert_eq!(provider.project_key(), "PROJ");
+///     assert_eq!(provider.repo(), "repo");
+///     assert!(!provider.personal());
+///
+///     let url = GitUrl::parse("https://corp.example/bitbucket/scm/PROJ/repo.git")?;
+///     let provider: BitbucketServerProvider = url.provider_info()?;
+///     assert_eq!(provider.base_path(), Some("bitbucket"));
+///
+///     let url = GitUrl::parse("ssh://git@bitbucket.corp.example:7999/~jdoe/repo.git")?;
+///     let provider: BitbucketServerProvider = url.provider_info()?;
+///     assert_eq!(provider.project_key(), "~jdoe");
+///     assert_eq!(provider.repo(), "repo");
+///     assert!(provider.personal());
+///     assert_eq!(provider.fullname(), "~jdoe/repo");
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Getters, CopyGetters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BitbucketServerProvider {
+    /// Context path of the install, without leading or trailing `/`
+    base_path: Option<String>,
+    /// Project key, or `~user` for personal repos
+    #[getset(get = "pub")]
+    project_key: String,
+    /// Repo slug
+    #[getset(get = "pub")]
+    repo: String,
+    /// If the repo belongs to a user instead of a project
+    #[getset(get_copy = "pub")]
+    personal: bool,
+}
+
+impl BitbucketServerProvider {
+    /// Provider info of a project repo, or a personal repo with `~user` as `project_key`
+    pub fn new(project_key: &str, repo: &str) -> Result<Self, GitUrlParseError> {
+        Self::parse_path(&super::join_names(&["scm", project_key, repo])?)
+    }
+
+    /// Context path of the install, without leading or trailing `/` (i.e. `bitbucket`)
+    pub fn base_path(&self) -> Option<&str> {
+        self.base_path.as_deref()
+    }
+
+    /// Helper method to get the full name of a repo: `{project_key}/{repo}`
+    pub fn fullname(&self) -> String {
+        format!("{}/{}", self.project_key, self.repo)
+    }
+
+    /// Parse the path of url for Bitbucket Server patterns
+    fn parse_path(input: &str) -> Result<BitbucketServerProvider, GitUrlParseError> {
+        let parts = split_path(input);
+
+        // Any context path is in front of the clone or browse path
+        let start = parts
+            .iter()
+            .position(|p| *p == "scm" || *p == "projects" || *p == "users")
+            .unwrap_or_default();
+
+        let (project_key, repo) = match &parts[start..] {
+            ["scm", project_key, repo] | [project_key, repo] => {
+                (project_key.to_string(), trim_git_suffix(repo))
+            }
+            ["projects", project_key, "repos", repo, ..] => (project_key.to_string(), *repo),
+            ["users", user, "repos", repo, ..] => (format!("~{user}"), *repo),
+            _ => {
+                return Err(GitUrlParseError::ProviderParseFail(
+                    "Path expected: ex. '/scm/PROJ/repo.git' or '/proj/repo.git'".into(),
+                ));
+            }
+        };
+
+        let base = &parts[..start];
+
+        Ok(BitbucketServerProvider {
+            base_path: (!base.is_empty()).then(|| base.join("/")),
+            personal: project_key.starts_with('~'),
+            project_key,
+            repo: repo.to_string(),
+        })
+    }
+
+    /// Split a web url path and query into the repo and the page within it.
+    /// The ref of browse pages is in the `at` query
+    pub(crate) fn split_web_path(parts: &[String], query: &[(String, String)]) -> Option<WebPath> {
+        let start = parts.iter().position(|p| p == "projects" || p == "users")?;
+
+        let [owner_kind, owner, repos, repo, rest @ ..] = &parts[start..] else {
+            return None;
+        };
+        if repos != "repos" {
+            return None;
+        }
+
+        let project_key = match owner_kind.as_str() {
+            "users" => format!("~{owner}"),
+            _ => owner.clone(),
+        };
+
+        let mut clone_path = parts[..start].to_vec();
+        clone_path.extend(["scm".to_string(), project_key, format!("{repo}.git")]);
+
+        let git_ref = query_value(query, "at")
+            .map(|r| r.strip_prefix("refs/heads/").unwrap_or(r).to_string());
+
+        match rest {
+            [] => Some(WebPath::new(clone_path, WebPage::Repo, &[])),
+            [browse, path @ ..] if browse == "browse" => {
+                let page = match (path, &git_ref) {
+                    ([], None) => WebPage::Repo,
+                    _ => WebPage::Tree,
+                };
+
+                Some(WebPath {
+                    clone_path,
+                    page,
+                    git_ref,
+                    path: Some(path.join("/")),
+                    lines: None,
+                })
+            }
+            [commits, sha, ..] if commits == "commits" => Some(WebPath::new(
+                clone_path,
+                WebPage::Commit,
+                std::slice::from_ref(sha),
+            )),
+            _ => Some(WebPath::new(clone_path, WebPage::Other, &[])),
+        }
+    }
+}
+
+impl GitProvider<GitUrl, GitUrlParseError> for BitbucketServerProvider {
+    fn from_git_url(url: &GitUrl) -> Result<Self, GitUrlParseError> {
+        if url.hint() == GitUrlParseHint::Filelike {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Self::parse_path(url.path())
+    }
+}
+
+#[cfg(feature = "url")]
+impl GitProvider<Url, GitUrlParseError> for BitbucketServerProvider {
+    fn from_git_url(url: &Url) -> Result<Self, GitUrlParseError> {
+        if url.scheme() == "file" {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Self::parse_path(url.path())
+    }
+}
+
+impl ToGitUrl for BitbucketCloudProvider {
+    fn clone_path(&self, _protocol: CloneProtocol) -> String {
+        format!("{}.git", self.fullname())
+    }
+}
+
+impl ToGitUrl for BitbucketServerProvider {
+    /// Https clone paths start with the context path and `scm/`. Ssh is usually served on port `7999`
+    fn clone_path(&self, protocol: CloneProtocol) -> String {
+        match (protocol, &self.base_path) {
+            (CloneProtocol::Https, Some(base_path)) => {
+                format!("{base_path}/scm/{}.git", self.fullname())
+            }
+            (CloneProtocol::Https, None) => format!("scm/{}.git", self.fullname()),
+            (CloneProtocol::Ssh, _) => format!("{}.git", self.fullname()),
+        }
+    }
+}
+
+impl ToWebUrl for BitbucketCloudProvider {
+    fn web_url(&self, url: &GitUrl, target: WebTarget) -> Result<String, GitUrlParseError> {
+        let base = format!("{}/{}", web_origin(url)?, self.fullname());
+        let anchor = |lines: Option<LineRange>| match lines.map(|l| (l.start(), l.end())) {
+            Some((start, Some(end))) => format!("#lines-{start}:{end}"),
+            Some((start, None)) => format!("#lines-{start}"),
+            None => String::new(),
+        };
+
+        let url = match target {
+            WebTarget::Repo => base,
+            WebTarget::Tree { git_ref, path } => format!(
+                "{base}/src/{}/{}",
+                encode_path(git_ref),
+                path.map(encode_path).unwrap_or_default()
+            ),
+            WebTarget::Blob {
+                git_ref,
+                path,
+                lines,
+            } => format!(
+                "{base}/src/{}/{}{}",
+                encode_path(git_ref),
+                encode_path(path),
+                anchor(lines)
+            ),
+            WebTarget::Commit { sha } => format!("{base}/commits/{}", encode_path(sha)),
+            WebTarget::Compare { base: from, head } => format!(
+                "{base}/branches/compare/{}%0D{}",
+                encode_component(head),
+                encode_component(from)
+            ),
+            WebTarget::Blame {
+                git_ref,
+                path,
+                lines,
+            } => format!(
+                "{base}/annotate/{}/{}{}",
+                encode_path(git_ref),
+                encode_path(path),
+                anchor(lines)
+            ),
+        };
+
+        Ok(url)
+    }
+}
+
+impl BitbucketServerProvider {
+    /// Url of the install at `origin` (i.e. `https://host`), including the context path
+    fn install_base(&self, origin: &str) -> String {
+        match &self.base_path {
+            Some(base_path) => format!("{origin}/{base_path}"),
+            None => origin.to_string(),
+        }
+    }
+
+    /// Web frontend url of the repo at `origin` (i.e. `https://host`). Personal repos are under `/users/{user}`
+    fn web_base(&self, origin: &str) -> String {
+        let origin = self.install_base(origin);
+        match self.project_key.strip_prefix('~') {
+            Some(user) => format!("{origin}/users/{user}/repos/{}", self.repo),
+            None => format!("{origin}/projects/{}/repos/{}", self.project_key, self.repo),
+        }
+    }
+}
+
+impl ToWebUrl for BitbucketServerProvider {
+    /// Personal repos are under `/users/{user}`. Blame isn't linkable
+    fn web_url(&self, url: &GitUrl, target: WebTarget) -> Result<String, GitUrlParseError> {
+        let base = self.web_base(&web_origin(url)?);
+
+        let url = match target {
+            WebTarget::Repo => format!("{base}/browse"),
+            WebTarget::Tree { git_ref, path } => format!(
+                "{base}/browse/{}?at={}",
+                path.map(encode_path).unwrap_or_default(),
+                encode_component(git_ref)
+            ),
+            WebTarget::Blob {
+                git_ref,
+                path,
+                lines,
+            } => format!(
+                "{base}/browse/{}?at={}{}",
+                encode_path(path),
+                encode_component(git_ref),
+                lines.map(|l| format!("#{l}")).unwrap_or_default()
+            ),
+            WebTarget::Commit { sha } => format!("{base}/commits/{}", encode_path(sha)),
+            WebTarget::Compare { base: from, head } => format!(
+                "{base}/compare/diff?sourceBranch={}&targetBranch={}",
+                encode_component(head),
+                encode_component(from)
+            ),
+            WebTarget::Blame { .. } => return Err(GitUrlParseError::ProviderUnsupported),
+        };
+
+        Ok(url)
+    }
+}
+
+impl ToApiUrl for BitbucketCloudProvider {
+    /// Bitbucket Cloud is always served by `api.bitbucket.org`
+    fn api_base_url(&self, url: &GitUrl) -> Result<String, GitUrlParseError> {
+        web_origin(url)?;
+        Ok("https://api.bitbucket.org/2.0".to_string())
+    }
+
+    fn api_url(&self, url: &GitUrl, resource: ApiResource) -> Result<String, GitUrlParseError> {
+        let repo = format!(
+            "{}/repositories/{}/{}",
+            self.api_base_url(url)?,
+            encode_component(&self.workspace),
+            encode_component(&self.repo)
+        );
+
+        let url = match resource {
+            ApiResource::Repo => repo,
+            ApiResource::Branches => format!("{repo}/refs/branches"),
+            ApiResource::PullRequests => format!("{repo}/pullrequests"),
+            ApiResource::File { git_ref, path } => format!(
+                "{repo}/src/{}/{}",
+                encode_component(git_ref),
+                encode_path(path)
+            ),
+        };
+
+        Ok(url)
+    }
+}
+
+impl ToApiUrl for BitbucketServerProvider {
+    fn api_base_url(&self, url: &GitUrl) -> Result<String, GitUrlParseError> {
+        Ok(format!(
+            "{}/rest/api/1.0",
+            self.install_base(&web_origin(url)?)
+        ))
+    }
+
+    /// Personal repos are under the `~{user}` project
+    fn api_url(&self, url: &GitUrl, resource: ApiResource) -> Result<String, GitUrlParseError> {
+        let repo = format!(
+            "{}/projects/{}/repos/{}",
+            self.api_base_url(url)?,
+            encode_component(&self.project_key),
+            encode_component(&self.repo)
+        );
+
+        let url = match resource {
+            ApiResource::Repo => repo,
+            ApiResource::Branches => format!("{repo}/branches"),
+            ApiResource::PullRequests => format!("{repo}/pull-requests"),
+            ApiResource::File { git_ref, path } => format!(
+                "{repo}/raw/{}?at={}",
+                encode_path(path),
+                encode_component(git_ref)
+            ),
+        };
+
+        Ok(url)
+    }
+}
+
+/// Split a url path into its non-empty segments
+fn split_path(input: &str) -> Vec<&str> {
+    input.split('/').filter(|s| !s.is_empty()).collect()
+}
+
+/// Remove the `.git` suffix from a repo name, if present
+fn trim_git_suffix(repo: &str) -> &str {
+    repo.strip_suffix(".git").unwrap_or(repo)
+}
+
+impl ToRawFileUrl for BitbucketCloudProvider {
+    fn raw_file_url(
+        &self,
+        url: &GitUrl,
+        git_ref: &str,
+        path: &str,
+    ) -> Result<String, GitUrlParseError> {
+        Ok(format!(
+            "{}/{}/raw/{}/{}",
+            web_origin(url)?,
+            self.fullname(),
+            encode_path(git_ref),
+            encode_path(path)
+        ))
+    }
+}
+
+impl ToRawFileUrl for BitbucketServerProvider {
+    fn raw_file_url(
+        &self,
+        url: &GitUrl,
+        git_ref: &str,
+        path: &str,
+    ) -> Result<String, GitUrlParseError> {
+        Ok(format!(
+            "{}/raw/{}?at={}",
+            self.web_base(&web_origin(url)?),
+            encode_path(path),
+            encode_component(git_ref)
+        ))
+    }
+}
@@ -0,0 +1,324 @@
+use super::web::{decode, encode_component, encode_path};
+use super::{CloneProtocol, GitProvider, ToGitUrl};
+use crate::types::GitUrlParseHint;
+use crate::{GitUrl, GitUrlParseError};
+
+use getset::{CopyGetters, Getters};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "url")]
+use url::Url;
+
+/// Path segments that follow the project in cgit web urls
+const CGIT_PAGES: &[&str] = &[
+    "about", "atom", "blame", "blob", "commit", "diff", "log", "patch", "plain", "rawdiff", "refs",
+    "snapshot", "stats", "summary", "tag", "tree",
+];
+
+/// Leading path segments of cgit installs that are not part of the project
+const CGIT_BASES: &[&str] = &["cgit", "cgit.cgi"];
+
+/// Last path segment of gitweb urls, where the project is in the query
+const GITWEB_SCRIPTS: &[&str] = &["gitweb.cgi", "gitweb.perl", "gitweb"];
+
+/// The web frontend serving the repos
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CgitFlavor {
+    /// cgit, with the project in the path (i.e. `/project.git/tree/`)
+    #[default]
+    Cgit,
+    /// gitweb, with the project in the query (i.e. `/?p=project.git;a=summary`)
+    Gitweb,
+}
+
+/// ## cgit and gitweb repository provider
+///
+/// ## Supported URL Formats
+///
+/// - `https://git.kernel.org/pub/scm/linux/kernel/git/torvalds/linux.git`
+/// - `git://git.savannah.gnu.org/emacs.git`
+/// - `https://host/cgit/project.git/tree/src` (cgit web urls)
+/// - `https://host/?p=project.git;a=summary` (gitweb, with [`CgitProvider::from_gitweb_url()`])
+///
+/// The whole path is the project, since these hosts don't have owners.
+/// [`GitUrl`] drops the query of urls, so gitweb urls are parsed from strings or `url::Url`
+///
+/// ## Examples
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::provider::{CgitFlavor, CgitProvider};
+///
+/// fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+///     let url = GitUrl::parse("https://git.kernel.org/pub/scm/linux/kernel/git/torvalds/linux.git")?;
+///     let provider: CgitProvider = url.provider_info()?;
+///     assert_eq!(provider.project(), "pub/scm/linux/kernel/git/torvalds/linux.git");
+///     assert_eq!(
+///         provider.commit_url("abc123"),
+///         "https://git.kernel.org/pub/scm/linux/kernel/git/torvalds/linux.git/commit/?id=abc123"
+///     );
+///
+///     let provider = CgitProvider::from_gitweb_url("https://repo.example.com/gitweb.cgi?p=tools/hello.git;a=summary")?;
+///     assert_eq!(provider.flavor(), CgitFlavor::Gitweb);
+///     assert_eq!(provider.project(), "tools/hello.git");
+///     assert_eq!(
+///         provider.blob_url("main", "README"),
+///         "https://repo.example.com/gitweb.cgi?p=tools/hello.git;a=blob;f=README;hb=main"
+///     );
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Getters, CopyGetters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CgitProvider {
+    /// Lowercase host of the repo
+    #[getset(get = "pub")]
+    host: String,
+    /// Path of the web frontend before the project (i.e. `cgit` or `gitweb.cgi`)
+    web_base: Option<String>,
+    /// Full project path, as used by the web frontend (i.e. `pub/scm/git/git.git`)
+    #[getset(get = "pub")]
+    project: String,
+    /// Web frontend of the host
+    #[getset(get_copy = "pub")]
+    flavor: CgitFlavor,
+    /// If the web frontend is served over plain http, as the http repo url was
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[getset(get_copy = "pub")]
+    http: bool,
+    /// Port of the web frontend, kept from http and https repo urls
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[getset(get_copy = "pub")]
+    web_port: Option<u16>,
+}
+
+impl CgitProvider {
+    /// Path of the web frontend before the project (i.e. `cgit` or `gitweb.cgi`)
+    pub fn web_base(&self) -> Option<&str> {
+        self.web_base.as_deref()
+    }
+
+    /// Helper method to get the full name of a repo, which is the project path
+    pub fn fullname(&self) -> String {
+        self.project.clone()
+    }
+
+    /// Url of the commit page of `sha`
+    pub fn commit_url(&self, sha: &str) -> String {
+        let sha = encode_component(sha);
+
+        match self.flavor {
+            CgitFlavor::Cgit => format!("{}/commit/?id={sha}", self.cgit_root()),
+            CgitFlavor::Gitweb => format!("{};a=commit;h={sha}", self.gitweb_root()),
+        }
+    }
+
+    /// Url of the tree page of `git_ref`, at the root or at `path`
+    pub fn tree_url(&self, git_ref: &str, path: Option<&str>) -> String {
+        let path = path.map(encode_path).unwrap_or_default();
+        let git_ref = encode_component(git_ref);
+
+        match self.flavor {
+            CgitFlavor::Cgit => format!("{}/tree/{path}?id={git_ref}", self.cgit_root()),
+            CgitFlavor::Gitweb if path.is_empty() => {
+                format!("{};a=tree;hb={git_ref}", self.gitweb_root())
+            }
+            CgitFlavor::Gitweb => {
+                format!("{};a=tree;f={path};hb={git_ref}", self.gitweb_root())
+            }
+        }
+    }
+
+    /// Url of the page of the file at `path` in `git_ref`
+    pub fn blob_url(&self, git_ref: &str, path: &str) -> String {
+        let path = encode_path(path);
+        let git_ref = encode_component(git_ref);
+
+        match self.flavor {
+            CgitFlavor::Cgit => format!("{}/blob/{path}?id={git_ref}", self.cgit_root()),
+            CgitFlavor::Gitweb => {
+                format!("{};a=blob;f={path};hb={git_ref}", self.gitweb_root())
+            }
+        }
+    }
+
+    /// Scheme of web urls: `http` for http repo urls, otherwise `https`.
+    /// `git://` and ssh urls don't say how the web frontend is served, so it's assumed to be https
+    pub fn web_scheme(&self) -> &'static str {
+        if self.http { "http" } else { "https" }
+    }
+
+    /// Parse a gitweb url with the project in the `p` query parameter
+    pub fn from_gitweb_url(input: &str) -> Result<CgitProvider, GitUrlParseError> {
+        let (url, query) = input.split_once('?').ok_or_else(|| {
+            GitUrlParseError::ProviderParseFail(
+                "Gitweb url expected: ex. 'https://host/?p=project.git'".into(),
+            )
+        })?;
+
+        let url = GitUrl::parse(url)?;
+
+        Self::parse_gitweb(url.host(), url.path(), query)
+            .map(|provider| provider.with_web_origin(url.scheme(), url.port()))
+    }
+
+    /// Keep the scheme and port of http and https repo urls for web urls
+    fn with_web_origin(mut self, scheme: Option<&str>, port: Option<u16>) -> Self {
+        let web = matches!(scheme, Some("http" | "https"));
+        self.http = scheme == Some("http");
+        self.web_port = port.filter(|_| web);
+        self
+    }
+
+    /// `{scheme}://{host}[:{port}]` of web urls
+    fn web_origin(&self) -> String {
+        match self.web_port {
+            Some(port) => format!("{}://{}:{port}", self.web_scheme(), self.host),
+            None => format!("{}://{}", self.web_scheme(), self.host),
+        }
+    }
+
+    /// Root of cgit web urls for the project
+    fn cgit_root(&self) -> String {
+        let project = encode_path(&self.project);
+
+        match &self.web_base {
+            Some(base) => format!("{}/{}/{project}", self.web_origin(), encode_path(base)),
+            None => format!("{}/{project}", self.web_origin()),
+        }
+    }
+
+    /// Root of gitweb web urls for the project, up to the `p` query parameter
+    fn gitweb_root(&self) -> String {
+        let base = self
+            .web_base
+            .as_deref()
+            .map(encode_path)
+            .unwrap_or_default();
+        format!(
+            "{}/{base}?p={}",
+            self.web_origin(),
+            encode_path(&self.project)
+        )
+    }
+
+    /// Lowercase `host`, or an error without host
+    fn require_host(host: Option<&str>) -> Result<String, GitUrlParseError> {
+        host.map(|h| h.to_ascii_lowercase())
+            .ok_or_else(|| GitUrlParseError::ProviderParseFail("cgit url needs a host".into()))
+    }
+
+    /// Parse the host, script path and query of a gitweb url
+    fn parse_gitweb(
+        host: Option<&str>,
+        path: &str,
+        query: &str,
+    ) -> Result<CgitProvider, GitUrlParseError> {
+        let host = Self::require_host(host)?;
+
+        let project = query
+            .split([';', '&'])
+            .find_map(|param| param.strip_prefix("p="))
+            .map(decode)
+            .map(|p| p.trim_matches('/').to_string())
+            .filter(|p| !p.is_empty())
+            .ok_or_else(|| {
+                GitUrlParseError::ProviderParseFail(
+                    "Gitweb url needs a 'p' query parameter: ex. '?p=project.git'".into(),
+                )
+            })?;
+
+        let web_base = path.trim_matches('/');
+
+        Ok(CgitProvider {
+            host,
+            web_base: (!web_base.is_empty()).then(|| web_base.to_string()),
+            project,
+            flavor: CgitFlavor::Gitweb,
+            http: false,
+            web_port: None,
+        })
+    }
+
+    /// Parse the host and path of url for cgit patterns
+    fn parse_path(host: Option<&str>, input: &str) -> Result<CgitProvider, GitUrlParseError> {
+        let host = Self::require_host(host)?;
+
+        let parts: Vec<&str> = input.split('/').filter(|s| !s.is_empty()).collect();
+
+        if parts.last().is_some_and(|p| GITWEB_SCRIPTS.contains(p)) {
+            return Err(GitUrlParseError::ProviderParseFail(
+                "Gitweb project is in the query: parse with 'CgitProvider::from_gitweb_url()'"
+                    .into(),
+            ));
+        }
+
+        let (web_base, parts) = match parts.as_slice() {
+            [base, rest @ ..] if CGIT_BASES.contains(base) && !rest.is_empty() => {
+                (Some(*base), rest)
+            }
+            parts => (None, parts),
+        };
+
+        // Ignore web pages after the project
+        let end = parts
+            .iter()
+            .skip(1)
+            .position(|p| CGIT_PAGES.contains(p))
+            .map_or(parts.len(), |i| i + 1);
+
+        if end == 0 {
+            return Err(GitUrlParseError::ProviderParseFail(
+                "Path needs a project: ex. '/project.git'".into(),
+            ));
+        }
+
+        Ok(CgitProvider {
+            host,
+            web_base: web_base.map(|b| b.to_string()),
+            project: parts[..end].join("/"),
+            flavor: CgitFlavor::Cgit,
+            http: false,
+            web_port: None,
+        })
+    }
+}
+
+impl GitProvider<GitUrl, GitUrlParseError> for CgitProvider {
+    fn from_git_url(url: &GitUrl) -> Result<Self, GitUrlParseError> {
+        if url.hint() == GitUrlParseHint::Filelike {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Self::parse_path(url.host(), url.path())
+            .map(|provider| provider.with_web_origin(url.scheme(), url.port()))
+    }
+}
+
+#[cfg(feature = "url")]
+impl GitProvider<Url, GitUrlParseError> for CgitProvider {
+    fn from_git_url(url: &Url) -> Result<Self, GitUrlParseError> {
+        if url.scheme() == "file" {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        let provider = match url.query() {
+            Some(query) if query.split([';', '&']).any(|p| p.starts_with("p=")) => {
+                Self::parse_gitweb(url.host_str(), url.path(), query)
+            }
+            _ => Self::parse_path(url.host_str(), url.path()),
+        };
+
+        provider.map(|provider| provider.with_web_origin(Some(url.scheme()), url.port()))
+    }
+}
+
+impl ToGitUrl for CgitProvider {
+    fn clone_path(&self, _protocol: CloneProtocol) -> String {
+        self.project.clone()
+    }
+}
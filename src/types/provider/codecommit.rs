@@ -0,0 +1,309 @@
+use super::{CloneProtocol, GitProvider, ToGitUrl};
+use crate::types::GitUrlParseHint;
+use crate::{GitUrl, GitUrlParseError};
+
+use getset::{CopyGetters, Getters};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "url")]
+use url::Url;
+
+/// Scheme of the `git-remote-codecommit` helper urls
+const HELPER_SCHEME: &str = "codecommit";
+
+/// The endpoint flavour of a CodeCommit host
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CodeCommitEndpoint {
+    /// `git-codecommit.<region>.amazonaws.com`
+    #[default]
+    Standard,
+    /// `git-codecommit-fips.<region>.amazonaws.com`
+    Fips,
+    /// `git-codecommit.<region>.amazonaws.com.cn`
+    China,
+}
+
+/// ## AWS CodeCommit repository provider
+///
+/// ## Supported URL Formats
+///
+/// - `https://git-codecommit.<region>.amazonaws.com/v1/repos/<name>`
+/// - `ssh://<key id>@git-codecommit.<region>.amazonaws.com/v1/repos/<name>`
+/// - `https://git-codecommit-fips.<region>.amazonaws.com/v1/repos/<name>`
+/// - `https://git-codecommit.<region>.amazonaws.com.cn/v1/repos/<name>`
+/// - `codecommit::<region>://<profile>@<name>` (`git-remote-codecommit` helper)
+///
+/// The helper urls can be parsed with [`CodeCommitProvider::from_helper_url()`].
+/// The region and profile are optional in helper urls, the AWS config is used for missing values
+///
+/// ## Examples
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::provider::{CodeCommitEndpoint, CodeCommitProvider};
+///
+/// fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+///     let url = GitUrl::parse("https://git-codecommit.us-east-2.amazonaws.com/v1/repos/MyDemoRepo")?;
+///     let provider: CodeCommitProvider = url.provider_info()?;
+///     assert_eq!(provider.region(), Some("us-east-2"));
+///     assert_eq!(provider.repo(), "MyDemoRepo");
+///     assert_eq!(provider.endpoint(), CodeCommitEndpoint::Standard);
+///     assert_eq!(provider.helper_url(), "codecommit::us-east-2://MyDemoRepo");
+///
+///     let provider = CodeCommitProvider::from_helper_url("codecommit::us-east-2://dev@MyDemoRepo")?;
+///     assert_eq!(provider.profile(), Some("dev"));
+///     assert_eq!(
+///         provider.ssh_url(Some("APKAEIBAERJR2EXAMPLE")).as_deref(),
+///         Some("ssh://APKAEIBAERJR2EXAMPLE@git-codecommit.us-east-2.amazonaws.com/v1/repos/MyDemoRepo")
+///     );
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Getters, CopyGetters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CodeCommitProvider {
+    /// AWS region, if known
+    region: Option<String>,
+    /// Repo name
+    #[getset(get = "pub")]
+    repo: String,
+    /// AWS credentials profile, from helper urls
+    profile: Option<String>,
+    /// Endpoint flavour of the host
+    #[getset(get_copy = "pub")]
+    endpoint: CodeCommitEndpoint,
+}
+
+impl CodeCommitProvider {
+    /// AWS region, if known. Helper urls without region use the AWS config
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+
+    /// AWS credentials profile, from helper urls
+    pub fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+
+    /// Helper method to get the full name of a repo: `{region}/{repo}`, or `{repo}` without region
+    pub fn fullname(&self) -> String {
+        match &self.region {
+            Some(region) => format!("{region}/{}", self.repo),
+            None => self.repo.clone(),
+        }
+    }
+
+    /// Git host of the repo's region and endpoint. Returns `None` without region
+    pub fn host(&self) -> Option<String> {
+        let region = self.region.as_ref()?;
+
+        Some(match self.endpoint {
+            CodeCommitEndpoint::Standard => format!("git-codecommit.{region}.amazonaws.com"),
+            CodeCommitEndpoint::Fips => format!("git-codecommit-fips.{region}.amazonaws.com"),
+            CodeCommitEndpoint::China => format!("git-codecommit.{region}.amazonaws.com.cn"),
+        })
+    }
+
+    /// HTTPS (GRC) clone url. Returns `None` without region
+    pub fn https_url(&self) -> Option<String> {
+        Some(format!("https://{}/v1/repos/{}", self.host()?, self.repo))
+    }
+
+    /// SSH clone url, with the IAM SSH key id as user. Returns `None` without region
+    pub fn ssh_url(&self, ssh_key_id: Option<&str>) -> Option<String> {
+        let user = ssh_key_id.map(|id| format!("{id}@")).unwrap_or_default();
+        Some(format!(
+            "ssh://{user}{}/v1/repos/{}",
+            self.host()?,
+            self.repo
+        ))
+    }
+
+    /// `git-remote-codecommit` helper url: `codecommit::{region}://{profile}@{repo}`
+    pub fn helper_url(&self) -> String {
+        let region = self
+            .region
+            .as_ref()
+            .map(|r| format!("::{r}"))
+            .unwrap_or_default();
+        let profile = self
+            .profile
+            .as_ref()
+            .map(|p| format!("{p}@"))
+            .unwrap_or_default();
+
+        format!("{HELPER_SCHEME}{region}://{profile}{}", self.repo)
+    }
+
+    /// Parse a `git-remote-codecommit` helper url: `codecommit://[profile@]repo` or `codecommit::region://[profile@]repo`
+    pub fn from_helper_url(input: &str) -> Result<CodeCommitProvider, GitUrlParseError> {
+        let invalid = || {
+            GitUrlParseError::ProviderParseFail(
+                "Helper url expected: ex. 'codecommit::region://profile@repo'".into(),
+            )
+        };
+
+        let rest = input
+            .strip_prefix(HELPER_SCHEME)
+            .and_then(|rest| rest.strip_prefix(':'))
+            .ok_or_else(invalid)?;
+
+        let (region, rest) = match rest.strip_prefix(':') {
+            Some(rest) => {
+                let (region, rest) = rest.split_once("://").ok_or_else(invalid)?;
+                (Some(region), rest)
+            }
+            None => (None, rest.strip_prefix("//").ok_or_else(invalid)?),
+        };
+
+        let (profile, repo) = match rest.split_once('@') {
+            Some((profile, repo)) => (Some(profile), repo),
+            None => (None, rest),
+        };
+
+        if let Some(region) = region {
+            Self::validate_region(region)?;
+        }
+        if profile.is_some_and(|p| p.is_empty()) {
+            return Err(GitUrlParseError::ProviderInvalidName(
+                "CodeCommit profile can't be empty".into(),
+            ));
+        }
+        Self::validate_repo(repo)?;
+
+        let endpoint = match region {
+            Some(region) if region.starts_with("cn-") => CodeCommitEndpoint::China,
+            _ => CodeCommitEndpoint::Standard,
+        };
+
+        Ok(CodeCommitProvider {
+            region: region.map(|r| r.to_string()),
+            repo: repo.to_string(),
+            profile: profile.map(|p| p.to_string()),
+            endpoint,
+        })
+    }
+
+    /// Parse the host and path of url for CodeCommit patterns
+    fn parse_path(host: Option<&str>, input: &str) -> Result<CodeCommitProvider, GitUrlParseError> {
+        let host = host.unwrap_or_default().to_ascii_lowercase();
+
+        let (service, region, endpoint) = match host.strip_suffix(".amazonaws.com.cn") {
+            Some(rest) => rest
+                .split_once('.')
+                .map(|(service, region)| (service, region, CodeCommitEndpoint::China)),
+            None => host.strip_suffix(".amazonaws.com").and_then(|rest| {
+                rest.split_once('.')
+                    .map(|(service, region)| (service, region, CodeCommitEndpoint::Standard))
+            }),
+        }
+        .ok_or_else(|| {
+            GitUrlParseError::ProviderParseFail(format!("Not a CodeCommit host: '{host}'"))
+        })?;
+
+        let endpoint = match (service, endpoint) {
+            ("git-codecommit", endpoint) => endpoint,
+            ("git-codecommit-fips", CodeCommitEndpoint::Standard) => CodeCommitEndpoint::Fips,
+            _ => {
+                return Err(GitUrlParseError::ProviderParseFail(format!(
+                    "Not a CodeCommit host: '{host}'"
+                )));
+            }
+        };
+
+        let parts: Vec<&str> = input.split('/').filter(|s| !s.is_empty()).collect();
+
+        let repo = match parts.as_slice() {
+            ["v1", "repos", repo] => *repo,
+            _ => {
+                return Err(GitUrlParseError::ProviderParseFail(
+                    "Path expected: ex. '/v1/repos/repo'".into(),
+                ));
+            }
+        };
+
+        Self::validate_region(region)?;
+        Self::validate_repo(repo)?;
+
+        Ok(CodeCommitProvider {
+            region: Some(region.to_string()),
+            repo: repo.to_string(),
+            profile: None,
+            endpoint,
+        })
+    }
+
+    /// AWS region names: ascii lowercase letters, digits and `-` (i.e. `us-east-1`)
+    fn validate_region(region: &str) -> Result<(), GitUrlParseError> {
+        let valid = !region.is_empty()
+            && region
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+
+        if valid {
+            Ok(())
+        } else {
+            Err(GitUrlParseError::ProviderInvalidName(format!(
+                "AWS region must be ascii lowercase letters, digits or '-': '{region}'"
+            )))
+        }
+    }
+
+    /// CodeCommit repo names: 1-100 ascii letters, digits, `.`, `-` or `_`
+    fn validate_repo(repo: &str) -> Result<(), GitUrlParseError> {
+        let valid = !repo.is_empty()
+            && repo.len() <= 100
+            && repo
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '_');
+
+        if valid {
+            Ok(())
+        } else {
+            Err(GitUrlParseError::ProviderInvalidName(format!(
+                "CodeCommit repo must be 1-100 ascii letters, digits, '.', '-' or '_': '{repo}'"
+            )))
+        }
+    }
+}
+
+impl GitProvider<GitUrl, GitUrlParseError> for CodeCommitProvider {
+    fn from_git_url(url: &GitUrl) -> Result<Self, GitUrlParseError> {
+        if url.hint() == GitUrlParseHint::Filelike {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        // `codecommit::region://repo` is parsed as scp-like, with `codecommit` host
+        if url.host() == Some(HELPER_SCHEME) {
+            return Self::from_helper_url(&url.to_string());
+        }
+
+        Self::parse_path(url.host(), url.path())
+    }
+}
+
+#[cfg(feature = "url")]
+impl GitProvider<Url, GitUrlParseError> for CodeCommitProvider {
+    fn from_git_url(url: &Url) -> Result<Self, GitUrlParseError> {
+        match url.scheme() {
+            "file" => Err(GitUrlParseError::ProviderUnsupported),
+            HELPER_SCHEME => Self::from_helper_url(url.as_str()),
+            _ => Self::parse_path(url.host_str(), url.path()),
+        }
+    }
+}
+
+impl ToGitUrl for CodeCommitProvider {
+    fn clone_path(&self, _protocol: CloneProtocol) -> String {
+        format!("v1/repos/{}", self.repo)
+    }
+
+    /// The ssh user is the IAM SSH key id, which isn't part of the provider info
+    fn ssh_user(&self) -> Option<&str> {
+        None
+    }
+}
@@ -0,0 +1,421 @@
+use super::{
+    AzureDevOpsProvider, BitbucketCloudProvider, BitbucketServerProvider, CgitProvider,
+    CodeCommitProvider, GenericProvider, GerritProvider, GitHubProvider, GitLabProvider,
+    GiteaProvider, GoogleSourceProvider, HuggingFaceProvider, LaunchpadProvider, PagureProvider,
+    PatternCaptures, ProviderRegistry, SourceHutProvider, ToGitUrl,
+};
+use crate::types::GitUrlParseHint;
+use crate::{GitUrl, GitUrlParseError};
+
+use getset::{CopyGetters, Getters};
+#[cfg(feature = "log")]
+use log::debug;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The built-in provider types, without any parsed provider info
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum ProviderKind {
+    /// [`GenericProvider`]
+    #[cfg_attr(feature = "serde", serde(rename = "generic"))]
+    Generic,
+    /// [`GitHubProvider`]
+    #[cfg_attr(feature = "serde", serde(rename = "github"))]
+    GitHub,
+    /// [`GitLabProvider`]
+    #[cfg_attr(feature = "serde", serde(rename = "gitlab"))]
+    GitLab,
+    /// [`AzureDevOpsProvider`]
+    #[cfg_attr(feature = "serde", serde(rename = "azure_devops"))]
+    AzureDevOps,
+    /// [`BitbucketCloudProvider`]
+    #[cfg_attr(feature = "serde", serde(rename = "bitbucket_cloud"))]
+    BitbucketCloud,
+    /// [`BitbucketServerProvider`]
+    #[cfg_attr(feature = "serde", serde(rename = "bitbucket_server"))]
+    BitbucketServer,
+    /// [`GiteaProvider`]
+    #[cfg_attr(feature = "serde", serde(rename = "gitea"))]
+    Gitea,
+    /// [`SourceHutProvider`]
+    #[cfg_attr(feature = "serde", serde(rename = "sourcehut"))]
+    SourceHut,
+    /// [`CodeCommitProvider`]
+    #[cfg_attr(feature = "serde", serde(rename = "codecommit"))]
+    CodeCommit,
+    /// [`GerritProvider`]
+    #[cfg_attr(feature = "serde", serde(rename = "gerrit"))]
+    Gerrit,
+    /// [`HuggingFaceProvider`]
+    #[cfg_attr(feature = "serde", serde(rename = "huggingface"))]
+    HuggingFace,
+    /// [`LaunchpadProvider`]
+    #[cfg_attr(feature = "serde", serde(rename = "launchpad"))]
+    Launchpad,
+    /// [`CgitProvider`]
+    #[cfg_attr(feature = "serde", serde(rename = "cgit"))]
+    Cgit,
+    /// [`PagureProvider`]
+    #[cfg_attr(feature = "serde", serde(rename = "pagure"))]
+    Pagure,
+    /// [`GoogleSourceProvider`]
+    #[cfg_attr(feature = "serde", serde(rename = "google_source"))]
+    GoogleSource,
+    /// [`PatternCaptures`]
+    #[cfg_attr(feature = "serde", serde(rename = "pattern"))]
+    Pattern,
+}
+
+impl ProviderKind {
+    /// Extract provider info from `url` using the provider type of this kind.
+    ///
+    /// [`ProviderKind::Pattern`] returns [`GitUrlParseError::ProviderUnsupported`], since the path patterns
+    /// are held by the registry. Use [`ProviderEntry::parse()`](super::ProviderEntry::parse) instead
+    pub fn parse(&self, url: &GitUrl) -> Result<AnyProvider, GitUrlParseError> {
+        match self {
+            ProviderKind::Generic => url.provider_info().map(AnyProvider::Generic),
+            ProviderKind::GitHub => url.provider_info().map(AnyProvider::GitHub),
+            ProviderKind::GitLab => url.provider_info().map(AnyProvider::GitLab),
+            ProviderKind::AzureDevOps => url.provider_info().map(AnyProvider::AzureDevOps),
+            ProviderKind::BitbucketCloud => url.provider_info().map(AnyProvider::BitbucketCloud),
+            ProviderKind::BitbucketServer => url.provider_info().map(AnyProvider::BitbucketServer),
+            ProviderKind::Gitea => url.provider_info().map(AnyProvider::Gitea),
+            ProviderKind::SourceHut => url.provider_info().map(AnyProvider::SourceHut),
+            ProviderKind::CodeCommit => url.provider_info().map(AnyProvider::CodeCommit),
+            ProviderKind::Gerrit => url.provider_info().map(AnyProvider::Gerrit),
+            ProviderKind::HuggingFace => url.provider_info().map(AnyProvider::HuggingFace),
+            ProviderKind::Launchpad => url.provider_info().map(AnyProvider::Launchpad),
+            ProviderKind::Cgit => url.provider_info().map(AnyProvider::Cgit),
+            ProviderKind::Pagure => url.provider_info().map(AnyProvider::Pagure),
+            ProviderKind::GoogleSource => url.provider_info().map(AnyProvider::GoogleSource),
+            // Path patterns are held by the registry entry, see `ProviderEntry::parse()`
+            ProviderKind::Pattern => Err(GitUrlParseError::ProviderUnsupported),
+        }
+    }
+}
+
+/// Provider info from any of the built-in providers
+///
+/// Returned by [`GitUrl::detect_provider()`](crate::GitUrl::detect_provider)
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum AnyProvider {
+    /// Generic `owner/repo` provider info
+    Generic(GenericProvider),
+    /// GitHub provider info
+    GitHub(GitHubProvider),
+    /// GitLab provider info
+    GitLab(GitLabProvider),
+    /// Azure DevOps provider info
+    AzureDevOps(AzureDevOpsProvider),
+    /// Bitbucket Cloud provider info
+    BitbucketCloud(BitbucketCloudProvider),
+    /// Bitbucket Server / Data Center provider info
+    BitbucketServer(BitbucketServerProvider),
+    /// Gitea, Forgejo or Codeberg provider info
+    Gitea(GiteaProvider),
+    /// SourceHut provider info
+    SourceHut(SourceHutProvider),
+    /// AWS CodeCommit provider info
+    CodeCommit(CodeCommitProvider),
+    /// Gerrit provider info
+    Gerrit(GerritProvider),
+    /// Hugging Face Hub provider info
+    HuggingFace(HuggingFaceProvider),
+    /// Launchpad provider info
+    Launchpad(LaunchpadProvider),
+    /// cgit and gitweb provider info
+    Cgit(CgitProvider),
+    /// Pagure provider info
+    Pagure(PagureProvider),
+    /// Google Cloud Source Repositories and Secure Source Manager provider info
+    GoogleSource(GoogleSourceProvider),
+    /// Captures of a host registered with path patterns
+    Pattern(PatternCaptures),
+}
+
+impl AnyProvider {
+    /// The provider type of the parsed provider info
+    pub fn kind(&self) -> ProviderKind {
+        match self {
+            AnyProvider::Generic(_) => ProviderKind::Generic,
+            AnyProvider::GitHub(_) => ProviderKind::GitHub,
+            AnyProvider::GitLab(_) => ProviderKind::GitLab,
+            AnyProvider::AzureDevOps(_) => ProviderKind::AzureDevOps,
+            AnyProvider::BitbucketCloud(_) => ProviderKind::BitbucketCloud,
+            AnyProvider::BitbucketServer(_) => ProviderKind::BitbucketServer,
+            AnyProvider::Gitea(_) => ProviderKind::Gitea,
+            AnyProvider::SourceHut(_) => ProviderKind::SourceHut,
+            AnyProvider::CodeCommit(_) => ProviderKind::CodeCommit,
+            AnyProvider::Gerrit(_) => ProviderKind::Gerrit,
+            AnyProvider::HuggingFace(_) => ProviderKind::HuggingFace,
+            AnyProvider::Launchpad(_) => ProviderKind::Launchpad,
+            AnyProvider::Cgit(_) => ProviderKind::Cgit,
+            AnyProvider::Pagure(_) => ProviderKind::Pagure,
+            AnyProvider::GoogleSource(_) => ProviderKind::GoogleSource,
+            AnyProvider::Pattern(_) => ProviderKind::Pattern,
+        }
+    }
+
+    /// Helper method to get the full name of a repo, formatted by the provider
+    pub fn fullname(&self) -> String {
+        match self {
+            AnyProvider::Generic(p) => p.fullname(),
+            AnyProvider::GitHub(p) => p.fullname(),
+            AnyProvider::GitLab(p) => p.fullname(),
+            AnyProvider::AzureDevOps(p) => p.fullname(),
+            AnyProvider::BitbucketCloud(p) => p.fullname(),
+            AnyProvider::BitbucketServer(p) => p.fullname(),
+            AnyProvider::Gitea(p) => p.fullname(),
+            AnyProvider::SourceHut(p) => p.fullname(),
+            AnyProvider::CodeCommit(p) => p.fullname(),
+            AnyProvider::Gerrit(p) => p.fullname(),
+            AnyProvider::HuggingFace(p) => p.fullname(),
+            AnyProvider::Launchpad(p) => p.fullname(),
+            AnyProvider::Cgit(p) => p.fullname(),
+            AnyProvider::Pagure(p) => p.fullname(),
+            AnyProvider::GoogleSource(p) => p.fullname(),
+            AnyProvider::Pattern(p) => p.fullname(),
+        }
+    }
+
+    /// The provider info for rendering clone urls, or `None` for path pattern captures
+    pub(crate) fn as_to_git_url(&self) -> Option<&dyn ToGitUrl> {
+        match self {
+            AnyProvider::Generic(p) => Some(p),
+            AnyProvider::GitHub(p) => Some(p),
+            AnyProvider::GitLab(p) => Some(p),
+            AnyProvider::AzureDevOps(p) => Some(p),
+            AnyProvider::BitbucketCloud(p) => Some(p),
+            AnyProvider::BitbucketServer(p) => Some(p),
+            AnyProvider::Gitea(p) => Some(p),
+            AnyProvider::SourceHut(p) => Some(p),
+            AnyProvider::CodeCommit(p) => Some(p),
+            AnyProvider::Gerrit(p) => Some(p),
+            AnyProvider::HuggingFace(p) => Some(p),
+            AnyProvider::Launchpad(p) => Some(p),
+            AnyProvider::Cgit(p) => Some(p),
+            AnyProvider::Pagure(p) => Some(p),
+            AnyProvider::GoogleSource(p) => Some(p),
+            AnyProvider::Pattern(_) => None,
+        }
+    }
+}
+
+/// How much evidence provider detection found for the detected provider
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Confidence {
+    /// Fallback when no host or path patterns matched
+    Low,
+    /// Matched by host naming or a provider-specific path pattern
+    Medium,
+    /// Matched by a known provider host
+    High,
+}
+
+/// Provider info found by [`GitUrl::detect_provider()`](crate::GitUrl::detect_provider)
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::provider::{AnyProvider, Confidence, ProviderKind};
+///
+/// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+/// let url = GitUrl::parse("https://gitlab.com/gitlab-org/sbom/systems/gitlab-core.git")?;
+/// let detected = url.detect_provider()?;
+///
+/// assert_eq!(detected.kind(), ProviderKind::GitLab);
+/// assert_eq!(detected.confidence(), Confidence::High);
+/// assert_eq!(detected.provider().fullname(), "gitlab-org/sbom/systems/gitlab-core");
+///
+/// if let AnyProvider::GitLab(gitlab) = detected.provider() {
+///     assert_eq!(gitlab.subgroup(), Some(vec!["sbom", "systems"]));
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Getters, CopyGetters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DetectedProvider {
+    /// Parsed provider info
+    #[getset(get = "pub")]
+    provider: AnyProvider,
+    /// How much evidence was found for `provider`
+    #[getset(get_copy = "pub")]
+    confidence: Confidence,
+}
+
+impl DetectedProvider {
+    /// The provider type of the detected provider info
+    pub fn kind(&self) -> ProviderKind {
+        self.provider.kind()
+    }
+
+    /// Consume and return the detected provider info
+    pub fn into_provider(self) -> AnyProvider {
+        self.provider
+    }
+}
+
+/// Detect the provider of `url` from `registry`, the built-in registry, then host and path shape
+pub(crate) fn detect(
+    url: &GitUrl,
+    registry: &ProviderRegistry,
+) -> Result<DetectedProvider, GitUrlParseError> {
+    // Transport hosts don't name a git host service
+    if url.hint() == GitUrlParseHint::Filelike || url.transport().is_some() {
+        return Err(GitUrlParseError::ProviderUnsupported);
+    }
+
+    // Path patterns of registered hosts
+    if let Some(entry) = registry
+        .lookup_url(url)
+        .filter(|entry| entry.provider() == ProviderKind::Pattern)
+    {
+        match entry.parse(url) {
+            Ok(provider) => {
+                return Ok(DetectedProvider {
+                    provider,
+                    confidence: Confidence::High,
+                });
+            }
+            Err(_e) => {
+                #[cfg(feature = "log")]
+                debug!("Registered path patterns failed to parse: {_e:?}");
+            }
+        }
+    }
+
+    for (kind, confidence) in candidates(url, registry) {
+        match kind.parse(url) {
+            Ok(provider) => {
+                #[cfg(feature = "log")]
+                debug!("Detected {kind:?} with {confidence:?} confidence");
+
+                return Ok(DetectedProvider {
+                    provider,
+                    confidence,
+                });
+            }
+            Err(_e) => {
+                #[cfg(feature = "log")]
+                debug!("Candidate {kind:?} failed to parse: {_e:?}");
+            }
+        }
+    }
+
+    // Fallback to generic
+    ProviderKind::Generic
+        .parse(url)
+        .map(|provider| DetectedProvider {
+            provider,
+            confidence: Confidence::Low,
+        })
+}
+
+/// Ordered list of provider kinds to try parsing `url` with
+fn candidates(url: &GitUrl, registry: &ProviderRegistry) -> Vec<(ProviderKind, Confidence)> {
+    let mut candidates = Vec::new();
+
+    let host = url.host().unwrap_or_default().to_ascii_lowercase();
+    let path = url.path().trim_start_matches('/');
+
+    // Registered and known hosts
+    for registry in [registry, ProviderRegistry::builtin_ref()] {
+        if let Some(entry) = registry
+            .lookup_url(url)
+            .filter(|entry| entry.provider() != ProviderKind::Pattern)
+        {
+            candidates.push((entry.provider(), Confidence::High));
+        }
+    }
+
+    // Host naming
+    let labels: Vec<&str> = host.split('.').collect();
+    if labels.iter().any(|label| label.contains("gitlab")) {
+        candidates.push((ProviderKind::GitLab, Confidence::Medium));
+    }
+    if labels.iter().any(|label| label.contains("github")) {
+        candidates.push((ProviderKind::GitHub, Confidence::Medium));
+    }
+    if labels.iter().any(|label| label.contains("bitbucket")) {
+        candidates.push((ProviderKind::BitbucketServer, Confidence::Medium));
+    }
+    if labels
+        .iter()
+        .any(|label| label.contains("gitea") || label.contains("forgejo"))
+    {
+        candidates.push((ProviderKind::Gitea, Confidence::Medium));
+    }
+    if labels.iter().any(|label| label.contains("gerrit")) {
+        candidates.push((ProviderKind::Gerrit, Confidence::Medium));
+    }
+    if labels.iter().any(|label| label.contains("cgit")) {
+        candidates.push((ProviderKind::Cgit, Confidence::Medium));
+    }
+    if labels.iter().any(|label| label.contains("pagure")) {
+        candidates.push((ProviderKind::Pagure, Confidence::Medium));
+    }
+
+    // GitHub Enterprise Server REST API path
+    if path.starts_with("api/v3/repos/") {
+        candidates.push((ProviderKind::GitHub, Confidence::Medium));
+    }
+
+    // Path shape
+    if path.contains("/_git/") || path.contains("/_ssh/") || path.starts_with("v3/") {
+        candidates.push((ProviderKind::AzureDevOps, Confidence::Medium));
+    }
+    if path.starts_with("scm/") || url.port() == Some(7999) {
+        candidates.push((ProviderKind::BitbucketServer, Confidence::Medium));
+    }
+    if ["/src/", "/raw/", "/media/"].iter().any(|web| {
+        ["branch/", "tag/", "commit/"]
+            .iter()
+            .any(|ref_kind| path.contains(&format!("{web}{ref_kind}")))
+    }) {
+        candidates.push((ProviderKind::Gitea, Confidence::Medium));
+    }
+
+    // GitLab web urls
+    if path.contains("/-/") {
+        candidates.push((ProviderKind::GitLab, Confidence::Medium));
+    }
+
+    // Gerrit ssh port and change pages
+    if url.port() == Some(29418) || (path.starts_with("c/") && path.contains("/+/")) {
+        candidates.push((ProviderKind::Gerrit, Confidence::Medium));
+    }
+
+    // Cloud Source Repositories `p/<project>/r/<repo>` paths
+    if path.starts_with("p/") && path.contains("/r/") {
+        candidates.push((ProviderKind::GoogleSource, Confidence::Medium));
+    }
+
+    // Pagure forks are under `forks/<user>/`
+    if path.starts_with("forks/") {
+        candidates.push((ProviderKind::Pagure, Confidence::Medium));
+    }
+
+    // Launchpad repos are named with `+git`
+    if path.contains("+git/") {
+        candidates.push((ProviderKind::Launchpad, Confidence::Medium));
+    }
+
+    // SourceHut owners are prefixed with `~`
+    if path.starts_with('~') && path.trim_end_matches('/').split('/').count() == 2 {
+        candidates.push((ProviderKind::SourceHut, Confidence::Medium));
+    }
+
+    // Nested groups are only supported by GitLab
+    let segments = path.trim_end_matches('/').split('/').count();
+    if segments > 2 {
+        candidates.push((ProviderKind::GitLab, Confidence::Low));
+    }
+
+    candidates
+}
@@ -1,4 +1,4 @@
-use super::GitProvider;
+use super::{CloneProtocol, GitProvider, ToGitUrl};
 use crate::types::GitUrlParseHint;
 use crate::{GitUrl, GitUrlParseError};
 
@@ -46,6 +46,12 @@ pub struct GenericProvider {
 }
 
 impl GenericProvider {
+    /// Provider info of `owner/repo`
+    pub fn new(owner: &str, repo: &str) -> Result<Self, GitUrlParseError> {
+        let path = super::join_names(&[owner, repo])?;
+        Self::parse_path(&path).map(|(_, provider)| provider)
+    }
+
     /// Parse the most common form of git url by offered by git providers
     fn parse_path(input: &str) -> Result<(&str, GenericProvider), GitUrlParseError> {
         let (input, _) = opt(tag("/")).parse(input)?;
@@ -91,3 +97,9 @@ impl GitProvider<Url, GitUrlParseError> for GenericProvider {
         Self::parse_path(path).map(|(_, provider)| provider)
     }
 }
+
+impl ToGitUrl for GenericProvider {
+    fn clone_path(&self, _protocol: CloneProtocol) -> String {
+        format!("{}.git", self.fullname())
+    }
+}
@@ -0,0 +1,176 @@
+use super::{CloneProtocol, GitProvider, ToGitUrl};
+use crate::types::GitUrlParseHint;
+use crate::{GitUrl, GitUrlParseError};
+
+use getset::{CopyGetters, Getters};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "url")]
+use url::Url;
+
+/// ## Gerrit repository provider
+///
+/// ## Supported URL Formats
+///
+/// - `ssh://user@host:29418/project/sub`
+/// - `https://host/project/sub`
+/// - `https://host/a/project/sub` (authenticated)
+/// - `https://host/c/project/sub/+/12345` (change page)
+///
+/// Gerrit project names can have any depth, so the whole path is the project
+///
+/// ## Examples
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::provider::GerritProvider;
+///
+/// fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+///     let url = GitUrl::parse("ssh://jdoe@review.opendev.org:29418/openstack/nova")?;
+///     let provider: GerritProvider = url.provider_info()?;
+///     assert_eq!(provider.project(), "openstack/nova");
+///     assert_eq!(provider.ssh_port(), Some(29418));
+///     assert!(!provider.authenticated());
+///
+///     assert_eq!(provider.change_url(12345), "https://review.opendev.org/c/openstack/nova/+/12345");
+///     assert_eq!(GerritProvider::change_ref(12345, 2), "refs/changes/45/12345/2");
+///     assert_eq!(GerritProvider::push_ref("master"), "refs/for/master");
+///
+///     let url = GitUrl::parse("https://review.opendev.org/a/openstack/nova")?;
+///     let provider: GerritProvider = url.provider_info()?;
+///     assert_eq!(provider.project(), "openstack/nova");
+///     assert!(provider.authenticated());
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Getters, CopyGetters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GerritProvider {
+    /// Lowercase host of the Gerrit server
+    #[getset(get = "pub")]
+    host: String,
+    /// Full project name (i.e. `platform/build/soong`)
+    #[getset(get = "pub")]
+    project: String,
+    /// If the url uses the authenticated `/a/` path prefix
+    #[getset(get_copy = "pub")]
+    authenticated: bool,
+    /// Port of ssh urls (usually `29418`)
+    #[getset(get_copy = "pub")]
+    ssh_port: Option<u16>,
+}
+
+impl GerritProvider {
+    /// Helper method to get the full name of a repo, which is the project name
+    pub fn fullname(&self) -> String {
+        self.project.clone()
+    }
+
+    /// Url of the review page of `change`
+    pub fn change_url(&self, change: u64) -> String {
+        format!("https://{}/c/{}/+/{change}", self.host, self.project)
+    }
+
+    /// Ref name of a patch set of a change: `refs/changes/{last 2 digits}/{change}/{patchset}`
+    pub fn change_ref(change: u64, patchset: u32) -> String {
+        format!("refs/changes/{:02}/{change}/{patchset}", change % 100)
+    }
+
+    /// Ref to push to for creating or updating changes for review on `branch`: `refs/for/{branch}`
+    pub fn push_ref(branch: &str) -> String {
+        let branch = branch.strip_prefix("refs/heads/").unwrap_or(branch);
+        format!("refs/for/{branch}")
+    }
+
+    /// Parse the host and path of url for Gerrit patterns
+    fn parse_path(
+        host: Option<&str>,
+        port: Option<u16>,
+        is_ssh: bool,
+        input: &str,
+    ) -> Result<GerritProvider, GitUrlParseError> {
+        let host = host
+            .ok_or_else(|| GitUrlParseError::ProviderParseFail("Gerrit url needs a host".into()))?
+            .to_ascii_lowercase();
+
+        let path = input.trim_matches('/');
+
+        // The `/a/` prefix is only used by http
+        let (path, authenticated) = match path.strip_prefix("a/") {
+            Some(path) if !is_ssh => (path, true),
+            _ => (path, false),
+        };
+
+        // Change page: `/c/<project>/+/<change>`
+        let path = match path.strip_prefix("c/").and_then(|p| p.split_once("/+/")) {
+            Some((project, _change)) if !is_ssh => project,
+            _ => path,
+        };
+
+        let project = path.strip_suffix(".git").unwrap_or(path);
+
+        if project.is_empty() || project.split('/').any(|p| p.is_empty()) {
+            return Err(GitUrlParseError::ProviderParseFail(
+                "Path needs a project: ex. '/project/sub'".into(),
+            ));
+        }
+
+        Ok(GerritProvider {
+            host,
+            project: project.to_string(),
+            authenticated,
+            ssh_port: if is_ssh { port } else { None },
+        })
+    }
+}
+
+impl GitProvider<GitUrl, GitUrlParseError> for GerritProvider {
+    fn from_git_url(url: &GitUrl) -> Result<Self, GitUrlParseError> {
+        if url.hint() == GitUrlParseHint::Filelike {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Self::parse_path(
+            url.host(),
+            url.port(),
+            url.scheme() == Some("ssh"),
+            url.path(),
+        )
+    }
+}
+
+#[cfg(feature = "url")]
+impl GitProvider<Url, GitUrlParseError> for GerritProvider {
+    fn from_git_url(url: &Url) -> Result<Self, GitUrlParseError> {
+        if url.scheme() == "file" {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Self::parse_path(
+            url.host_str(),
+            url.port(),
+            url.scheme() == "ssh",
+            url.path(),
+        )
+    }
+}
+
+impl ToGitUrl for GerritProvider {
+    fn clone_path(&self, protocol: CloneProtocol) -> String {
+        match protocol {
+            CloneProtocol::Https if self.authenticated => format!("a/{}", self.project),
+            _ => self.project.clone(),
+        }
+    }
+
+    /// Gerrit ssh users are the account user names
+    fn ssh_user(&self) -> Option<&str> {
+        None
+    }
+
+    fn ssh_port(&self) -> Option<u16> {
+        self.ssh_port
+    }
+}
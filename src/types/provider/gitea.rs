@@ -0,0 +1,395 @@
+use super::api::{ApiResource, ToApiUrl};
+use super::web::{
+    LineRange, RefKind, ToRawFileUrl, ToWebUrl, WebPage, WebPath, WebTarget, encode_component,
+    encode_path, ref_kind, web_origin,
+};
+use super::{CloneProtocol, GitProvider, ToGitUrl};
+use crate::types::GitUrlParseHint;
+use crate::{GitUrl, GitUrlParseError};
+
+use getset::{CopyGetters, Getters};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "url")]
+use url::Url;
+
+/// Path segments that follow `owner/repo` in Gitea web urls
+const WEB_SEGMENTS: &[&str] = &[
+    "src", "raw", "media", "blame", "wiki", "commit", "commits", "compare", "branches", "tags",
+    "releases", "archive", "issues", "pulls", "activity", "actions", "settings",
+];
+
+/// The type of repo hosted by Gitea
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GiteaRepoKind {
+    /// Regular `owner/repo` repository
+    #[default]
+    Repository,
+    /// Wiki of a repository (i.e. `owner/repo.wiki.git`)
+    Wiki,
+}
+
+/// ## Gitea, Forgejo and Codeberg repository provider
+///
+/// ## Supported URL Formats
+///
+/// - `https://codeberg.org/owner/repo.git`
+/// - `git@codeberg.org:owner/repo.git`
+/// - `https://codeberg.org/owner/repo.wiki.git`
+/// - `https://host/gitea/owner/repo.git` (sub-url install)
+/// - `https://codeberg.org/owner/repo/src/branch/<branch>/<path>`
+/// - `https://codeberg.org/owner/repo/raw/commit/<sha>/<path>`
+///
+/// Web urls are recognized by the segment after the repo (`src`, `raw`, `media`, `wiki`, `commit`, ...).
+/// Any path before `owner/repo` is kept as the sub-url base path
+///
+/// ## Examples
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::provider::{GiteaProvider, GiteaRepoKind};
+///
+/// fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+///     let url = GitUrl::parse("https://codeberg.org/forgejo/forgejo.git")?;
+///     let provider: GiteaProvider = url.provider_info()?;
+///     assert_eq!(provider.base_path(), None);
+///     assert_eq!(provider.owner(), "forgejo");
+///     assert_eq!(provider.repo(), "forgejo");
+///     assert_eq!(provider.kind(), GiteaRepoKind::Repository);
+///
+///     let url = GitUrl::parse("https://git.example.com/gitea/owner/repo.wiki.git")?;
+///     let provider: GiteaProvider = url.provider_info()?;
+///     assert_eq!(provider.base_path(), Some("gitea"));
+///     assert_eq!(provider.fullname(), "owner/repo");
+///     assert_eq!(provider.kind(), GiteaRepoKind::Wiki);
+///
+///     let url = GitUrl::parse("https://codeberg.org/owner/repo/src/branch/main/README.md")?;
+///     let provider: GiteaProvider = url.provider_info()?;
+///     assert_eq!(provider.fullname(), "owner/repo");
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Getters, CopyGetters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GiteaProvider {
+    /// Path of a sub-url install, without leading or trailing `/`
+    base_path: Option<String>,
+    /// Repo owner (user or organization)
+    #[getset(get = "pub")]
+    owner: String,
+    /// Repo name
+    #[getset(get = "pub")]
+    repo: String,
+    /// Type of repo
+    #[getset(get_copy = "pub")]
+    kind: GiteaRepoKind,
+}
+
+impl GiteaProvider {
+    /// Provider info of the `owner/repo` repository
+    pub fn new(owner: &str, repo: &str) -> Result<Self, GitUrlParseError> {
+        Self::parse_path(&super::join_names(&[owner, repo])?)
+    }
+
+    /// Path of a sub-url install, without leading or trailing `/`
+    pub fn base_path(&self) -> Option<&str> {
+        self.base_path.as_deref()
+    }
+
+    /// Helper method to get the full name of a repo: `{owner}/{repo}`
+    pub fn fullname(&self) -> String {
+        format!("{}/{}", self.owner, self.repo)
+    }
+
+    /// Parse the path of url for Gitea clone and web url patterns
+    fn parse_path(input: &str) -> Result<GiteaProvider, GitUrlParseError> {
+        let parts: Vec<&str> = input.split('/').filter(|s| !s.is_empty()).collect();
+
+        // Web urls have `owner/repo` right before the first known web segment
+        let web = parts
+            .iter()
+            .enumerate()
+            .skip(2)
+            .find(|(_, p)| WEB_SEGMENTS.contains(p))
+            .map(|(i, p)| (i, *p == "wiki"));
+
+        let (end, web_wiki) = match web {
+            Some((i, wiki)) => (i, wiki),
+            None => (parts.len(), false),
+        };
+
+        if end < 2 {
+            return Err(GitUrlParseError::ProviderParseFail(
+                "Path needs at least 2 parts: ex. '/owner/repo'".into(),
+            ));
+        }
+
+        let (base, owner, repo) = (&parts[..end - 2], parts[end - 2], parts[end - 1]);
+
+        let repo = repo.strip_suffix(".git").unwrap_or(repo);
+        let (repo, kind) = match repo.strip_suffix(".wiki") {
+            Some(repo) => (repo, GiteaRepoKind::Wiki),
+            None if web_wiki => (repo, GiteaRepoKind::Wiki),
+            None => (repo, GiteaRepoKind::Repository),
+        };
+
+        Self::validate_name(owner)?;
+        Self::validate_name(repo)?;
+
+        Ok(GiteaProvider {
+            base_path: (!base.is_empty()).then(|| base.join("/")),
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            kind,
+        })
+    }
+
+    /// Gitea user, organization and repo names: ascii letters, digits, `.`, `-` or `_`. Can't be `.` or `..`
+    fn validate_name(name: &str) -> Result<(), GitUrlParseError> {
+        let valid = !name.is_empty()
+            && name != "."
+            && name != ".."
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '_');
+
+        if valid {
+            Ok(())
+        } else {
+            Err(GitUrlParseError::ProviderInvalidName(format!(
+                "Gitea names must be ascii letters, digits, '.', '-' or '_': '{name}'"
+            )))
+        }
+    }
+
+    /// Split a web url path into the repo, after any base path, and the page within it
+    pub(crate) fn split_web_path(parts: &[String]) -> Option<WebPath> {
+        let is_ref_kind = |i: usize| {
+            matches!(
+                parts.get(i).map(|p| p.as_str()),
+                Some("branch" | "tag" | "commit")
+            )
+        };
+
+        let marker = (2..parts.len()).find(|&i| match parts[i].as_str() {
+            "src" | "blame" => is_ref_kind(i + 1),
+            "commit" => i + 1 < parts.len(),
+            _ => false,
+        });
+
+        let end = marker.unwrap_or(parts.len());
+        let (repo, base) = parts[..end].split_last()?;
+        if base.is_empty() {
+            return None;
+        }
+
+        let mut clone_path = base.to_vec();
+        clone_path.push(format!("{}.git", repo.strip_suffix(".git").unwrap_or(repo)));
+
+        let Some(marker) = marker else {
+            return Some(WebPath::new(clone_path, WebPage::Repo, &[]));
+        };
+
+        if parts[marker] == "commit" {
+            return Some(WebPath::new(
+                clone_path,
+                WebPage::Commit,
+                &parts[marker + 1..],
+            ));
+        }
+
+        let page = match parts[marker].as_str() {
+            "blame" => WebPage::Blame,
+            _ => WebPage::Tree,
+        };
+
+        let mut web_path = WebPath::new(clone_path, page, &parts[marker + 2..]);
+        if parts[marker + 1] == "tag" {
+            web_path.git_ref = web_path.git_ref.map(|tag| format!("refs/tags/{tag}"));
+        }
+
+        Some(web_path)
+    }
+}
+
+impl GitProvider<GitUrl, GitUrlParseError> for GiteaProvider {
+    fn from_git_url(url: &GitUrl) -> Result<Self, GitUrlParseError> {
+        if url.hint() == GitUrlParseHint::Filelike {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Self::parse_path(url.path())
+    }
+}
+
+#[cfg(feature = "url")]
+impl GitProvider<Url, GitUrlParseError> for GiteaProvider {
+    fn from_git_url(url: &Url) -> Result<Self, GitUrlParseError> {
+        if url.scheme() == "file" {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Self::parse_path(url.path())
+    }
+}
+
+impl ToGitUrl for GiteaProvider {
+    /// Https paths keep the base path of sub-url installs
+    fn clone_path(&self, protocol: CloneProtocol) -> String {
+        let repo = match self.kind {
+            GiteaRepoKind::Repository => format!("{}.git", self.fullname()),
+            GiteaRepoKind::Wiki => format!("{}.wiki.git", self.fullname()),
+        };
+
+        match (protocol, &self.base_path) {
+            (CloneProtocol::Https, Some(base_path)) => format!("{base_path}/{repo}"),
+            _ => repo,
+        }
+    }
+}
+
+impl GiteaProvider {
+    /// `{kind}/{name}` path of a ref in source and blame urls
+    fn ref_path(git_ref: &str) -> String {
+        let (kind, name) = ref_kind(git_ref);
+        let kind = match kind {
+            RefKind::Branch => "branch",
+            RefKind::Tag => "tag",
+            RefKind::Commit => "commit",
+        };
+
+        format!("{kind}/{}", encode_path(name))
+    }
+}
+
+impl ToWebUrl for GiteaProvider {
+    /// Wikis only have a home page
+    fn web_url(&self, url: &GitUrl, target: WebTarget) -> Result<String, GitUrlParseError> {
+        let origin = web_origin(url)?;
+        let base = match &self.base_path {
+            Some(base_path) => format!("{origin}/{base_path}/{}", self.fullname()),
+            None => format!("{origin}/{}", self.fullname()),
+        };
+        let anchor = |lines: Option<LineRange>| match lines.map(|l| (l.start(), l.end())) {
+            Some((start, Some(end))) => format!("#L{start}-L{end}"),
+            Some((start, None)) => format!("#L{start}"),
+            None => String::new(),
+        };
+
+        let url = match (self.kind, target) {
+            (GiteaRepoKind::Wiki, WebTarget::Repo) => format!("{base}/wiki"),
+            (_, WebTarget::Repo) => base,
+            (GiteaRepoKind::Repository, WebTarget::Tree { git_ref, path }) => match path {
+                Some(path) => format!(
+                    "{base}/src/{}/{}",
+                    Self::ref_path(git_ref),
+                    encode_path(path)
+                ),
+                None => format!("{base}/src/{}", Self::ref_path(git_ref)),
+            },
+            (
+                GiteaRepoKind::Repository,
+                WebTarget::Blob {
+                    git_ref,
+                    path,
+                    lines,
+                },
+            ) => format!(
+                "{base}/src/{}/{}{}",
+                Self::ref_path(git_ref),
+                encode_path(path),
+                anchor(lines)
+            ),
+            (GiteaRepoKind::Repository, WebTarget::Commit { sha }) => {
+                format!("{base}/commit/{}", encode_path(sha))
+            }
+            (GiteaRepoKind::Repository, WebTarget::Compare { base: from, head }) => format!(
+                "{base}/compare/{}...{}",
+                encode_path(from),
+                encode_path(head)
+            ),
+            (
+                GiteaRepoKind::Repository,
+                WebTarget::Blame {
+                    git_ref,
+                    path,
+                    lines,
+                },
+            ) => format!(
+                "{base}/blame/{}/{}{}",
+                Self::ref_path(git_ref),
+                encode_path(path),
+                anchor(lines)
+            ),
+            _ => return Err(GitUrlParseError::ProviderUnsupported),
+        };
+
+        Ok(url)
+    }
+}
+
+impl ToApiUrl for GiteaProvider {
+    /// Sub-url installs serve the API under their base path
+    fn api_base_url(&self, url: &GitUrl) -> Result<String, GitUrlParseError> {
+        let origin = web_origin(url)?;
+
+        match &self.base_path {
+            Some(base_path) => Ok(format!("{origin}/{base_path}/api/v1")),
+            None => Ok(format!("{origin}/api/v1")),
+        }
+    }
+
+    /// Wikis only have a repo resource, listing their pages
+    fn api_url(&self, url: &GitUrl, resource: ApiResource) -> Result<String, GitUrlParseError> {
+        let repo = format!(
+            "{}/repos/{}/{}",
+            self.api_base_url(url)?,
+            encode_component(&self.owner),
+            encode_component(&self.repo)
+        );
+
+        let url = match (self.kind, resource) {
+            (GiteaRepoKind::Wiki, ApiResource::Repo) => format!("{repo}/wiki/pages"),
+            (GiteaRepoKind::Repository, ApiResource::Repo) => repo,
+            (GiteaRepoKind::Repository, ApiResource::Branches) => format!("{repo}/branches"),
+            (GiteaRepoKind::Repository, ApiResource::PullRequests) => format!("{repo}/pulls"),
+            (GiteaRepoKind::Repository, ApiResource::File { git_ref, path }) => format!(
+                "{repo}/raw/{}?ref={}",
+                encode_path(path),
+                encode_component(ref_kind(git_ref).1)
+            ),
+            _ => return Err(GitUrlParseError::ProviderUnsupported),
+        };
+
+        Ok(url)
+    }
+}
+
+impl ToRawFileUrl for GiteaProvider {
+    /// Sub-url installs serve files under their base path. Wikis are unsupported
+    fn raw_file_url(
+        &self,
+        url: &GitUrl,
+        git_ref: &str,
+        path: &str,
+    ) -> Result<String, GitUrlParseError> {
+        let origin = web_origin(url)?;
+        if self.kind != GiteaRepoKind::Repository {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        let base = match &self.base_path {
+            Some(base_path) => format!("{origin}/{base_path}/{}", self.fullname()),
+            None => format!("{origin}/{}", self.fullname()),
+        };
+
+        Ok(format!(
+            "{base}/raw/{}/{}",
+            Self::ref_path(git_ref),
+            encode_path(path)
+        ))
+    }
+}
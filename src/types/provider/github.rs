@@ -0,0 +1,414 @@
+use super::api::{ApiResource, ToApiUrl};
+use super::web::{
+    LineRange, ToRawFileUrl, ToWebUrl, WebPath, WebTarget, encode_component, encode_path,
+    web_origin,
+};
+use super::{CloneProtocol, GitProvider, ToGitUrl};
+use crate::types::GitUrlParseHint;
+use crate::{GitUrl, GitUrlParseError};
+
+use getset::{CopyGetters, Getters};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "url")]
+use url::Url;
+
+/// The type of repo hosted by GitHub
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GitHubRepoKind {
+    /// Regular `owner/repo` repository
+    #[default]
+    Repository,
+    /// Wiki of a repository (i.e. `owner/repo.wiki.git`)
+    Wiki,
+    /// Gist (i.e. `gist.github.com/<id>.git`)
+    Gist,
+}
+
+/// ## GitHub repository provider
+///
+/// ## Supported URL Formats
+///
+/// - `https://github.com/owner/repo.git`
+/// - `git@github.com:owner/repo.git`
+/// - `ssh://git@ssh.github.com:443/owner/repo.git`
+/// - `https://github.com/owner/repo.wiki.git`
+/// - `https://gist.github.com/<id>.git`
+/// - `https://api.github.com/repos/owner/repo`
+/// - `https://raw.githubusercontent.com/owner/repo/<ref>/<path>`
+///
+/// GitHub Enterprise Server hosts are supported with the same patterns, including
+/// `https://<host>/api/v3/repos/owner/repo`, `https://<host>/raw/owner/repo/<ref>/<path>` and `https://<host>/gist/<id>.git`
+///
+/// Owner and repo names are checked against GitHub's naming rules
+///
+/// ## Examples
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::provider::{GitHubProvider, GitHubRepoKind};
+///
+/// fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+///     let url = GitUrl::parse("git@github.com:tjtelan/git-url-parse-rs.git")?;
+///     let provider: GitHubProvider = url.provider_info()?;
+///     assert_eq!(provider.owner(), Some("tjtelan"));
+///     assert_eq!(provider.repo(), "git-url-parse-rs");
+///     assert_eq!(provider.kind(), GitHubRepoKind::Repository);
+///     assert_eq!(provider.fullname(), "tjtelan/git-url-parse-rs");
+///
+///     let wiki = GitUrl::parse("https://github.com/tjtelan/git-url-parse-rs.wiki.git")?;
+///     let provider: GitHubProvider = wiki.provider_info()?;
+///     assert_eq!(provider.repo(), "git-url-parse-rs");
+///     assert_eq!(provider.kind(), GitHubRepoKind::Wiki);
+///
+///     let gist = GitUrl::parse("https://gist.github.com/aa5a315d61ae9438b18d.git")?;
+///     let provider: GitHubProvider = gist.provider_info()?;
+///     assert_eq!(provider.owner(), None);
+///     assert_eq!(provider.repo(), "aa5a315d61ae9438b18d");
+///     assert_eq!(provider.kind(), GitHubRepoKind::Gist);
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Getters, CopyGetters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GitHubProvider {
+    /// Repo owner (user or organization). Gists may not have an owner in the url
+    owner: Option<String>,
+    /// Repo name, or gist id
+    #[getset(get = "pub")]
+    repo: String,
+    /// Type of repo
+    #[getset(get_copy = "pub")]
+    kind: GitHubRepoKind,
+}
+
+impl GitHubProvider {
+    /// Provider info of the `owner/repo` repository
+    pub fn new(owner: &str, repo: &str) -> Result<Self, GitUrlParseError> {
+        let path = super::join_names(&[owner, repo])?;
+        Self::parse_path(Some("github.com"), &path)
+    }
+
+    /// Repo owner (user or organization). Gists may not have an owner in the url
+    pub fn owner(&self) -> Option<&str> {
+        self.owner.as_deref()
+    }
+
+    /// Helper method to get the full name of a repo: `{owner}/{repo}`, or `{repo}` for gists without owner
+    pub fn fullname(&self) -> String {
+        match &self.owner {
+            Some(owner) => format!("{owner}/{}", self.repo),
+            None => self.repo.clone(),
+        }
+    }
+
+    /// Parse the host and path of url for GitHub patterns
+    fn parse_path(host: Option<&str>, input: &str) -> Result<GitHubProvider, GitUrlParseError> {
+        let host = host.unwrap_or_default().to_ascii_lowercase();
+
+        let parts: Vec<&str> = input
+            .trim_start_matches('/')
+            .trim_end_matches('/')
+            .split('/')
+            .collect();
+
+        match (host.as_str(), parts.as_slice()) {
+            // Gists
+            ("gist.github.com", [id]) => Self::parse_gist(None, id),
+            ("gist.github.com", [owner, id]) => Self::parse_gist(Some(owner), id),
+            ("gist.github.com", _) => Err(GitUrlParseError::ProviderParseFail(
+                "Gist path expected: ex. '/<id>.git' or '/<owner>/<id>'".into(),
+            )),
+            // GitHub Enterprise Server serves gists under `/gist`
+            (host, ["gist", id]) if !is_github_com(host) => Self::parse_gist(None, id),
+            (host, ["gist", owner, id]) if !is_github_com(host) => {
+                Self::parse_gist(Some(owner), id)
+            }
+            // REST API and raw content siblings
+            ("api.github.com", ["repos", owner, repo, ..])
+            | ("raw.githubusercontent.com", [owner, repo, ..]) => Self::parse_repo(owner, repo),
+            // GitHub Enterprise Server serves them under `/api/v3` and `/raw`
+            (host, ["api", "v3", "repos", owner, repo, ..] | ["raw", owner, repo, ..])
+                if !is_github_com(host) =>
+            {
+                Self::parse_repo(owner, repo)
+            }
+            (_, [owner, repo]) => Self::parse_repo(owner, repo),
+            _ => Err(GitUrlParseError::ProviderParseFail(
+                "Path needs 2 parts: ex. '/owner/repo'".into(),
+            )),
+        }
+    }
+
+    /// Validate owner and repo names, and detect wikis
+    fn parse_repo(owner: &str, repo: &str) -> Result<GitHubProvider, GitUrlParseError> {
+        let repo = repo.strip_suffix(".git").unwrap_or(repo);
+
+        let (repo, kind) = match repo.strip_suffix(".wiki") {
+            Some(repo) => (repo, GitHubRepoKind::Wiki),
+            None => (repo, GitHubRepoKind::Repository),
+        };
+
+        Self::validate_owner(owner)?;
+        Self::validate_repo(repo)?;
+
+        Ok(GitHubProvider {
+            owner: Some(owner.to_string()),
+            repo: repo.to_string(),
+            kind,
+        })
+    }
+
+    /// Validate gist id
+    fn parse_gist(owner: Option<&str>, id: &str) -> Result<GitHubProvider, GitUrlParseError> {
+        let id = id.strip_suffix(".git").unwrap_or(id);
+
+        if let Some(owner) = owner {
+            Self::validate_owner(owner)?;
+        }
+
+        if id.is_empty() || !id.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(GitUrlParseError::ProviderInvalidName(format!(
+                "Gist id must be hexadecimal: '{id}'"
+            )));
+        }
+
+        Ok(GitHubProvider {
+            owner: owner.map(|o| o.to_string()),
+            repo: id.to_string(),
+            kind: GitHubRepoKind::Gist,
+        })
+    }
+
+    /// GitHub user and organization names:
+    /// 1-39 alphanumeric characters or single hyphens, not starting or ending with a hyphen
+    fn validate_owner(owner: &str) -> Result<(), GitUrlParseError> {
+        let valid = !owner.is_empty()
+            && owner.len() <= 39
+            && !owner.starts_with('-')
+            && !owner.ends_with('-')
+            && owner.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+
+        if valid {
+            Ok(())
+        } else {
+            Err(GitUrlParseError::ProviderInvalidName(format!(
+                "GitHub owner must be 1-39 alphanumeric characters or hyphens, not starting or ending with a hyphen: '{owner}'"
+            )))
+        }
+    }
+
+    /// GitHub repo names: 1-100 ascii letters, digits, `.`, `-` or `_`. Can't be `.` or `..`
+    fn validate_repo(repo: &str) -> Result<(), GitUrlParseError> {
+        let valid = !repo.is_empty()
+            && repo.len() <= 100
+            && repo != "."
+            && repo != ".."
+            && repo
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '_');
+
+        if valid {
+            Ok(())
+        } else {
+            Err(GitUrlParseError::ProviderInvalidName(format!(
+                "GitHub repo must be 1-100 ascii letters, digits, '.', '-' or '_': '{repo}'"
+            )))
+        }
+    }
+
+    /// Split a web url path into the repo and the page within it
+    pub(crate) fn split_web_path(parts: &[String]) -> Option<WebPath> {
+        let [owner, repo, rest @ ..] = parts else {
+            return None;
+        };
+        let repo = repo.strip_suffix(".git").unwrap_or(repo);
+
+        Some(WebPath::from_pages(
+            vec![owner.clone(), format!("{repo}.git")],
+            rest,
+        ))
+    }
+}
+
+impl GitProvider<GitUrl, GitUrlParseError> for GitHubProvider {
+    fn from_git_url(url: &GitUrl) -> Result<Self, GitUrlParseError> {
+        if url.hint() == GitUrlParseHint::Filelike {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Self::parse_path(url.host(), url.path())
+    }
+}
+
+#[cfg(feature = "url")]
+impl GitProvider<Url, GitUrlParseError> for GitHubProvider {
+    fn from_git_url(url: &Url) -> Result<Self, GitUrlParseError> {
+        if url.scheme() == "file" {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Self::parse_path(url.host_str(), url.path())
+    }
+}
+
+impl ToGitUrl for GitHubProvider {
+    /// Gists clone from the gist host (i.e. `gist.github.com`). Ssh gist paths are only the id
+    fn clone_path(&self, protocol: CloneProtocol) -> String {
+        match (self.kind, protocol) {
+            (GitHubRepoKind::Wiki, _) => format!("{}.wiki.git", self.fullname()),
+            (GitHubRepoKind::Gist, CloneProtocol::Ssh) => format!("{}.git", self.repo),
+            _ => format!("{}.git", self.fullname()),
+        }
+    }
+}
+
+impl ToWebUrl for GitHubProvider {
+    /// Wikis and gists only have a home page. Gists are on the gist host of `github.com`,
+    /// or under `/gist` on GitHub Enterprise Server. Other `github.com` hosts link to `github.com`
+    fn web_url(&self, url: &GitUrl, target: WebTarget) -> Result<String, GitUrlParseError> {
+        let origin = web_origin(url)?;
+        let github_com = url.host().is_some_and(is_github_com);
+        let base = match self.kind {
+            GitHubRepoKind::Gist if github_com => {
+                format!("https://gist.github.com/{}", self.fullname())
+            }
+            GitHubRepoKind::Gist => format!("{origin}/gist/{}", self.fullname()),
+            _ if github_com => format!("https://github.com/{}", self.fullname()),
+            _ => format!("{origin}/{}", self.fullname()),
+        };
+        let anchor = |lines: Option<LineRange>| match lines.map(|l| (l.start(), l.end())) {
+            Some((start, Some(end))) => format!("#L{start}-L{end}"),
+            Some((start, None)) => format!("#L{start}"),
+            None => String::new(),
+        };
+
+        let url = match (self.kind, target) {
+            (GitHubRepoKind::Wiki, WebTarget::Repo) => format!("{base}/wiki"),
+            (_, WebTarget::Repo) => base,
+            (GitHubRepoKind::Repository, WebTarget::Tree { git_ref, path }) => match path {
+                Some(path) => format!("{base}/tree/{}/{}", encode_path(git_ref), encode_path(path)),
+                None => format!("{base}/tree/{}", encode_path(git_ref)),
+            },
+            (
+                GitHubRepoKind::Repository,
+                WebTarget::Blob {
+                    git_ref,
+                    path,
+                    lines,
+                },
+            ) => format!(
+                "{base}/blob/{}/{}{}",
+                encode_path(git_ref),
+                encode_path(path),
+                anchor(lines)
+            ),
+            (GitHubRepoKind::Repository, WebTarget::Commit { sha }) => {
+                format!("{base}/commit/{}", encode_path(sha))
+            }
+            (GitHubRepoKind::Repository, WebTarget::Compare { base: from, head }) => format!(
+                "{base}/compare/{}...{}",
+                encode_path(from),
+                encode_path(head)
+            ),
+            (
+                GitHubRepoKind::Repository,
+                WebTarget::Blame {
+                    git_ref,
+                    path,
+                    lines,
+                },
+            ) => format!(
+                "{base}/blame/{}/{}{}",
+                encode_path(git_ref),
+                encode_path(path),
+                anchor(lines)
+            ),
+            _ => return Err(GitUrlParseError::ProviderUnsupported),
+        };
+
+        Ok(url)
+    }
+}
+
+impl ToApiUrl for GitHubProvider {
+    /// `github.com` and its sibling hosts are served by `api.github.com`,
+    /// GitHub Enterprise Server hosts by `/api/v3`
+    fn api_base_url(&self, url: &GitUrl) -> Result<String, GitUrlParseError> {
+        let origin = web_origin(url)?;
+
+        if url.host().is_some_and(is_github_com) {
+            Ok("https://api.github.com".to_string())
+        } else {
+            Ok(format!("{origin}/api/v3"))
+        }
+    }
+
+    /// Gists only have a repo resource, wikis don't have any
+    fn api_url(&self, url: &GitUrl, resource: ApiResource) -> Result<String, GitUrlParseError> {
+        let base = self.api_base_url(url)?;
+        let repo = format!("{base}/repos/{}", self.fullname());
+
+        let url = match (self.kind, resource) {
+            (GitHubRepoKind::Gist, ApiResource::Repo) => {
+                format!("{base}/gists/{}", encode_component(&self.repo))
+            }
+            (GitHubRepoKind::Repository, ApiResource::Repo) => repo,
+            (GitHubRepoKind::Repository, ApiResource::Branches) => format!("{repo}/branches"),
+            (GitHubRepoKind::Repository, ApiResource::PullRequests) => format!("{repo}/pulls"),
+            (GitHubRepoKind::Repository, ApiResource::File { git_ref, path }) => format!(
+                "{repo}/contents/{}?ref={}",
+                encode_path(path),
+                encode_component(git_ref)
+            ),
+            _ => return Err(GitUrlParseError::ProviderUnsupported),
+        };
+
+        Ok(url)
+    }
+}
+
+/// Whether `host` is `github.com`, or one of its ssh, gist, api and raw content hosts
+fn is_github_com(host: &str) -> bool {
+    matches!(
+        host.to_ascii_lowercase().as_str(),
+        "github.com"
+            | "www.github.com"
+            | "ssh.github.com"
+            | "gist.github.com"
+            | "api.github.com"
+            | "raw.githubusercontent.com"
+    )
+}
+
+impl ToRawFileUrl for GitHubProvider {
+    /// `github.com` files are served by `raw.githubusercontent.com`,
+    /// GitHub Enterprise Server files under `/raw`. Wikis and gists are unsupported
+    fn raw_file_url(
+        &self,
+        url: &GitUrl,
+        git_ref: &str,
+        path: &str,
+    ) -> Result<String, GitUrlParseError> {
+        let origin = web_origin(url)?;
+        if self.kind != GitHubRepoKind::Repository {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        let base = if url.host().is_some_and(is_github_com) {
+            "https://raw.githubusercontent.com".to_string()
+        } else {
+            format!("{origin}/raw")
+        };
+
+        Ok(format!(
+            "{base}/{}/{}/{}",
+            self.fullname(),
+            encode_path(git_ref),
+            encode_path(path)
+        ))
+    }
+}
@@ -1,7 +1,13 @@
-use super::GitProvider;
+use super::api::{ApiResource, ToApiUrl};
+use super::web::{
+    LineRange, ToRawFileUrl, ToWebUrl, WebPath, WebTarget, encode_component, encode_path,
+    web_origin,
+};
+use super::{CloneProtocol, GitProvider, ToGitUrl};
+use crate::types::GitUrlParseHint;
 use crate::{GitUrl, GitUrlParseError};
 
-use getset::{CloneGetters, Getters};
+use getset::{CloneGetters, CopyGetters, Getters};
 use nom::Parser;
 use nom::bytes::complete::tag;
 use nom::combinator::opt;
@@ -10,6 +16,86 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "url")]
 use url::Url;
 
+/// Names reserved by GitLab for top-level groups and users
+const RESERVED_TOP_LEVEL: &[&str] = &[
+    "-",
+    ".well-known",
+    "404.html",
+    "422.html",
+    "500.html",
+    "502.html",
+    "503.html",
+    "admin",
+    "api",
+    "apple-touch-icon.png",
+    "assets",
+    "dashboard",
+    "deploy.html",
+    "explore",
+    "favicon.ico",
+    "favicon.png",
+    "files",
+    "groups",
+    "health_check",
+    "help",
+    "import",
+    "jwt",
+    "login",
+    "oauth",
+    "profile",
+    "projects",
+    "public",
+    "robots.txt",
+    "s",
+    "search",
+    "sitemap",
+    "sitemap.xml",
+    "sitemap.xml.gz",
+    "slash-command-logo.png",
+    "snippets",
+    "unsubscribes",
+    "uploads",
+    "users",
+    "v2",
+];
+
+/// Names reserved by GitLab for projects
+const RESERVED_PROJECT: &[&str] = &[
+    "-",
+    "badges",
+    "blame",
+    "blob",
+    "builds",
+    "commits",
+    "create",
+    "create_dir",
+    "edit",
+    "files",
+    "find_file",
+    "new",
+    "preview",
+    "raw",
+    "refs",
+    "tree",
+    "update",
+    "wikis",
+];
+
+/// The type of repo hosted by GitLab
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GitLabRepoKind {
+    /// Regular project repository
+    #[default]
+    Project,
+    /// Wiki of a project (i.e. `group/project.wiki.git`)
+    Wiki,
+    /// Design management repo of a project (i.e. `group/project.design.git`)
+    Design,
+    /// Project snippet (i.e. `group/project/snippets/<id>.git`) or personal snippet (i.e. `snippets/<id>.git`)
+    Snippet,
+}
+
 /// ## GitLab repository provider
 ///
 /// ## Supported URL Formats
@@ -18,12 +104,19 @@ use url::Url;
 /// - `https://gitlab.com/owner/subgroup1/subgroup2/repo.git`
 /// - `git@gitlab.com:owner/repo.git`
 /// - `git@gitlab.com:owner/subgroup1/subgroup2/repo.git`
+/// - `https://gitlab.com/owner/repo.wiki.git`
+/// - `https://gitlab.com/owner/repo.design.git`
+/// - `https://gitlab.com/owner/repo/snippets/<id>.git`
+/// - `https://gitlab.com/snippets/<id>.git`
+/// - `https://gitlab.com/owner/subgroup/repo/-/tree/main/src` (web urls, up to the `/-/` separator)
+///
+/// Group and project names are checked against GitLab's naming rules and reserved names
 ///
 /// ## Examples
 ///
 /// ```
 /// use git_url_parse::GitUrl;
-/// use git_url_parse::types::provider::GitLabProvider;
+/// use git_url_parse::types::provider::{GitLabProvider, GitLabRepoKind};
 ///
 /// fn main() -> Result<(), git_url_parse::GitUrlParseError> {
 ///     // Top-level repository
@@ -42,25 +135,53 @@ use url::Url;
 ///     assert_eq!(provider2.subgroup(), Some(vec!["group1", "group2"]));
 ///     assert_eq!(provider2.fullname(), "owner/group1/group2/project");
 ///
+///     // Web url
+///     let url3 = GitUrl::parse("https://gitlab.com/owner/group1/project/-/tree/main/src")?;
+///     let provider3 : GitLabProvider = url3.provider_info()?;
+///     assert_eq!(provider3.fullname(), "owner/group1/project");
+///
+///     // Wiki
+///     let url4 = GitUrl::parse("git@gitlab.com:owner/project.wiki.git")?;
+///     let provider4 : GitLabProvider = url4.provider_info()?;
+///     assert_eq!(provider4.repo(), "project");
+///     assert_eq!(provider4.kind(), GitLabRepoKind::Wiki);
+///
 ///     Ok(())
 /// }
 /// ```
 ///
-#[derive(Clone, Debug, PartialEq, Eq, Default, Getters, CloneGetters)]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Getters, CloneGetters, CopyGetters)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GitLabProvider {
-    /// Repo owner
+    /// Repo owner. Empty for personal snippets
     #[getset(get = "pub")]
     owner: String,
     /// Gitlab subgroups
     //#[getset(get_clone = "pub")]
     subgroup: Option<Vec<String>>,
-    /// Repo name
+    /// Repo name, or snippet id for personal snippets
     #[getset(get = "pub")]
     repo: String,
+    /// Type of repo
+    #[getset(get_copy = "pub")]
+    kind: GitLabRepoKind,
+    /// Snippet id, for snippet repos
+    snippet_id: Option<String>,
 }
 
 impl GitLabProvider {
+    /// Provider info of the `owner/{subgroups}/repo` project
+    pub fn new(owner: &str, subgroup: &[&str], repo: &str) -> Result<Self, GitUrlParseError> {
+        let names: Vec<&str> = [owner]
+            .into_iter()
+            .chain(subgroup.iter().copied())
+            .chain([repo])
+            .collect();
+
+        let path = super::join_names(&names)?;
+        Self::parse_path(&path).map(|(_, provider)| provider)
+    }
+
     /// Repo owner
     /// Gitlab subgroups
     pub fn subgroup(&self) -> Option<Vec<&str>> {
@@ -72,9 +193,17 @@ impl GitLabProvider {
         }
     }
 
-    /// Helper method to get the full name of a repo: `{owner}/{repo}` or `{owner}/{subgroups}/{repo}`
+    /// Snippet id, for snippet repos
+    pub fn snippet_id(&self) -> Option<&str> {
+        self.snippet_id.as_deref()
+    }
+
+    /// Helper method to get the full name of a repo: `{owner}/{repo}` or `{owner}/{subgroups}/{repo}`.
+    /// Personal snippets are `snippets/{id}`
     pub fn fullname(&self) -> String {
-        if let Some(subgroup) = self.subgroup() {
+        if self.owner.is_empty() {
+            format!("snippets/{}", self.repo)
+        } else if let Some(subgroup) = self.subgroup() {
             let subgroup_str = subgroup.join("/");
 
             format!("{}/{subgroup_str}/{}", self.owner, self.repo)
@@ -88,11 +217,56 @@ impl GitLabProvider {
         // Optional leading slash
         let (input, _) = opt(tag("/")).parse(input)?;
 
+        // Split the path
+        let mut parts: Vec<&str> = input.split('/').filter(|s| !s.is_empty()).collect();
+
+        // Web urls put everything after the project behind a `/-/` separator
+        let mut web_kind = None;
+        let mut snippet_id = None;
+        if let Some(separator) = parts.iter().position(|p| *p == "-") {
+            match &parts[separator + 1..] {
+                ["snippets", id, ..] => {
+                    web_kind = Some(GitLabRepoKind::Snippet);
+                    snippet_id = Some(*id);
+                }
+                ["wikis", ..] => web_kind = Some(GitLabRepoKind::Wiki),
+                _ => {}
+            }
+            parts.truncate(separator);
+        }
+
         // Remove .git extension if present
-        let input = input.trim_end_matches(".git");
+        if let Some(last) = parts.last_mut() {
+            *last = last.strip_suffix(".git").unwrap_or(last);
+        }
 
-        // Split the path
-        let parts: Vec<&str> = input.split('/').filter(|s| !s.is_empty()).collect();
+        // Personal snippets
+        if let (Some(GitLabRepoKind::Snippet), []) | (None, ["snippets", _]) =
+            (web_kind, parts.as_slice())
+        {
+            let id = snippet_id.or(parts.last().copied()).unwrap_or_default();
+            Self::validate_snippet_id(id)?;
+
+            return Ok((
+                input,
+                GitLabProvider {
+                    owner: String::new(),
+                    subgroup: None,
+                    repo: id.to_string(),
+                    kind: GitLabRepoKind::Snippet,
+                    snippet_id: Some(id.to_string()),
+                },
+            ));
+        }
+
+        // Project snippets
+        if let [.., "snippets", id] = parts.as_slice() {
+            if web_kind.is_none() && id.chars().all(|c| c.is_ascii_digit()) {
+                snippet_id = Some(*id);
+                web_kind = Some(GitLabRepoKind::Snippet);
+                parts.truncate(parts.len() - 2);
+            }
+        }
 
         // Ensure we have at least 2 parts (owner and repo)
         if parts.len() < 2 {
@@ -102,7 +276,23 @@ impl GitLabProvider {
         }
 
         // Last part is the repo
-        let repo = parts[parts.len() - 1].to_string();
+        let repo = parts[parts.len() - 1];
+        let (repo, kind) = if let Some(repo) = repo.strip_suffix(".wiki") {
+            (repo, GitLabRepoKind::Wiki)
+        } else if let Some(repo) = repo.strip_suffix(".design") {
+            (repo, GitLabRepoKind::Design)
+        } else {
+            (repo, web_kind.unwrap_or_default())
+        };
+
+        if let Some(id) = snippet_id {
+            Self::validate_snippet_id(id)?;
+        }
+        Self::validate_path(parts[0], RESERVED_TOP_LEVEL)?;
+        for group in &parts[1..parts.len() - 1] {
+            Self::validate_path(group, &[])?;
+        }
+        Self::validate_path(repo, RESERVED_PROJECT)?;
 
         // Everything before the last part is the owner/subgroups
         let (owner, subgroup) = if parts.len() > 2 {
@@ -122,14 +312,79 @@ impl GitLabProvider {
             GitLabProvider {
                 owner,
                 subgroup,
-                repo,
+                repo: repo.to_string(),
+                kind,
+                snippet_id: snippet_id.map(|id| id.to_string()),
             },
         ))
     }
+
+    /// GitLab group and project paths: ascii letters, digits, `_`, `-` or `.`, starting with a letter, digit or `_`.
+    /// Can't end with `.`, `.git` or `.atom`, or be one of the `reserved` names
+    fn validate_path(name: &str, reserved: &[&str]) -> Result<(), GitUrlParseError> {
+        if reserved.iter().any(|r| r.eq_ignore_ascii_case(name)) {
+            return Err(GitUrlParseError::ProviderReservedName(format!(
+                "'{name}' is reserved by GitLab"
+            )));
+        }
+
+        let valid = name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+            && !name.ends_with('.')
+            && !name.ends_with(".git")
+            && !name.ends_with(".atom")
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.');
+
+        if valid {
+            Ok(())
+        } else {
+            Err(GitUrlParseError::ProviderInvalidName(format!(
+                "GitLab path must be ascii letters, digits, '_', '-' or '.', starting with a letter, digit or '_': '{name}'"
+            )))
+        }
+    }
+
+    /// GitLab snippet ids are numeric
+    fn validate_snippet_id(id: &str) -> Result<(), GitUrlParseError> {
+        if !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()) {
+            Ok(())
+        } else {
+            Err(GitUrlParseError::ProviderInvalidName(format!(
+                "GitLab snippet id must be numeric: '{id}'"
+            )))
+        }
+    }
+
+    /// Split a web url path into the project and the page after the `/-/` separator
+    pub(crate) fn split_web_path(parts: &[String]) -> Option<WebPath> {
+        let (project, rest) = match parts.iter().position(|p| p == "-") {
+            Some(separator) => (&parts[..separator], &parts[separator + 1..]),
+            None => (parts, &[][..]),
+        };
+
+        let (repo, groups) = project.split_last()?;
+        if groups.is_empty() {
+            return None;
+        }
+
+        let repo = repo.strip_suffix(".git").unwrap_or(repo);
+        let mut clone_path = groups.to_vec();
+        clone_path.push(format!("{repo}.git"));
+
+        Some(WebPath::from_pages(clone_path, rest))
+    }
 }
 
 impl GitProvider<GitUrl, GitUrlParseError> for GitLabProvider {
     fn from_git_url(url: &GitUrl) -> Result<Self, GitUrlParseError> {
+        if url.hint() == GitUrlParseHint::Filelike {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
         let path = url.path();
         Self::parse_path(path).map(|(_, provider)| provider)
     }
@@ -138,7 +393,157 @@ impl GitProvider<GitUrl, GitUrlParseError> for GitLabProvider {
 #[cfg(feature = "url")]
 impl GitProvider<Url, GitUrlParseError> for GitLabProvider {
     fn from_git_url(url: &Url) -> Result<Self, GitUrlParseError> {
+        if url.scheme() == "file" {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
         let path = url.path();
         Self::parse_path(path).map(|(_, provider)| provider)
     }
 }
+
+impl ToGitUrl for GitLabProvider {
+    fn clone_path(&self, _protocol: CloneProtocol) -> String {
+        match (self.kind, &self.snippet_id) {
+            (GitLabRepoKind::Wiki, _) => format!("{}.wiki.git", self.fullname()),
+            (GitLabRepoKind::Design, _) => format!("{}.design.git", self.fullname()),
+            (GitLabRepoKind::Snippet, Some(id)) if !self.owner.is_empty() => {
+                format!("{}/snippets/{id}.git", self.fullname())
+            }
+            _ => format!("{}.git", self.fullname()),
+        }
+    }
+}
+
+impl ToWebUrl for GitLabProvider {
+    /// Wikis, designs and snippets only have a home page
+    fn web_url(&self, url: &GitUrl, target: WebTarget) -> Result<String, GitUrlParseError> {
+        let origin = web_origin(url)?;
+        let base = format!("{origin}/{}", self.fullname());
+        let anchor = |lines: Option<LineRange>| lines.map(|l| format!("#L{l}")).unwrap_or_default();
+
+        let url = match (self.kind, target) {
+            (GitLabRepoKind::Wiki, WebTarget::Repo) => format!("{base}/-/wikis/home"),
+            (GitLabRepoKind::Snippet, WebTarget::Repo) => match &self.snippet_id {
+                Some(id) if self.owner.is_empty() => format!("{origin}/-/snippets/{id}"),
+                Some(id) => format!("{base}/-/snippets/{id}"),
+                None => base,
+            },
+            (_, WebTarget::Repo) => base,
+            (GitLabRepoKind::Project, WebTarget::Tree { git_ref, path }) => match path {
+                Some(path) => format!(
+                    "{base}/-/tree/{}/{}",
+                    encode_path(git_ref),
+                    encode_path(path)
+                ),
+                None => format!("{base}/-/tree/{}", encode_path(git_ref)),
+            },
+            (
+                GitLabRepoKind::Project,
+                WebTarget::Blob {
+                    git_ref,
+                    path,
+                    lines,
+                },
+            ) => format!(
+                "{base}/-/blob/{}/{}{}",
+                encode_path(git_ref),
+                encode_path(path),
+                anchor(lines)
+            ),
+            (GitLabRepoKind::Project, WebTarget::Commit { sha }) => {
+                format!("{base}/-/commit/{}", encode_path(sha))
+            }
+            (GitLabRepoKind::Project, WebTarget::Compare { base: from, head }) => format!(
+                "{base}/-/compare/{}...{}",
+                encode_path(from),
+                encode_path(head)
+            ),
+            (
+                GitLabRepoKind::Project,
+                WebTarget::Blame {
+                    git_ref,
+                    path,
+                    lines,
+                },
+            ) => format!(
+                "{base}/-/blame/{}/{}{}",
+                encode_path(git_ref),
+                encode_path(path),
+                anchor(lines)
+            ),
+            _ => return Err(GitUrlParseError::ProviderUnsupported),
+        };
+
+        Ok(url)
+    }
+}
+
+impl ToApiUrl for GitLabProvider {
+    fn api_base_url(&self, url: &GitUrl) -> Result<String, GitUrlParseError> {
+        Ok(format!("{}/api/v4", web_origin(url)?))
+    }
+
+    /// Projects are addressed by their percent-encoded path (i.e. `group%2Fsubgroup%2Frepo`).
+    /// Wikis and snippets only have a repo resource, designs don't have any
+    fn api_url(&self, url: &GitUrl, resource: ApiResource) -> Result<String, GitUrlParseError> {
+        let base = self.api_base_url(url)?;
+        let project = match (self.kind, &self.snippet_id) {
+            (GitLabRepoKind::Snippet, Some(_)) if self.owner.is_empty() => String::new(),
+            _ => {
+                let mut names = vec![self.owner.as_str()];
+                names.extend(self.subgroup.iter().flatten().map(String::as_str));
+                names.push(&self.repo);
+                format!("{base}/projects/{}", encode_component(&names.join("/")))
+            }
+        };
+
+        let url = match (self.kind, resource) {
+            (GitLabRepoKind::Wiki, ApiResource::Repo) => format!("{project}/wikis"),
+            (GitLabRepoKind::Snippet, ApiResource::Repo) => match &self.snippet_id {
+                Some(id) if self.owner.is_empty() => {
+                    format!("{base}/snippets/{}", encode_component(id))
+                }
+                Some(id) => format!("{project}/snippets/{}", encode_component(id)),
+                None => return Err(GitUrlParseError::ProviderUnsupported),
+            },
+            (GitLabRepoKind::Project, ApiResource::Repo) => project,
+            (GitLabRepoKind::Project, ApiResource::Branches) => {
+                format!("{project}/repository/branches")
+            }
+            (GitLabRepoKind::Project, ApiResource::PullRequests) => {
+                format!("{project}/merge_requests")
+            }
+            (GitLabRepoKind::Project, ApiResource::File { git_ref, path }) => format!(
+                "{project}/repository/files/{}/raw?ref={}",
+                encode_component(path.trim_matches('/')),
+                encode_component(git_ref)
+            ),
+            _ => return Err(GitUrlParseError::ProviderUnsupported),
+        };
+
+        Ok(url)
+    }
+}
+
+impl ToRawFileUrl for GitLabProvider {
+    /// Wikis, designs and snippets are unsupported
+    fn raw_file_url(
+        &self,
+        url: &GitUrl,
+        git_ref: &str,
+        path: &str,
+    ) -> Result<String, GitUrlParseError> {
+        let origin = web_origin(url)?;
+        if self.kind != GitLabRepoKind::Project {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Ok(format!(
+            "{origin}/{}/-/raw/{}/{}",
+            self.fullname(),
+            encode_path(git_ref),
+            encode_path(path)
+        ))
+    }
+}
@@ -0,0 +1,326 @@
+use super::{CloneProtocol, GitProvider, ToGitUrl};
+use crate::types::GitUrlParseHint;
+use crate::{GitUrl, GitUrlParseError};
+
+use getset::{CopyGetters, Getters};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "url")]
+use url::Url;
+
+/// Git host of Cloud Source Repositories
+const CSR_HOST: &str = "source.developers.google.com";
+
+/// Web console host of Cloud Source Repositories
+const CSR_CONSOLE_HOST: &str = "source.cloud.google.com";
+
+/// SSH port of Cloud Source Repositories
+const CSR_SSH_PORT: u16 = 2022;
+
+/// Domain of Secure Source Manager instances
+const SSM_DOMAIN: &str = "sourcemanager.dev";
+
+/// The Google Cloud service hosting the repo
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum GoogleSourceService {
+    /// Cloud Source Repositories (`source.developers.google.com`)
+    #[default]
+    CloudSourceRepositories,
+    /// Secure Source Manager instance (`<instance>-<project number>-git.<location>.sourcemanager.dev`)
+    SecureSourceManager,
+}
+
+/// ## Google Cloud Source Repositories and Secure Source Manager provider
+///
+/// ## Supported URL Formats
+///
+/// - `https://source.developers.google.com/p/<project>/r/<repo>`
+/// - `ssh://user@source.developers.google.com:2022/p/<project>/r/<repo>`
+/// - `https://source.cloud.google.com/<project>/<repo>` (web console)
+/// - `https://<instance>-<project number>-git.<location>.sourcemanager.dev/<project>/<repo>.git`
+/// - `ssh://<instance>-<project number>-ssh.<location>.sourcemanager.dev/<project>/<repo>.git`
+///
+/// ## Examples
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::provider::{GoogleSourceProvider, GoogleSourceService};
+///
+/// fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+///     let url = GitUrl::parse("ssh://jdoe@source.developers.google.com:2022/p/my-project/r/my-repo")?;
+///     let provider: GoogleSourceProvider = url.provider_info()?;
+///     assert_eq!(provider.service(), GoogleSourceService::CloudSourceRepositories);
+///     assert_eq!(provider.project(), "my-project");
+///     assert_eq!(provider.repo(), "my-repo");
+///     assert_eq!(
+///         provider.https_url(),
+///         "https://source.developers.google.com/p/my-project/r/my-repo"
+///     );
+///     assert_eq!(
+///         provider.ssh_url(Some("jdoe")),
+///         "ssh://jdoe@source.developers.google.com:2022/p/my-project/r/my-repo"
+///     );
+///
+///     let url = GitUrl::parse("https://prod-123456789-git.us-central1.sourcemanager.dev/my-project/my-repo.git")?;
+///     let provider: GoogleSourceProvider = url.provider_info()?;
+///     assert_eq!(provider.service(), GoogleSourceService::SecureSourceManager);
+///     assert_eq!(provider.instance(), Some("prod"));
+///     assert_eq!(provider.location(), Some("us-central1"));
+///     assert_eq!(
+///         provider.ssh_url(None),
+///         "ssh://prod-123456789-ssh.us-central1.sourcemanager.dev/my-project/my-repo.git"
+///     );
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Getters, CopyGetters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GoogleSourceProvider {
+    /// Service hosting the repo
+    #[getset(get_copy = "pub")]
+    service: GoogleSourceService,
+    /// Google Cloud project id
+    #[getset(get = "pub")]
+    project: String,
+    /// Repo name
+    #[getset(get = "pub")]
+    repo: String,
+    /// Secure Source Manager instance id
+    instance: Option<String>,
+    /// Google Cloud project number of the Secure Source Manager instance
+    project_number: Option<String>,
+    /// Google Cloud location of the Secure Source Manager instance
+    location: Option<String>,
+}
+
+impl GoogleSourceProvider {
+    /// Secure Source Manager instance id
+    pub fn instance(&self) -> Option<&str> {
+        self.instance.as_deref()
+    }
+
+    /// Google Cloud project number of the Secure Source Manager instance
+    pub fn project_number(&self) -> Option<&str> {
+        self.project_number.as_deref()
+    }
+
+    /// Google Cloud location of the Secure Source Manager instance (i.e. `us-central1`)
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+
+    /// Helper method to get the full name of a repo: `{project}/{repo}`
+    pub fn fullname(&self) -> String {
+        format!("{}/{}", self.project, self.repo)
+    }
+
+    /// HTTPS clone url
+    pub fn https_url(&self) -> String {
+        match self.service {
+            GoogleSourceService::CloudSourceRepositories => {
+                format!("https://{CSR_HOST}/p/{}/r/{}", self.project, self.repo)
+            }
+            GoogleSourceService::SecureSourceManager => format!(
+                "https://{}/{}/{}.git",
+                self.ssm_host("git"),
+                self.project,
+                self.repo
+            ),
+        }
+    }
+
+    /// SSH clone url, with `user` if given. Cloud Source Repositories uses port `2022`
+    pub fn ssh_url(&self, user: Option<&str>) -> String {
+        let user = user.map(|u| format!("{u}@")).unwrap_or_default();
+
+        match self.service {
+            GoogleSourceService::CloudSourceRepositories => format!(
+                "ssh://{user}{CSR_HOST}:{CSR_SSH_PORT}/p/{}/r/{}",
+                self.project, self.repo
+            ),
+            GoogleSourceService::SecureSourceManager => format!(
+                "ssh://{user}{}/{}/{}.git",
+                self.ssm_host("ssh"),
+                self.project,
+                self.repo
+            ),
+        }
+    }
+
+    /// Secure Source Manager host of the instance for `protocol` (`git` or `ssh`)
+    fn ssm_host(&self, protocol: &str) -> String {
+        format!(
+            "{}-{}-{protocol}.{}.{SSM_DOMAIN}",
+            self.instance.as_deref().unwrap_or_default(),
+            self.project_number.as_deref().unwrap_or_default(),
+            self.location.as_deref().unwrap_or_default()
+        )
+    }
+
+    /// Parse the host and path of url for Cloud Source Repositories and Secure Source Manager patterns
+    fn parse_path(
+        host: Option<&str>,
+        input: &str,
+    ) -> Result<GoogleSourceProvider, GitUrlParseError> {
+        let host = host.unwrap_or_default().to_ascii_lowercase();
+        let parts: Vec<&str> = input.split('/').filter(|s| !s.is_empty()).collect();
+
+        if let Some(instance_host) = host.strip_suffix(&format!(".{SSM_DOMAIN}")) {
+            return Self::parse_ssm(instance_host, &parts);
+        }
+
+        let (project, repo) = match (host.as_str(), parts.as_slice()) {
+            (CSR_CONSOLE_HOST, [project, repo, ..]) => (*project, *repo),
+            (_, ["p", project, "r", repo, ..]) => (*project, *repo),
+            _ => {
+                return Err(GitUrlParseError::ProviderParseFail(
+                    "Path expected: ex. '/p/project/r/repo'".into(),
+                ));
+            }
+        };
+
+        let repo = repo.strip_suffix(".git").unwrap_or(repo);
+
+        Self::validate_project(project)?;
+        Self::validate_repo(repo)?;
+
+        Ok(GoogleSourceProvider {
+            service: GoogleSourceService::CloudSourceRepositories,
+            project: project.to_string(),
+            repo: repo.to_string(),
+            instance: None,
+            project_number: None,
+            location: None,
+        })
+    }
+
+    /// Parse `<instance>-<project number>-<git|ssh>.<location>` host and `/<project>/<repo>.git` path
+    fn parse_ssm(
+        instance_host: &str,
+        parts: &[&str],
+    ) -> Result<GoogleSourceProvider, GitUrlParseError> {
+        let invalid_host = || {
+            GitUrlParseError::ProviderParseFail(format!(
+                "Secure Source Manager host expected: ex. 'instance-123-git.location.{SSM_DOMAIN}'"
+            ))
+        };
+
+        let (instance_label, location) = instance_host.split_once('.').ok_or_else(invalid_host)?;
+
+        let (instance, project_number) = instance_label
+            .strip_suffix("-git")
+            .or_else(|| instance_label.strip_suffix("-ssh"))
+            .and_then(|rest| rest.rsplit_once('-'))
+            .filter(|(instance, number)| {
+                !instance.is_empty()
+                    && !number.is_empty()
+                    && number.chars().all(|c| c.is_ascii_digit())
+            })
+            .ok_or_else(invalid_host)?;
+
+        if location.is_empty() || location.contains('.') {
+            return Err(invalid_host());
+        }
+
+        let (project, repo) = match parts {
+            [project, repo] => (*project, *repo),
+            _ => {
+                return Err(GitUrlParseError::ProviderParseFail(
+                    "Path expected: ex. '/project/repo.git'".into(),
+                ));
+            }
+        };
+
+        let repo = repo.strip_suffix(".git").unwrap_or(repo);
+
+        Self::validate_project(project)?;
+        Self::validate_repo(repo)?;
+
+        Ok(GoogleSourceProvider {
+            service: GoogleSourceService::SecureSourceManager,
+            project: project.to_string(),
+            repo: repo.to_string(),
+            instance: Some(instance.to_string()),
+            project_number: Some(project_number.to_string()),
+            location: Some(location.to_string()),
+        })
+    }
+
+    /// Google Cloud project ids: ascii lowercase letters, digits and `-`. Legacy ids can be domain scoped with `.` and `:`
+    fn validate_project(project: &str) -> Result<(), GitUrlParseError> {
+        let valid = !project.is_empty()
+            && project
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "-.:".contains(c));
+
+        if valid {
+            Ok(())
+        } else {
+            Err(GitUrlParseError::ProviderInvalidName(format!(
+                "Google Cloud project id must be ascii lowercase letters, digits or '-': '{project}'"
+            )))
+        }
+    }
+
+    /// Repo names: ascii letters, digits, `.`, `_` or `-`
+    fn validate_repo(repo: &str) -> Result<(), GitUrlParseError> {
+        let valid = !repo.is_empty()
+            && repo
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "._-".contains(c));
+
+        if valid {
+            Ok(())
+        } else {
+            Err(GitUrlParseError::ProviderInvalidName(format!(
+                "Repo name must be ascii letters, digits, '.', '_' or '-': '{repo}'"
+            )))
+        }
+    }
+}
+
+impl GitProvider<GitUrl, GitUrlParseError> for GoogleSourceProvider {
+    fn from_git_url(url: &GitUrl) -> Result<Self, GitUrlParseError> {
+        if url.hint() == GitUrlParseHint::Filelike {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Self::parse_path(url.host(), url.path())
+    }
+}
+
+#[cfg(feature = "url")]
+impl GitProvider<Url, GitUrlParseError> for GoogleSourceProvider {
+    fn from_git_url(url: &Url) -> Result<Self, GitUrlParseError> {
+        if url.scheme() == "file" {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Self::parse_path(url.host_str(), url.path())
+    }
+}
+
+impl ToGitUrl for GoogleSourceProvider {
+    /// Secure Source Manager uses different instance hosts for https and ssh
+    fn clone_path(&self, _protocol: CloneProtocol) -> String {
+        match self.service {
+            GoogleSourceService::CloudSourceRepositories => {
+                format!("p/{}/r/{}", self.project, self.repo)
+            }
+            GoogleSourceService::SecureSourceManager => {
+                format!("{}/{}.git", self.project, self.repo)
+            }
+        }
+    }
+
+    fn ssh_port(&self) -> Option<u16> {
+        match self.service {
+            GoogleSourceService::CloudSourceRepositories => Some(CSR_SSH_PORT),
+            GoogleSourceService::SecureSourceManager => None,
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use super::web::{encode_component, encode_path};
+use super::{CloneProtocol, GitProvider, ToGitUrl};
+use crate::types::GitUrlParseHint;
+use crate::{GitUrl, GitUrlParseError};
+
+use getset::{CopyGetters, Getters};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "url")]
+use url::Url;
+
+/// Path segments that follow the repo name in Hugging Face web urls, before a revision or id
+const WEB_SEGMENTS: &[&str] = &[
+    "tree",
+    "blob",
+    "resolve",
+    "raw",
+    "commit",
+    "commits",
+    "discussions",
+    "edit",
+];
+
+/// The type of repo hosted by the Hugging Face Hub
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum HuggingFaceRepoType {
+    /// Model repo (i.e. `huggingface.co/org/model`)
+    #[default]
+    Model,
+    /// Dataset repo (i.e. `huggingface.co/datasets/org/name`)
+    Dataset,
+    /// Space repo (i.e. `huggingface.co/spaces/org/app`)
+    Space,
+}
+
+impl HuggingFaceRepoType {
+    /// Url path prefix of the repo type: empty for models, `datasets/` or `spaces/`
+    fn path_prefix(&self) -> &'static str {
+        match self {
+            HuggingFaceRepoType::Model => "",
+            HuggingFaceRepoType::Dataset => "datasets/",
+            HuggingFaceRepoType::Space => "spaces/",
+        }
+    }
+}
+
+/// ## Hugging Face Hub repository provider
+///
+/// ## Supported URL Formats
+///
+/// - `https://huggingface.co/org/model`
+/// - `https://huggingface.co/datasets/org/name`
+/// - `https://huggingface.co/spaces/org/app`
+/// - `git@hf.co:org/model`
+/// - `git@hf.co:datasets/org/name`
+/// - `https://huggingface.co/model` (legacy models without namespace)
+/// - `https://huggingface.co/org/model/blob/main/config.json` (web urls)
+///
+/// ## Examples
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::provider::{HuggingFaceProvider, HuggingFaceRepoType};
+///
+/// fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+///     let url = GitUrl::parse("https://huggingface.co/datasets/stanfordnlp/imdb")?;
+///     let provider: HuggingFaceProvider = url.provider_info()?;
+///     assert_eq!(provider.repo_type(), HuggingFaceRepoType::Dataset);
+///     assert_eq!(provider.namespace(), Some("stanfordnlp"));
+///     assert_eq!(provider.name(), "imdb");
+///     assert_eq!(provider.fullname(), "stanfordnlp/imdb");
+///     assert_eq!(
+///         provider.resolve_url("main", "README.md"),
+///         "https://huggingface.co/datasets/stanfordnlp/imdb/resolve/main/README.md"
+///     );
+///
+///     let url = GitUrl::parse("git@hf.co:openai-community/gpt2")?;
+///     let provider: HuggingFaceProvider = url.provider_info()?;
+///     assert_eq!(provider.repo_type(), HuggingFaceRepoType::Model);
+///     assert_eq!(provider.fullname(), "openai-community/gpt2");
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Getters, CopyGetters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HuggingFaceProvider {
+    /// Type of repo
+    #[getset(get_copy = "pub")]
+    repo_type: HuggingFaceRepoType,
+    /// User or organization. Legacy models may not have a namespace
+    namespace: Option<String>,
+    /// Repo name
+    #[getset(get = "pub")]
+    name: String,
+}
+
+impl HuggingFaceProvider {
+    /// User or organization. Legacy models may not have a namespace
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// Helper method to get the repo id: `{namespace}/{name}`, or `{name}` without namespace
+    pub fn fullname(&self) -> String {
+        match &self.namespace {
+            Some(namespace) => format!("{namespace}/{}", self.name),
+            None => self.name.clone(),
+        }
+    }
+
+    /// Url of the repo page, which is also the https clone url
+    pub fn browse_url(&self) -> String {
+        format!(
+            "https://huggingface.co/{}{}",
+            self.repo_type.path_prefix(),
+            self.fullname()
+        )
+    }
+
+    /// Download url of `file` at revision `rev`: `.../resolve/{rev}/{file}`.
+    /// The revision is a single encoded segment (i.e. `refs%2Fpr%2F1`)
+    pub fn resolve_url(&self, rev: &str, file: &str) -> String {
+        format!(
+            "{}/resolve/{}/{}",
+            self.browse_url(),
+            encode_component(rev),
+            encode_path(file)
+        )
+    }
+
+    /// Parse the path of url for Hugging Face patterns
+    fn parse_path(input: &str) -> Result<HuggingFaceProvider, GitUrlParseError> {
+        let parts: Vec<&str> = input.split('/').filter(|s| !s.is_empty()).collect();
+
+        let (repo_type, parts) = match parts.as_slice() {
+            ["datasets", rest @ ..] => (HuggingFaceRepoType::Dataset, rest),
+            ["spaces", rest @ ..] => (HuggingFaceRepoType::Space, rest),
+            rest => (HuggingFaceRepoType::Model, rest),
+        };
+
+        let (namespace, name) = match parts {
+            [name] => (None, *name),
+            // Legacy models only when the page has a revision, so `org/tree` stays a repo
+            [name, web, _, ..] if WEB_SEGMENTS.contains(web) => (None, *name),
+            [namespace, name, ..] => (Some(*namespace), *name),
+            [] => {
+                return Err(GitUrlParseError::ProviderParseFail(
+                    "Path expected: ex. '/org/model' or '/datasets/org/name'".into(),
+                ));
+            }
+        };
+
+        let name = name.strip_suffix(".git").unwrap_or(name);
+
+        for part in namespace.iter().chain([&name]) {
+            Self::validate_name(part)?;
+        }
+
+        Ok(HuggingFaceProvider {
+            repo_type,
+            namespace: namespace.map(|n| n.to_string()),
+            name: name.to_string(),
+        })
+    }
+
+    /// Hugging Face namespaces and repo names: 1-96 ascii letters, digits, `.`, `-` or `_`
+    fn validate_name(name: &str) -> Result<(), GitUrlParseError> {
+        let valid = !name.is_empty()
+            && name.len() <= 96
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '_');
+
+        if valid {
+            Ok(())
+        } else {
+            Err(GitUrlParseError::ProviderInvalidName(format!(
+                "Hugging Face names must be 1-96 ascii letters, digits, '.', '-' or '_': '{name}'"
+            )))
+        }
+    }
+}
+
+impl GitProvider<GitUrl, GitUrlParseError> for HuggingFaceProvider {
+    fn from_git_url(url: &GitUrl) -> Result<Self, GitUrlParseError> {
+        if url.hint() == GitUrlParseHint::Filelike {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Self::parse_path(url.path())
+    }
+}
+
+#[cfg(feature = "url")]
+impl GitProvider<Url, GitUrlParseError> for HuggingFaceProvider {
+    fn from_git_url(url: &Url) -> Result<Self, GitUrlParseError> {
+        if url.scheme() == "file" {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Self::parse_path(url.path())
+    }
+}
+
+impl ToGitUrl for HuggingFaceProvider {
+    fn clone_path(&self, _protocol: CloneProtocol) -> String {
+        format!("{}{}", self.repo_type.path_prefix(), self.fullname())
+    }
+}
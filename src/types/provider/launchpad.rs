@@ -0,0 +1,252 @@
+use super::{CloneProtocol, GitProvider, ToGitUrl};
+use crate::types::GitUrlParseHint;
+use crate::{GitUrl, GitUrlParseError};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "url")]
+use url::Url;
+
+/// Scheme of the Launchpad shorthand urls
+const SHORTHAND_SCHEME: &str = "lp";
+
+/// Git host of Launchpad
+const GIT_HOST: &str = "git.launchpad.net";
+
+/// Path segment between the owner or project and the repo name
+const REPO_SEGMENT: &str = "+git";
+
+/// ## Launchpad repository provider
+///
+/// ## Supported URL Formats
+///
+/// - `https://git.launchpad.net/project` (default repo of a project)
+/// - `https://git.launchpad.net/~user/project` (default repo of an owner for a project)
+/// - `git+ssh://git.launchpad.net/~user/project/+git/repo`
+/// - `git+ssh://git.launchpad.net/~user/+git/repo` (personal repo)
+/// - `https://code.launchpad.net/~user/project/+git/repo/+ref/main` (web urls)
+/// - `lp:project`, `lp:~user/project/+git/repo` (shorthands)
+///
+/// The shorthands can be expanded to clone urls with [`LaunchpadProvider::expand_shorthand()`]
+///
+/// ## Examples
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::provider::LaunchpadProvider;
+///
+/// fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+///     let url = GitUrl::parse("git+ssh://git.launchpad.net/~user/project/+git/repo")?;
+///     let provider: LaunchpadProvider = url.provider_info()?;
+///     assert_eq!(provider.owner(), Some("user"));
+///     assert_eq!(provider.project(), Some("project"));
+///     assert_eq!(provider.repo(), Some("repo"));
+///     assert_eq!(provider.fullname(), "~user/project/+git/repo");
+///     assert_eq!(provider.shorthand(), "lp:~user/project/+git/repo");
+///
+///     let url = GitUrl::parse("lp:project")?;
+///     let provider: LaunchpadProvider = url.provider_info()?;
+///     assert_eq!(provider.owner(), None);
+///     assert_eq!(provider.project(), Some("project"));
+///     assert_eq!(provider.repo(), None);
+///
+///     assert_eq!(
+///         LaunchpadProvider::expand_shorthand("lp:project")?,
+///         "https://git.launchpad.net/project"
+///     );
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LaunchpadProvider {
+    /// Owning person or team, without the `~` prefix
+    owner: Option<String>,
+    /// Project of the repo. Personal repos don't have a project
+    project: Option<String>,
+    /// Repo name. The default repo of a project or owner is named by them instead
+    repo: Option<String>,
+}
+
+impl LaunchpadProvider {
+    /// Owning person or team, without the `~` prefix
+    pub fn owner(&self) -> Option<&str> {
+        self.owner.as_deref()
+    }
+
+    /// Project of the repo. Personal repos don't have a project
+    pub fn project(&self) -> Option<&str> {
+        self.project.as_deref()
+    }
+
+    /// Repo name. The default repo of a project or owner is named by them instead
+    pub fn repo(&self) -> Option<&str> {
+        self.repo.as_deref()
+    }
+
+    /// Helper method to get the unique name of a repo: `[~{owner}/][{project}][/+git/{repo}]`
+    pub fn fullname(&self) -> String {
+        let mut parts = Vec::new();
+
+        if let Some(owner) = &self.owner {
+            parts.push(format!("~{owner}"));
+        }
+        if let Some(project) = &self.project {
+            parts.push(project.clone());
+        }
+        if let Some(repo) = &self.repo {
+            parts.push(format!("{REPO_SEGMENT}/{repo}"));
+        }
+
+        parts.join("/")
+    }
+
+    /// Shorthand url of the repo: `lp:{fullname}`
+    pub fn shorthand(&self) -> String {
+        format!("{SHORTHAND_SCHEME}:{}", self.fullname())
+    }
+
+    /// Anonymous HTTPS clone url
+    pub fn https_url(&self) -> String {
+        format!("https://{GIT_HOST}/{}", self.fullname())
+    }
+
+    /// SSH clone url, with the Launchpad user name as user if given
+    pub fn ssh_url(&self, user: Option<&str>) -> String {
+        let user = user.map(|u| format!("{u}@")).unwrap_or_default();
+        format!("git+ssh://{user}{GIT_HOST}/{}", self.fullname())
+    }
+
+    /// Expand a `lp:` shorthand into its HTTPS clone url
+    pub fn expand_shorthand(input: &str) -> Result<String, GitUrlParseError> {
+        Self::from_shorthand(input).map(|provider| provider.https_url())
+    }
+
+    /// Parse a `lp:` shorthand url
+    fn from_shorthand(input: &str) -> Result<LaunchpadProvider, GitUrlParseError> {
+        let path = input
+            .strip_prefix(SHORTHAND_SCHEME)
+            .and_then(|rest| rest.strip_prefix(':'))
+            .ok_or_else(|| {
+                GitUrlParseError::ProviderParseFail(
+                    "Shorthand expected: ex. 'lp:project' or 'lp:~user/project/+git/repo'".into(),
+                )
+            })?;
+
+        Self::parse_path(path)
+    }
+
+    /// Parse the path of url for Launchpad patterns
+    fn parse_path(input: &str) -> Result<LaunchpadProvider, GitUrlParseError> {
+        let parts: Vec<&str> = input.split('/').filter(|s| !s.is_empty()).collect();
+
+        let (prefix, repo) = match parts.iter().position(|p| *p == REPO_SEGMENT) {
+            Some(marker) => match parts.get(marker + 1) {
+                Some(repo) => (&parts[..marker], Some(*repo)),
+                None => {
+                    return Err(GitUrlParseError::ProviderParseFail(
+                        "Path needs a repo after '+git/'".into(),
+                    ));
+                }
+            },
+            // Ignore web pages like `+ref/main` after the project
+            None => {
+                let end = parts
+                    .iter()
+                    .position(|p| p.starts_with('+'))
+                    .unwrap_or(parts.len());
+                (&parts[..end], None)
+            }
+        };
+
+        let (owner, project) = match prefix {
+            [owner] if repo.is_some() => (Some(*owner), None),
+            [project] if repo.is_none() && !project.starts_with('~') => (None, Some(*project)),
+            [owner, project] => (Some(*owner), Some(*project)),
+            _ => {
+                return Err(GitUrlParseError::ProviderParseFail(
+                    "Path expected: ex. '/project' or '/~user/project/+git/repo'".into(),
+                ));
+            }
+        };
+
+        let owner = match owner {
+            Some(owner) => Some(owner.strip_prefix('~').ok_or_else(|| {
+                GitUrlParseError::ProviderParseFail(format!(
+                    "Launchpad owner must start with '~': '{owner}'"
+                ))
+            })?),
+            None => None,
+        };
+
+        // `.git` is only allowed on the last segment
+        let (project, repo) = match repo {
+            Some(repo) => (project, Some(repo.strip_suffix(".git").unwrap_or(repo))),
+            None => (project.map(|p| p.strip_suffix(".git").unwrap_or(p)), None),
+        };
+
+        for name in [owner, project, repo].into_iter().flatten() {
+            Self::validate_name(name)?;
+        }
+
+        Ok(LaunchpadProvider {
+            owner: owner.map(|o| o.to_string()),
+            project: project.map(|p| p.to_string()),
+            repo: repo.map(|r| r.to_string()),
+        })
+    }
+
+    /// Launchpad names: ascii letters and digits, then also `+`, `.`, `-` or `_`
+    fn validate_name(name: &str) -> Result<(), GitUrlParseError> {
+        let mut chars = name.chars();
+        let valid = chars.next().is_some_and(|c| c.is_ascii_alphanumeric())
+            && chars.all(|c| c.is_ascii_alphanumeric() || "+.-_".contains(c));
+
+        if valid {
+            Ok(())
+        } else {
+            Err(GitUrlParseError::ProviderInvalidName(format!(
+                "Launchpad names must start with an ascii letter or digit, then letters, digits, '+', '.', '-' or '_': '{name}'"
+            )))
+        }
+    }
+}
+
+impl GitProvider<GitUrl, GitUrlParseError> for LaunchpadProvider {
+    fn from_git_url(url: &GitUrl) -> Result<Self, GitUrlParseError> {
+        if url.hint() == GitUrlParseHint::Filelike {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        // `lp:project` is parsed as scp-like, with `lp` host
+        if url.hint() == GitUrlParseHint::Sshlike && url.host() == Some(SHORTHAND_SCHEME) {
+            return Self::from_shorthand(&url.to_string());
+        }
+
+        Self::parse_path(url.path())
+    }
+}
+
+#[cfg(feature = "url")]
+impl GitProvider<Url, GitUrlParseError> for LaunchpadProvider {
+    fn from_git_url(url: &Url) -> Result<Self, GitUrlParseError> {
+        match url.scheme() {
+            "file" => Err(GitUrlParseError::ProviderUnsupported),
+            SHORTHAND_SCHEME => Self::from_shorthand(url.as_str()),
+            _ => Self::parse_path(url.path()),
+        }
+    }
+}
+
+impl ToGitUrl for LaunchpadProvider {
+    fn clone_path(&self, _protocol: CloneProtocol) -> String {
+        self.fullname()
+    }
+
+    /// Launchpad ssh users are the account user names
+    fn ssh_user(&self) -> Option<&str> {
+        None
+    }
+}
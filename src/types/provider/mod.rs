@@ -6,19 +6,99 @@
 //!
 //! - [Generic Git repositories](crate::types::provider::GenericProvider)
 //! - [Azure DevOps](crate::types::provider::AzureDevOpsProvider)
+//! - [Bitbucket Cloud](crate::types::provider::BitbucketCloudProvider)
+//! - [Bitbucket Server / Data Center](crate::types::provider::BitbucketServerProvider)
+//! - [cgit / gitweb](crate::types::provider::CgitProvider)
+//! - [AWS CodeCommit](crate::types::provider::CodeCommitProvider)
+//! - [Gerrit](crate::types::provider::GerritProvider)
+//! - [Gitea / Forgejo / Codeberg](crate::types::provider::GiteaProvider)
+//! - [GitHub](crate::types::provider::GitHubProvider)
 //! - [GitLab](crate::types::provider::GitLabProvider)
-//! - Custom (via [`GitProvider`] trait)
+//! - [Google Cloud Source Repositories / Secure Source Manager](crate::types::provider::GoogleSourceProvider)
+//! - [Hugging Face Hub](crate::types::provider::HuggingFaceProvider)
+//! - [Launchpad](crate::types::provider::LaunchpadProvider)
+//! - [Pagure / Fedora dist-git](crate::types::provider::PagureProvider)
+//! - [SourceHut](crate::types::provider::SourceHutProvider)
+//! - Custom (via [`GitProvider`] trait, or path patterns with [`PatternProvider`])
+//!
+//! Built-in provider info can be rendered back into clone urls with [`ToGitUrl`],
+//! into web frontend urls with [`ToWebUrl`], and into REST API urls with [`ToApiUrl`].
+//! Browser links are split back into the clone url and the location within the repo with [`WebLink`]
+//!
+//! ## Provider Detection
+//!
+//! When the provider isn't known ahead of time, [`crate::GitUrl::detect_provider()`]
+//! picks a built-in provider from the url host and path shape, returned as [`AnyProvider`]
+//!
+//! Self-hosted instances can be mapped to a provider with a [`ProviderRegistry`],
+//! and detected with [`crate::GitUrl::detect_provider_with()`].
+//! Hosts registered with a [`PatternSet`] are parsed with its path patterns
 
+/// REST API urls of repos
+pub mod api;
 /// Azure DevOps git host
 pub mod azure_devops;
+/// Bitbucket Cloud and Bitbucket Server git hosts
+pub mod bitbucket;
+/// cgit and gitweb git hosts
+pub mod cgit;
+/// AWS CodeCommit git host
+pub mod codecommit;
+/// Built-in provider detection
+pub mod detect;
 /// Generic git host
 pub mod generic;
+/// Gerrit code review git host
+pub mod gerrit;
+/// Gitea and Forgejo git hosts
+pub mod gitea;
+/// GitHub git host
+pub mod github;
 /// GitLab git host
 pub mod gitlab;
+/// Google Cloud Source Repositories and Secure Source Manager git hosts
+pub mod google_source;
+/// Hugging Face Hub git host
+pub mod huggingface;
+/// Launchpad git host
+pub mod launchpad;
+/// Pagure and Fedora dist-git hosts
+pub mod pagure;
+/// Declarative path pattern providers
+pub mod pattern;
+/// Host to provider mapping
+pub mod registry;
+/// SourceHut git and hg hosts
+pub mod sourcehut;
+/// Web frontend urls of repos
+pub mod web;
 
-pub use azure_devops::AzureDevOpsProvider;
+pub use api::{ApiResource, ToApiUrl};
+pub use azure_devops::{AzureDevOpsHost, AzureDevOpsProvider};
+pub use bitbucket::{BitbucketCloudProvider, BitbucketServerProvider};
+pub use cgit::{CgitFlavor, CgitProvider};
+pub use codecommit::{CodeCommitEndpoint, CodeCommitProvider};
+pub use detect::{AnyProvider, Confidence, DetectedProvider, ProviderKind};
 pub use generic::GenericProvider;
-pub use gitlab::GitLabProvider;
+pub use gerrit::GerritProvider;
+pub use gitea::{GiteaProvider, GiteaRepoKind};
+pub use github::{GitHubProvider, GitHubRepoKind};
+pub use gitlab::{GitLabProvider, GitLabRepoKind};
+pub use google_source::{GoogleSourceProvider, GoogleSourceService};
+pub use huggingface::{HuggingFaceProvider, HuggingFaceRepoType};
+pub use launchpad::LaunchpadProvider;
+pub use pagure::PagureProvider;
+pub use pattern::{PathPattern, PatternCapture, PatternCaptures, PatternProvider, PatternSet};
+pub use registry::{HostPattern, HostSettings, ProviderEntry, ProviderRegistry};
+pub use sourcehut::{SourceHutProvider, SourceHutVcs};
+pub use web::{LineRange, ToRawFileUrl, ToWebUrl, WebLink, WebPage, WebTarget};
+
+use crate::{GitUrl, GitUrlParseError};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "url")]
+use url::Url;
 
 /// Secondary parser called by [`crate::GitUrl::provider_info()`] to extract Git host provider info from url
 ///
@@ -54,3 +134,146 @@ pub trait GitProvider<T, E>: Clone + std::fmt::Debug {
     /// Logic for extracting service level information from a `GitUrl`
     fn from_git_url(url: &T) -> Result<Self, E>;
 }
+
+/// Transport of clone urls rendered by [`ToGitUrl`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CloneProtocol {
+    /// `https://host/path`
+    Https,
+    /// `user@host:path`, or `ssh://user@host:port/path` with a port
+    Ssh,
+}
+
+/// Render provider info back into clone urls, the reverse of [`GitProvider`]
+///
+/// ```
+/// use git_url_parse::types::provider::{CloneProtocol, GitLabProvider, ToGitUrl};
+///
+/// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+/// let provider = GitLabProvider::new("group", &["subgroup"], "repo")?;
+///
+/// let url = provider.to_git_url("gitlab.corp.example", None, CloneProtocol::Https)?;
+/// assert_eq!(url.to_string(), "https://gitlab.corp.example/group/subgroup/repo.git");
+///
+/// let url = provider.to_git_url("gitlab.corp.example", None, CloneProtocol::Ssh)?;
+/// assert_eq!(url.to_string(), "git@gitlab.corp.example:group/subgroup/repo.git");
+///
+/// // Ports only apply to the rendered protocol
+/// let url = provider.to_git_url("gitlab.corp.example", Some(2222), CloneProtocol::Ssh)?;
+/// assert_eq!(url.port(), Some(2222));
+///
+/// let url = provider.to_git_url("gitlab.corp.example", Some(8443), CloneProtocol::Https)?;
+/// assert_eq!(url.to_string(), "https://gitlab.corp.example:8443/group/subgroup/repo.git");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// With `feature = url`, [`ToGitUrl::to_url()`] renders a [`url::Url`](https://docs.rs/url/latest/url/struct.Url.html)
+pub trait ToGitUrl {
+    /// Path of the repo on its host for `protocol`, without a leading `/`
+    fn clone_path(&self, protocol: CloneProtocol) -> String;
+
+    /// User of ssh clone urls
+    fn ssh_user(&self) -> Option<&str> {
+        Some("git")
+    }
+
+    /// Port of ssh clone urls, when the host doesn't use port 22
+    fn ssh_port(&self) -> Option<u16> {
+        None
+    }
+
+    /// Host serving clone urls for `protocol`, for a repo on `host`.
+    /// Public instances serving ssh from a separate host map between the two
+    fn clone_host(&self, host: &str, _protocol: CloneProtocol) -> String {
+        host.to_string()
+    }
+
+    /// Clone url of the repo on `host` (i.e. `git.corp.example`), for `protocol`.
+    /// `port` is the port of `protocol` on the host. Ssh urls default to [`ToGitUrl::ssh_port()`]
+    fn to_git_url(
+        &self,
+        host: &str,
+        port: Option<u16>,
+        protocol: CloneProtocol,
+    ) -> Result<GitUrl, GitUrlParseError> {
+        let url = render_clone_url(self, host, port, protocol, true)?;
+
+        GitUrl::parse(&url)
+    }
+
+    /// Clone url of the repo on `host`, as a [`url::Url`](https://docs.rs/url/latest/url/struct.Url.html)
+    #[cfg(feature = "url")]
+    fn to_url(
+        &self,
+        host: &str,
+        port: Option<u16>,
+        protocol: CloneProtocol,
+    ) -> Result<Url, GitUrlParseError> {
+        let url = render_clone_url(self, host, port, protocol, false)?;
+
+        // Validate as a git url before handing off to the url crate
+        GitUrl::parse(&url)?;
+        Ok(Url::parse(&url)?)
+    }
+}
+
+/// Format a clone url. Ssh urls without a port use the scp-like form when `scp_like`
+fn render_clone_url<P: ToGitUrl + ?Sized>(
+    provider: &P,
+    host: &str,
+    port: Option<u16>,
+    protocol: CloneProtocol,
+    scp_like: bool,
+) -> Result<String, GitUrlParseError> {
+    let host = host.trim_end_matches('/');
+
+    // IPv6 literals are bracketed, so any `:` after them is a port
+    let has_port = match host.strip_prefix('[') {
+        Some(literal) => literal
+            .split_once(']')
+            .is_none_or(|(_, rest)| !rest.is_empty()),
+        None => host.contains(':'),
+    };
+    if has_port {
+        return Err(GitUrlParseError::ProviderInvalidName(format!(
+            "Host must not include a port, pass it separately. IPv6 hosts need brackets: '{host}'"
+        )));
+    }
+
+    let path = provider.clone_path(protocol);
+
+    let url = match protocol {
+        CloneProtocol::Https => match port {
+            Some(port) => format!("https://{host}:{port}/{path}"),
+            None => format!("https://{host}/{path}"),
+        },
+        CloneProtocol::Ssh => {
+            let user = provider
+                .ssh_user()
+                .map(|u| format!("{u}@"))
+                .unwrap_or_default();
+
+            match port.or(provider.ssh_port()) {
+                Some(port) => format!("ssh://{user}{host}:{port}/{path}"),
+                None if scp_like => format!("{user}{host}:{path}"),
+                None => format!("ssh://{user}{host}/{path}"),
+            }
+        }
+    };
+
+    Ok(url)
+}
+
+/// Join names into a path for provider constructors, rejecting empty names and names with `/`
+fn join_names(names: &[&str]) -> Result<String, GitUrlParseError> {
+    if let Some(name) = names.iter().find(|n| n.is_empty() || n.contains('/')) {
+        return Err(GitUrlParseError::ProviderInvalidName(format!(
+            "Name must be a single non-empty path segment: '{name}'"
+        )));
+    }
+
+    Ok(names.join("/"))
+}
@@ -0,0 +1,200 @@
+use super::{CloneProtocol, GitProvider, ToGitUrl};
+use crate::types::GitUrlParseHint;
+use crate::{GitUrl, GitUrlParseError};
+
+use getset::Getters;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "url")]
+use url::Url;
+
+/// Leading path segment of forked repos
+const FORKS_SEGMENT: &str = "forks";
+
+/// Path segments that follow the repo name in Pagure web urls
+const WEB_SEGMENTS: &[&str] = &[
+    "blob",
+    "blame",
+    "boards",
+    "branch",
+    "branches",
+    "c",
+    "commit",
+    "commits",
+    "forks",
+    "history",
+    "issue",
+    "issues",
+    "pull-request",
+    "pull-requests",
+    "raw",
+    "releases",
+    "roadmap",
+    "settings",
+    "stats",
+    "tags",
+    "tree",
+];
+
+/// ## Pagure repository provider
+///
+/// Also used by Fedora and CentOS dist-git
+///
+/// ## Supported URL Formats
+///
+/// - `https://pagure.io/repo.git`
+/// - `https://src.fedoraproject.org/rpms/bash.git`
+/// - `ssh://pkgs.fedoraproject.org/rpms/bash`
+/// - `https://src.fedoraproject.org/forks/user/rpms/bash.git` (forks)
+/// - `https://src.fedoraproject.org/rpms/bash/blob/rawhide/f/bash.spec` (web urls)
+///
+/// ## Examples
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::provider::PagureProvider;
+///
+/// fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+///     let url = GitUrl::parse("https://src.fedoraproject.org/forks/jdoe/rpms/bash.git")?;
+///     let provider: PagureProvider = url.provider_info()?;
+///     assert_eq!(provider.namespace(), Some("rpms"));
+///     assert_eq!(provider.repo(), "bash");
+///     assert_eq!(provider.fork_owner(), Some("jdoe"));
+///     assert!(provider.is_fork());
+///     assert_eq!(provider.fullname(), "forks/jdoe/rpms/bash");
+///
+///     let url = GitUrl::parse("ssh://pkgs.fedoraproject.org/rpms/bash")?;
+///     let provider: PagureProvider = url.provider_info()?;
+///     assert_eq!(provider.namespace(), Some("rpms"));
+///     assert!(!provider.is_fork());
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Getters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PagureProvider {
+    /// Namespace of the repo (i.e. `rpms`), if any
+    namespace: Option<String>,
+    /// Repo name
+    #[getset(get = "pub")]
+    repo: String,
+    /// User who owns the fork, for forked repos
+    fork_owner: Option<String>,
+}
+
+impl PagureProvider {
+    /// Namespace of the repo (i.e. `rpms`), if any
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// User who owns the fork, for forked repos
+    pub fn fork_owner(&self) -> Option<&str> {
+        self.fork_owner.as_deref()
+    }
+
+    /// If the repo is a user's fork
+    pub fn is_fork(&self) -> bool {
+        self.fork_owner.is_some()
+    }
+
+    /// Helper method to get the full name of a repo: `[forks/{fork_owner}/][{namespace}/]{repo}`
+    pub fn fullname(&self) -> String {
+        let mut parts = Vec::new();
+
+        if let Some(fork_owner) = &self.fork_owner {
+            parts.extend([FORKS_SEGMENT, fork_owner]);
+        }
+        if let Some(namespace) = &self.namespace {
+            parts.push(namespace);
+        }
+        parts.push(&self.repo);
+
+        parts.join("/")
+    }
+
+    /// Parse the path of url for Pagure patterns
+    fn parse_path(input: &str) -> Result<PagureProvider, GitUrlParseError> {
+        let parts: Vec<&str> = input.split('/').filter(|s| !s.is_empty()).collect();
+
+        let (fork_owner, parts) = match parts.as_slice() {
+            [FORKS_SEGMENT, fork_owner, rest @ ..] if !rest.is_empty() => (Some(*fork_owner), rest),
+            parts => (None, parts),
+        };
+
+        // Ignore web pages after the repo
+        let end = parts
+            .iter()
+            .skip(1)
+            .position(|p| WEB_SEGMENTS.contains(p))
+            .map_or(parts.len(), |i| i + 1);
+
+        let (namespace, repo) = match &parts[..end] {
+            [repo] => (None, *repo),
+            [namespace, repo] => (Some(*namespace), *repo),
+            _ => {
+                return Err(GitUrlParseError::ProviderParseFail(
+                    "Path expected: ex. '/repo', '/namespace/repo' or '/forks/user/namespace/repo'"
+                        .into(),
+                ));
+            }
+        };
+
+        let repo = repo.strip_suffix(".git").unwrap_or(repo);
+
+        for name in [fork_owner, namespace, Some(repo)].into_iter().flatten() {
+            Self::validate_name(name)?;
+        }
+
+        Ok(PagureProvider {
+            namespace: namespace.map(|n| n.to_string()),
+            repo: repo.to_string(),
+            fork_owner: fork_owner.map(|f| f.to_string()),
+        })
+    }
+
+    /// Pagure names: ascii letters, digits, `.`, `_`, `-` or `+` (i.e. `libstdc++`)
+    fn validate_name(name: &str) -> Result<(), GitUrlParseError> {
+        let valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "._-+".contains(c));
+
+        if valid {
+            Ok(())
+        } else {
+            Err(GitUrlParseError::ProviderInvalidName(format!(
+                "Pagure names must be ascii letters, digits, '.', '_', '-' or '+': '{name}'"
+            )))
+        }
+    }
+}
+
+impl GitProvider<GitUrl, GitUrlParseError> for PagureProvider {
+    fn from_git_url(url: &GitUrl) -> Result<Self, GitUrlParseError> {
+        if url.hint() == GitUrlParseHint::Filelike {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Self::parse_path(url.path())
+    }
+}
+
+#[cfg(feature = "url")]
+impl GitProvider<Url, GitUrlParseError> for PagureProvider {
+    fn from_git_url(url: &Url) -> Result<Self, GitUrlParseError> {
+        if url.scheme() == "file" {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Self::parse_path(url.path())
+    }
+}
+
+impl ToGitUrl for PagureProvider {
+    fn clone_path(&self, _protocol: CloneProtocol) -> String {
+        format!("{}.git", self.fullname())
+    }
+}
@@ -0,0 +1,637 @@
+use crate::types::GitUrlParseHint;
+use crate::{GitUrl, GitUrlParseError};
+
+use getset::{Getters, WithSetters};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "url")]
+use url::Url;
+
+/// Value constraint of a single segment capture
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum CaptureType {
+    /// Any non-empty text (`{name}` or `{name:str}`)
+    Text,
+    /// Ascii digits (`{name:int}`)
+    Int,
+    /// Ascii hex digits (`{name:hex}`)
+    Hex,
+}
+
+impl CaptureType {
+    /// Capture type from its name in a pattern
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "str" => Some(CaptureType::Text),
+            "int" => Some(CaptureType::Int),
+            "hex" => Some(CaptureType::Hex),
+            _ => None,
+        }
+    }
+
+    /// Returns true if `value` can be captured by this type
+    fn matches(&self, value: &str) -> bool {
+        match self {
+            CaptureType::Text => !value.is_empty(),
+            CaptureType::Int => !value.is_empty() && value.chars().all(|c| c.is_ascii_digit()),
+            CaptureType::Hex => !value.is_empty() && value.chars().all(|c| c.is_ascii_hexdigit()),
+        }
+    }
+}
+
+/// Literal text around the capture of a pattern segment
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Piece {
+    /// Required text
+    Literal(String),
+    /// Text that may be missing (`[text]`)
+    Optional(String),
+}
+
+/// One `/` separated segment of a [`PathPattern`]
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Segment {
+    /// Literal text with at most one capture (i.e. `_git`, `{repo}[.git]`, `~{owner}`)
+    Single {
+        /// Text before the capture, or the whole segment without capture
+        prefix: Vec<Piece>,
+        /// Name and type of the capture
+        capture: Option<(String, CaptureType)>,
+        /// Text after the capture
+        suffix: Vec<Piece>,
+    },
+    /// Whole segment that may be missing (`[text]`)
+    Optional(String),
+    /// Capture of repeating segments (`{name*}` or `{name+}`)
+    Repeat {
+        /// Name of the capture
+        name: String,
+        /// Least number of segments to capture
+        min: usize,
+    },
+}
+
+impl Segment {
+    /// Parse one segment of a pattern. `names` collects the capture names of the pattern
+    fn parse(input: &str, names: &mut Vec<String>) -> Result<Self, GitUrlParseError> {
+        let invalid = |reason: &str| {
+            GitUrlParseError::InvalidPathPattern(format!("{reason} in segment '{input}'"))
+        };
+
+        let mut prefix = Vec::new();
+        let mut capture = None;
+        let mut suffix = Vec::new();
+        let mut repeat = None;
+
+        let mut rest = input;
+        while !rest.is_empty() {
+            let pieces = if capture.is_some() || repeat.is_some() {
+                &mut suffix
+            } else {
+                &mut prefix
+            };
+
+            if let Some(after_brace) = rest.strip_prefix('{') {
+                let (inner, after) = after_brace
+                    .split_once('}')
+                    .ok_or_else(|| invalid("Unclosed '{'"))?;
+
+                if capture.is_some() || repeat.is_some() {
+                    return Err(invalid("More than one capture"));
+                }
+
+                let (name, kind) = match inner.split_once(':') {
+                    Some((name, kind)) => (
+                        name,
+                        CaptureType::from_name(kind)
+                            .ok_or_else(|| invalid("Unknown capture type"))?,
+                    ),
+                    None => (inner, CaptureType::Text),
+                };
+
+                let (name, min) = match (name.strip_suffix('*'), name.strip_suffix('+')) {
+                    (Some(name), _) => (name, Some(0)),
+                    (_, Some(name)) => (name, Some(1)),
+                    _ => (name, None),
+                };
+
+                let valid_name = name
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                    && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+                if !valid_name {
+                    return Err(invalid("Invalid capture name"));
+                }
+                if names.iter().any(|n| n == name) {
+                    return Err(invalid("Duplicate capture name"));
+                }
+                names.push(name.to_string());
+
+                match min {
+                    Some(_) if kind != CaptureType::Text => {
+                        return Err(invalid("Repeating captures can't be typed"));
+                    }
+                    Some(min) => repeat = Some((name.to_string(), min)),
+                    None => capture = Some((name.to_string(), kind)),
+                }
+
+                rest = after;
+            } else if let Some(after_bracket) = rest.strip_prefix('[') {
+                let (inner, after) = after_bracket
+                    .split_once(']')
+                    .ok_or_else(|| invalid("Unclosed '['"))?;
+
+                if inner.is_empty() || inner.contains(['{', '}', '[']) {
+                    return Err(invalid("Optional literal must be plain text"));
+                }
+
+                pieces.push(Piece::Optional(inner.to_string()));
+                rest = after;
+            } else {
+                let end = rest.find(['{', '}', '[', ']']).unwrap_or(rest.len());
+                if end == 0 {
+                    return Err(invalid("Unexpected closing bracket"));
+                }
+
+                pieces.push(Piece::Literal(rest[..end].to_string()));
+                rest = &rest[end..];
+            }
+        }
+
+        if let Some((name, min)) = repeat {
+            if !prefix.is_empty() || !suffix.is_empty() {
+                return Err(invalid("Repeating capture must be the whole segment"));
+            }
+            return Ok(Segment::Repeat { name, min });
+        }
+
+        if let (None, [Piece::Optional(text)]) = (&capture, prefix.as_slice()) {
+            return Ok(Segment::Optional(text.clone()));
+        }
+
+        Ok(Segment::Single {
+            prefix,
+            capture,
+            suffix,
+        })
+    }
+
+    /// Match `pieces` at the start of `text`, calling `rest` with what follows.
+    /// Backtracks over optional literals, trying them present first
+    fn strip_prefix_pieces<'a>(
+        pieces: &[Piece],
+        text: &'a str,
+        rest: &mut dyn FnMut(&'a str) -> bool,
+    ) -> bool {
+        match pieces.split_first() {
+            None => rest(text),
+            Some((Piece::Literal(literal), pieces)) => text
+                .strip_prefix(literal.as_str())
+                .is_some_and(|text| Self::strip_prefix_pieces(pieces, text, rest)),
+            Some((Piece::Optional(literal), pieces)) => {
+                text.strip_prefix(literal.as_str())
+                    .is_some_and(|text| Self::strip_prefix_pieces(pieces, text, rest))
+                    || Self::strip_prefix_pieces(pieces, text, rest)
+            }
+        }
+    }
+
+    /// Match `pieces` at the end of `text`, calling `rest` with what precedes them.
+    /// Backtracks over optional literals, trying them present first
+    fn strip_suffix_pieces<'a>(
+        pieces: &[Piece],
+        text: &'a str,
+        rest: &mut dyn FnMut(&'a str) -> bool,
+    ) -> bool {
+        match pieces.split_last() {
+            None => rest(text),
+            Some((Piece::Literal(literal), pieces)) => text
+                .strip_suffix(literal.as_str())
+                .is_some_and(|text| Self::strip_suffix_pieces(pieces, text, rest)),
+            Some((Piece::Optional(literal), pieces)) => {
+                text.strip_suffix(literal.as_str())
+                    .is_some_and(|text| Self::strip_suffix_pieces(pieces, text, rest))
+                    || Self::strip_suffix_pieces(pieces, text, rest)
+            }
+        }
+    }
+
+    /// Match a [`Segment::Single`] against one path segment.
+    /// Returns `Some` with the captured value, if any, when the segment matches
+    fn match_single<'a>(
+        prefix: &[Piece],
+        capture: &Option<(String, CaptureType)>,
+        suffix: &[Piece],
+        segment: &'a str,
+    ) -> Option<Option<&'a str>> {
+        let mut value = None;
+
+        let matched = Self::strip_prefix_pieces(prefix, segment, &mut |rest| {
+            Self::strip_suffix_pieces(suffix, rest, &mut |middle| match capture {
+                None => middle.is_empty(),
+                Some((_, kind)) if kind.matches(middle) => {
+                    value = Some(middle);
+                    true
+                }
+                Some(_) => false,
+            })
+        });
+
+        matched.then_some(value)
+    }
+}
+
+/// A path pattern for extracting named parts of url paths
+///
+/// Patterns are `/` separated segments of:
+/// - literal text (i.e. `_git`)
+/// - `{name}` captures of one segment, with an optional type: `{name:str}`, `{name:int}` or `{name:hex}`
+/// - `{name*}` or `{name+}` captures of zero or more, or one or more, segments
+/// - `[text]` optional literal text, as a whole segment or around a capture (i.e. `{repo}[.git]`)
+///
+/// Literal text can surround a capture in the same segment (i.e. `~{owner}`, `{repo}.git`)
+///
+/// ```
+/// use git_url_parse::types::provider::PathPattern;
+///
+/// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+/// let pattern: PathPattern = "/{owner}/{subgroup*}/{repo}[.git]".parse()?;
+///
+/// let captures = pattern.captures("/group/sub/deeper/project.git").expect("Pattern should match");
+/// assert_eq!(captures.get("owner"), Some("group"));
+/// assert_eq!(captures.segments("subgroup"), Some(&["sub".to_string(), "deeper".to_string()][..]));
+/// assert_eq!(captures.get("repo"), Some("project"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
+pub struct PathPattern {
+    /// Pattern as written
+    source: String,
+    /// Parsed segments of the pattern
+    segments: Vec<Segment>,
+}
+
+impl PathPattern {
+    /// Returns the captures of `path` if it matches the whole pattern
+    pub fn captures(&self, path: &str) -> Option<PatternCaptures> {
+        let parts: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let mut captures = Vec::new();
+
+        self.match_segments(0, &parts, &mut captures)
+            .then_some(PatternCaptures { captures })
+    }
+
+    /// Match pattern segments from `index` against `path`, backtracking over optional and repeating segments
+    fn match_segments(
+        &self,
+        index: usize,
+        path: &[&str],
+        captures: &mut Vec<PatternCapture>,
+    ) -> bool {
+        let Some(segment) = self.segments.get(index) else {
+            return path.is_empty();
+        };
+
+        let checkpoint = captures.len();
+
+        match segment {
+            Segment::Optional(text) => {
+                if path.first() == Some(&text.as_str())
+                    && self.match_segments(index + 1, &path[1..], captures)
+                {
+                    return true;
+                }
+                captures.truncate(checkpoint);
+
+                self.match_segments(index + 1, path, captures)
+            }
+            Segment::Repeat { name, min } => {
+                // Greedy, so later captures get the fewest segments
+                for take in (*min..=path.len()).rev() {
+                    captures.push(PatternCapture {
+                        name: name.clone(),
+                        segments: path[..take].iter().map(|s| s.to_string()).collect(),
+                        repeat: true,
+                    });
+
+                    if self.match_segments(index + 1, &path[take..], captures) {
+                        return true;
+                    }
+                    captures.truncate(checkpoint);
+                }
+
+                false
+            }
+            Segment::Single {
+                prefix,
+                capture,
+                suffix,
+            } => {
+                let Some(first) = path.first() else {
+                    return false;
+                };
+
+                let Some(value) = Segment::match_single(prefix, capture, suffix, first) else {
+                    return false;
+                };
+
+                if let (Some((name, _)), Some(value)) = (capture, value) {
+                    captures.push(PatternCapture {
+                        name: name.clone(),
+                        segments: vec![value.to_string()],
+                        repeat: false,
+                    });
+                }
+
+                if self.match_segments(index + 1, &path[1..], captures) {
+                    return true;
+                }
+                captures.truncate(checkpoint);
+
+                false
+            }
+        }
+    }
+}
+
+impl FromStr for PathPattern {
+    type Err = GitUrlParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let source = s.trim();
+        let mut names = Vec::new();
+
+        let segments = source
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .map(|segment| Segment::parse(segment, &mut names))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if segments.is_empty() {
+            return Err(GitUrlParseError::InvalidPathPattern(format!(
+                "Pattern has no segments: '{source}'"
+            )));
+        }
+
+        Ok(PathPattern {
+            source: source.to_string(),
+            segments,
+        })
+    }
+}
+
+impl TryFrom<String> for PathPattern {
+    type Error = GitUrlParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<PathPattern> for String {
+    fn from(value: PathPattern) -> Self {
+        value.source
+    }
+}
+
+impl fmt::Display for PathPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+/// A named value captured by a [`PathPattern`]
+#[derive(Debug, PartialEq, Eq, Clone, Getters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PatternCapture {
+    /// Name of the capture in the pattern
+    #[getset(get = "pub")]
+    name: String,
+    /// Captured path segments. Single captures have one segment
+    #[getset(get = "pub")]
+    segments: Vec<String>,
+    /// If captured by a repeating `{name*}` or `{name+}`
+    repeat: bool,
+}
+
+impl PatternCapture {
+    /// Captured segments joined with `/`
+    pub fn value(&self) -> String {
+        self.segments.join("/")
+    }
+}
+
+/// Values captured from a url path by a [`PathPattern`], in pattern order
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PatternCaptures {
+    /// Captured values
+    captures: Vec<PatternCapture>,
+}
+
+impl PatternCaptures {
+    /// All captured values, in pattern order. Optional segments that were missing aren't included
+    pub fn captures(&self) -> &[PatternCapture] {
+        &self.captures
+    }
+
+    /// Value of the single segment capture `name`
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.find(name)
+            .filter(|c| !c.repeat)
+            .and_then(|c| c.segments.first())
+            .map(|s| s.as_str())
+    }
+
+    /// Segments captured as `name`, for repeating or single captures
+    pub fn segments(&self, name: &str) -> Option<&[String]> {
+        self.find(name).map(|c| c.segments.as_slice())
+    }
+
+    /// Parse the value of capture `name`, joined with `/` for repeating captures
+    ///
+    /// ```
+    /// use git_url_parse::types::provider::PathPattern;
+    ///
+    /// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+    /// let pattern: PathPattern = "/c/{project+}/+/{change:int}".parse()?;
+    /// let captures = pattern.captures("/c/platform/build/+/12345").expect("Pattern should match");
+    ///
+    /// assert_eq!(captures.parse::<u64>("change")?, 12345);
+    /// assert_eq!(captures.parse::<String>("project")?, "platform/build");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse<T: FromStr>(&self, name: &str) -> Result<T, GitUrlParseError> {
+        let capture = self.find(name).ok_or_else(|| {
+            GitUrlParseError::ProviderParseFail(format!("No capture named '{name}'"))
+        })?;
+
+        capture.value().parse().map_err(|_| {
+            GitUrlParseError::ProviderParseFail(format!(
+                "Capture '{name}' has an unexpected value: '{}'",
+                capture.value()
+            ))
+        })
+    }
+
+    /// Helper method to get the full name of a repo: all non-empty captured values joined with `/`
+    pub fn fullname(&self) -> String {
+        self.captures
+            .iter()
+            .map(|c| c.value())
+            .filter(|v| !v.is_empty())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// First capture named `name`
+    fn find(&self, name: &str) -> Option<&PatternCapture> {
+        self.captures.iter().find(|c| c.name == name)
+    }
+}
+
+/// Path patterns of a provider, one per transport
+///
+/// Can be registered for hosts with [`ProviderRegistry::register_patterns()`](super::ProviderRegistry::register_patterns),
+/// or used for a custom provider with [`PatternProvider`]
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::provider::PatternSet;
+///
+/// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+/// let patterns = PatternSet::new("/{org}/{project}/_git/{repo}".parse()?)
+///     .with_ssh("/v3/{org}/{project}/{repo}".parse()?);
+///
+/// let url = GitUrl::parse("https://forge.corp.example/acme/tools/_git/cli")?;
+/// let captures = patterns.parse(&url)?;
+/// assert_eq!(captures.get("repo"), Some("cli"));
+///
+/// let url = GitUrl::parse("git@forge.corp.example:v3/acme/tools/cli")?;
+/// let captures = patterns.parse(&url)?;
+/// assert_eq!(captures.fullname(), "acme/tools/cli");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Getters, WithSetters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[getset(get = "pub", set_with = "pub")]
+pub struct PatternSet {
+    /// Pattern for ssh urls
+    ssh: PathPattern,
+    /// Pattern for http-like urls (i.e. `https://`, `git://`)
+    https: PathPattern,
+}
+
+impl PatternSet {
+    /// Patterns with the same `pattern` for every transport
+    pub fn new(pattern: PathPattern) -> Self {
+        PatternSet {
+            ssh: pattern.clone(),
+            https: pattern,
+        }
+    }
+
+    /// Returns the captures of the pattern for the transport of `url`
+    pub fn parse(&self, url: &GitUrl) -> Result<PatternCaptures, GitUrlParseError> {
+        match url.hint() {
+            GitUrlParseHint::Filelike => Err(GitUrlParseError::ProviderUnsupported),
+            GitUrlParseHint::Sshlike => Self::captures(&self.ssh, url.path()),
+            _ => Self::captures(&self.https, url.path()),
+        }
+    }
+
+    /// Returns the captures of the pattern for the scheme of `url`
+    #[cfg(feature = "url")]
+    pub fn parse_url(&self, url: &Url) -> Result<PatternCaptures, GitUrlParseError> {
+        match url.scheme() {
+            "file" => Err(GitUrlParseError::ProviderUnsupported),
+            scheme if scheme.contains("ssh") => Self::captures(&self.ssh, url.path()),
+            _ => Self::captures(&self.https, url.path()),
+        }
+    }
+
+    /// Captures of `pattern` in `path`, or an error if it doesn't match
+    fn captures(pattern: &PathPattern, path: &str) -> Result<PatternCaptures, GitUrlParseError> {
+        pattern.captures(path).ok_or_else(|| {
+            GitUrlParseError::ProviderParseFail(format!(
+                "Path '{path}' doesn't match pattern '{pattern}'"
+            ))
+        })
+    }
+}
+
+/// A provider defined by path patterns, instead of a hand-written parser
+///
+/// Providers opt in to [`GitProvider`](super::GitProvider) by calling [`PatternProvider::from_pattern_git_url()`],
+/// and [`PatternProvider::from_pattern_url()`] with `feature = url`
+///
+/// ```
+/// use std::sync::LazyLock;
+/// use git_url_parse::{GitUrl, GitUrlParseError};
+/// use git_url_parse::types::provider::{GitProvider, PatternCaptures, PatternProvider, PatternSet};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq)]
+/// struct ForgeProvider {
+///     project: String,
+///     repo: String,
+/// }
+///
+/// static FORGE_PATTERNS: LazyLock<PatternSet> = LazyLock::new(|| {
+///     PatternSet::new("/scm/{project}/{repo}[.git]".parse().expect("Pattern is valid"))
+///         .with_ssh("/{project}/{repo}[.git]".parse().expect("Pattern is valid"))
+/// });
+///
+/// impl PatternProvider for ForgeProvider {
+///     fn patterns() -> &'static PatternSet {
+///         &FORGE_PATTERNS
+///     }
+///
+///     fn from_captures(captures: &PatternCaptures) -> Result<Self, GitUrlParseError> {
+///         Ok(ForgeProvider {
+///             project: captures.parse("project")?,
+///             repo: captures.parse("repo")?,
+///         })
+///     }
+/// }
+///
+/// impl GitProvider<GitUrl, GitUrlParseError> for ForgeProvider {
+///     fn from_git_url(url: &GitUrl) -> Result<Self, GitUrlParseError> {
+///         Self::from_pattern_git_url(url)
+///     }
+/// }
+///
+/// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+/// let url = GitUrl::parse("https://forge.corp.example/scm/tools/cli.git")?;
+/// let provider: ForgeProvider = url.provider_info()?;
+/// assert_eq!(provider.project, "tools");
+/// assert_eq!(provider.repo, "cli");
+/// # Ok(())
+/// # }
+/// ```
+pub trait PatternProvider: Clone + std::fmt::Debug {
+    /// Path patterns of the provider
+    fn patterns() -> &'static PatternSet;
+
+    /// Build the provider info from the captures of a matching url
+    fn from_captures(captures: &PatternCaptures) -> Result<Self, GitUrlParseError>;
+
+    /// Parse provider info from the path of `url`, for implementing [`GitProvider`](super::GitProvider)
+    fn from_pattern_git_url(url: &GitUrl) -> Result<Self, GitUrlParseError> {
+        Self::from_captures(&Self::patterns().parse(url)?)
+    }
+
+    /// Parse provider info from the path of a [`url::Url`](https://docs.rs/url/latest/url/struct.Url.html),
+    /// for implementing [`GitProvider`](super::GitProvider)
+    #[cfg(feature = "url")]
+    fn from_pattern_url(url: &Url) -> Result<Self, GitUrlParseError> {
+        Self::from_captures(&Self::patterns().parse_url(url)?)
+    }
+}
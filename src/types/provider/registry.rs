@@ -0,0 +1,591 @@
+use super::{AnyProvider, CloneProtocol, PatternSet, ProviderKind, detect};
+use crate::types::GitUrlParseHint;
+use crate::{GitUrl, GitUrlParseError};
+
+use getset::{CopyGetters, Getters, WithSetters};
+#[cfg(feature = "log")]
+use log::debug;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+/// Registry of the public instances of the built-in providers
+static BUILTIN: LazyLock<ProviderRegistry> = LazyLock::new(|| {
+    let mut registry = ProviderRegistry::new();
+
+    for (pattern, kind) in [
+        ("github.com", ProviderKind::GitHub),
+        ("www.github.com", ProviderKind::GitHub),
+        ("ssh.github.com", ProviderKind::GitHub),
+        ("gist.github.com", ProviderKind::GitHub),
+        ("api.github.com", ProviderKind::GitHub),
+        ("raw.githubusercontent.com", ProviderKind::GitHub),
+        ("bitbucket.org", ProviderKind::BitbucketCloud),
+        ("codeberg.org", ProviderKind::Gitea),
+        ("gitlab.com", ProviderKind::GitLab),
+        ("git.sr.ht", ProviderKind::SourceHut),
+        ("hg.sr.ht", ProviderKind::SourceHut),
+        ("dev.azure.com", ProviderKind::AzureDevOps),
+        ("ssh.dev.azure.com", ProviderKind::AzureDevOps),
+        ("*.visualstudio.com", ProviderKind::AzureDevOps),
+        ("git-codecommit.*.amazonaws.com", ProviderKind::CodeCommit),
+        (
+            "git-codecommit-fips.*.amazonaws.com",
+            ProviderKind::CodeCommit,
+        ),
+        (
+            "git-codecommit.*.amazonaws.com.cn",
+            ProviderKind::CodeCommit,
+        ),
+        ("codecommit", ProviderKind::CodeCommit),
+        ("*.googlesource.com", ProviderKind::Gerrit),
+        ("review.opendev.org", ProviderKind::Gerrit),
+        ("huggingface.co", ProviderKind::HuggingFace),
+        ("hf.co", ProviderKind::HuggingFace),
+        ("git.launchpad.net", ProviderKind::Launchpad),
+        ("code.launchpad.net", ProviderKind::Launchpad),
+        ("lp", ProviderKind::Launchpad),
+        ("git.kernel.org", ProviderKind::Cgit),
+        ("git.savannah.gnu.org", ProviderKind::Cgit),
+        ("git.savannah.nongnu.org", ProviderKind::Cgit),
+        ("pagure.io", ProviderKind::Pagure),
+        ("src.fedoraproject.org", ProviderKind::Pagure),
+        ("pkgs.fedoraproject.org", ProviderKind::Pagure),
+        ("source.developers.google.com", ProviderKind::GoogleSource),
+        ("source.cloud.google.com", ProviderKind::GoogleSource),
+        ("*.sourcemanager.dev", ProviderKind::GoogleSource),
+    ] {
+        registry
+            .register(pattern, kind)
+            .expect("Built-in host pattern is valid");
+    }
+
+    registry
+});
+
+/// Host matching rule for a [`ProviderRegistry`] entry
+///
+/// Parsed from strings of the form:
+/// - `git.corp.example` - exact host, any port
+/// - `*.corp.example` - any subdomain of `corp.example`, any port
+/// - `git.*.corp.example` - `*` labels match any single label (i.e. `git.eu.corp.example`)
+/// - `git.corp.example:7999` - exact host, only on port `7999`
+/// - `[::1]:8080` - bracketed IPv6 host, as in urls
+#[derive(Debug, PartialEq, Eq, Clone, Getters, CopyGetters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
+pub struct HostPattern {
+    /// Lowercase host name, without the `*.` wildcard prefix. May contain `*` labels
+    #[getset(get = "pub")]
+    host: String,
+    /// If subdomains of `host` are matched instead of `host`
+    #[getset(get_copy = "pub")]
+    wildcard: bool,
+    /// Port required to match, if specified
+    #[getset(get_copy = "pub")]
+    port: Option<u16>,
+}
+
+impl HostPattern {
+    /// Returns true if `host` and `port` match this pattern
+    pub fn matches(&self, host: &str, port: Option<u16>) -> bool {
+        if self.port.is_some() && self.port != port {
+            return false;
+        }
+
+        let host = host.to_ascii_lowercase();
+
+        if self.wildcard {
+            // At least one subdomain label before the matched labels
+            host.char_indices()
+                .filter(|(_, c)| *c == '.')
+                .any(|(i, _)| i > 0 && labels_match(&self.host, &host[i + 1..]))
+        } else {
+            labels_match(&self.host, &host)
+        }
+    }
+
+    /// Ordering key for picking the most specific match: port, then exact host, then longest host
+    fn specificity(&self) -> (bool, bool, usize) {
+        let exact = !self.wildcard && !self.host.split('.').any(|label| label == "*");
+        (self.port.is_some(), exact, self.host.len())
+    }
+}
+
+/// Returns true if `host` has the labels of `pattern`, where `*` labels match any single label
+fn labels_match(pattern: &str, host: &str) -> bool {
+    let mut labels = host.split('.');
+
+    pattern
+        .split('.')
+        .all(|p| labels.next().is_some_and(|label| p == "*" || p == label))
+        && labels.next().is_none()
+}
+
+impl FromStr for HostPattern {
+    type Err = GitUrlParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || GitUrlParseError::InvalidHostPattern(s.to_string());
+
+        let s = s.trim();
+        let parse_port = |port: &str| port.parse::<u16>().map_err(|_| invalid());
+
+        // IPv6 hosts are bracketed, so the port is split off after the closing bracket
+        if s.starts_with('[') {
+            let (host, port) = s.split_once(']').ok_or_else(invalid)?;
+            let port = match port {
+                "" => None,
+                port => Some(parse_port(port.strip_prefix(':').ok_or_else(invalid)?)?),
+            };
+
+            let address = &host[1..];
+            let valid_address = address.contains(':')
+                && address
+                    .chars()
+                    .all(|c| c.is_ascii_hexdigit() || c == ':' || c == '.');
+
+            if !valid_address {
+                return Err(invalid());
+            }
+
+            return Ok(HostPattern {
+                host: format!("{}]", host.to_ascii_lowercase()),
+                wildcard: false,
+                port,
+            });
+        }
+
+        let (host, port) = match s.rsplit_once(':') {
+            Some((host, port)) => (host, Some(parse_port(port)?)),
+            None => (s, None),
+        };
+
+        let (host, wildcard) = match host.strip_prefix("*.") {
+            Some(host) => (host, true),
+            None => (host, false),
+        };
+
+        let valid_host = host.split('.').all(|label| {
+            label == "*"
+                || (!label.is_empty()
+                    && label
+                        .chars()
+                        .all(|c| c.is_alphanumeric() || c == '-' || c == '_'))
+        }) && host.split('.').any(|label| label != "*");
+
+        if !valid_host {
+            return Err(invalid());
+        }
+
+        Ok(HostPattern {
+            host: host.to_ascii_lowercase(),
+            wildcard,
+            port,
+        })
+    }
+}
+
+impl TryFrom<String> for HostPattern {
+    type Error = GitUrlParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<HostPattern> for String {
+    fn from(value: HostPattern) -> Self {
+        value.to_string()
+    }
+}
+
+impl fmt::Display for HostPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let wildcard = if self.wildcard { "*." } else { "" };
+
+        match self.port {
+            Some(port) => write!(f, "{wildcard}{}:{port}", self.host),
+            None => write!(f, "{wildcard}{}", self.host),
+        }
+    }
+}
+
+/// Per-host settings used when converting urls between ssh and https
+#[derive(Debug, Default, PartialEq, Eq, Clone, CopyGetters, WithSetters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[getset(set_with = "pub")]
+pub struct HostSettings {
+    /// User for ssh urls (i.e. `git`)
+    ssh_user: Option<String>,
+    /// Port for ssh urls, if not the default
+    #[getset(get_copy = "pub")]
+    ssh_port: Option<u16>,
+    /// Path prefix of https urls, for instances not served from the root (i.e. `gitlab` in `https://host/gitlab/group/repo.git`)
+    https_base_path: Option<String>,
+}
+
+impl HostSettings {
+    /// User for ssh urls (i.e. `git`)
+    pub fn ssh_user(&self) -> Option<&str> {
+        self.ssh_user.as_deref()
+    }
+
+    /// Path prefix of https urls, without leading or trailing `/`
+    pub fn https_base_path(&self) -> Option<&str> {
+        self.https_base_path
+            .as_deref()
+            .map(|p| p.trim_matches('/'))
+            .filter(|p| !p.is_empty())
+    }
+}
+
+/// A host pattern, mapped to a provider kind and host settings, and path patterns for [`ProviderKind::Pattern`]
+#[derive(Debug, PartialEq, Eq, Clone, Getters, CopyGetters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ProviderEntry {
+    /// Hosts matched by this entry
+    #[getset(get = "pub")]
+    host: HostPattern,
+    /// Provider of the matched hosts
+    #[getset(get_copy = "pub")]
+    provider: ProviderKind,
+    /// Settings for the matched hosts
+    #[getset(get = "pub")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    settings: HostSettings,
+    /// Path patterns of the matched hosts, for [`ProviderKind::Pattern`]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    patterns: Option<PatternSet>,
+}
+
+impl ProviderEntry {
+    /// Path patterns of the matched hosts, for [`ProviderKind::Pattern`]
+    pub fn patterns(&self) -> Option<&PatternSet> {
+        self.patterns.as_ref()
+    }
+
+    /// Extract provider info from `url` with the provider of this entry.
+    /// [`ProviderKind::Pattern`] entries are parsed with their path patterns
+    pub fn parse(&self, url: &GitUrl) -> Result<AnyProvider, GitUrlParseError> {
+        match (self.provider, &self.patterns) {
+            (ProviderKind::Pattern, Some(patterns)) => {
+                patterns.parse(url).map(AnyProvider::Pattern)
+            }
+            (kind, _) => kind.parse(url),
+        }
+    }
+}
+
+/// Maps hosts to built-in providers, for self-hosted instances
+///
+/// Used by [`GitUrl::detect_provider_with()`](crate::GitUrl::detect_provider_with)
+/// and for converting urls between ssh and https.
+/// With `feature = serde`, the registry can be loaded from a config file
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::provider::{Confidence, HostSettings, ProviderKind, ProviderRegistry};
+///
+/// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+/// let mut registry = ProviderRegistry::new();
+/// registry.register("git.corp.example", ProviderKind::GitLab)?;
+/// registry.register_with_settings(
+///     "tfs.corp.example",
+///     ProviderKind::AzureDevOps,
+///     HostSettings::default().with_ssh_port(Some(22)),
+/// )?;
+///
+/// let url = GitUrl::parse("https://git.corp.example/group/repo.git")?;
+/// let detected = url.detect_provider_with(&registry)?;
+/// assert_eq!(detected.kind(), ProviderKind::GitLab);
+/// assert_eq!(detected.confidence(), Confidence::High);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ProviderRegistry {
+    /// Registered hosts
+    #[cfg_attr(feature = "serde", serde(default))]
+    hosts: Vec<ProviderEntry>,
+}
+
+impl ProviderRegistry {
+    /// Returns an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a registry of the public instances of the built-in providers (i.e. `gitlab.com`, `dev.azure.com`)
+    pub fn builtin() -> Self {
+        BUILTIN.clone()
+    }
+
+    /// Shared instance of [`ProviderRegistry::builtin()`]
+    pub(crate) fn builtin_ref() -> &'static Self {
+        &BUILTIN
+    }
+
+    /// Registered hosts, in the order they were registered
+    pub fn entries(&self) -> &[ProviderEntry] {
+        &self.hosts
+    }
+
+    /// Map hosts matching `pattern` to the `kind` provider
+    pub fn register(
+        &mut self,
+        pattern: &str,
+        kind: ProviderKind,
+    ) -> Result<&mut Self, GitUrlParseError> {
+        self.register_with_settings(pattern, kind, HostSettings::default())
+    }
+
+    /// Map hosts matching `pattern` to the `kind` provider, with host settings.
+    /// [`ProviderKind::Pattern`] returns [`GitUrlParseError::ProviderUnsupported`], see [`ProviderRegistry::register_patterns()`]
+    pub fn register_with_settings(
+        &mut self,
+        pattern: &str,
+        kind: ProviderKind,
+        settings: HostSettings,
+    ) -> Result<&mut Self, GitUrlParseError> {
+        if kind == ProviderKind::Pattern {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        let host = pattern.parse()?;
+
+        self.hosts.push(ProviderEntry {
+            host,
+            provider: kind,
+            settings,
+            patterns: None,
+        });
+
+        Ok(self)
+    }
+
+    /// Map hosts matching `pattern` to a provider parsed with path `patterns`
+    ///
+    /// ```
+    /// use git_url_parse::GitUrl;
+    /// use git_url_parse::types::provider::{AnyProvider, PatternSet, ProviderKind, ProviderRegistry};
+    ///
+    /// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+    /// let mut registry = ProviderRegistry::new();
+    /// registry.register_patterns(
+    ///     "forge.corp.example",
+    ///     PatternSet::new("/scm/{project}/{repo}[.git]".parse()?),
+    /// )?;
+    ///
+    /// let url = GitUrl::parse("https://forge.corp.example/scm/tools/cli.git")?;
+    /// let detected = url.detect_provider_with(&registry)?;
+    /// assert_eq!(detected.kind(), ProviderKind::Pattern);
+    ///
+    /// let AnyProvider::Pattern(captures) = detected.provider() else {
+    ///     panic!("Pattern provider expected");
+    /// };
+    /// assert_eq!(captures.get("project"), Some("tools"));
+    /// assert_eq!(captures.get("repo"), Some("cli"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn register_patterns(
+        &mut self,
+        pattern: &str,
+        patterns: PatternSet,
+    ) -> Result<&mut Self, GitUrlParseError> {
+        let host = pattern.parse()?;
+
+        self.hosts.push(ProviderEntry {
+            host,
+            provider: ProviderKind::Pattern,
+            settings: HostSettings::default(),
+            patterns: Some(patterns),
+        });
+
+        Ok(self)
+    }
+
+    /// Returns the most specific entry matching `host` and `port`
+    ///
+    /// Entries with a port are preferred, then exact hosts, then the longest wildcard.
+    /// Between equally specific entries, the last registered wins.
+    pub fn lookup(&self, host: &str, port: Option<u16>) -> Option<&ProviderEntry> {
+        self.hosts
+            .iter()
+            .filter(|entry| entry.host.matches(host, port))
+            .max_by_key(|entry| entry.host.specificity())
+    }
+
+    /// Returns the most specific entry matching the host and port of `url`
+    pub fn lookup_url(&self, url: &GitUrl) -> Option<&ProviderEntry> {
+        let host = url.host()?;
+        self.lookup(host, url.port())
+    }
+
+    /// Returns `url` converted to an ssh url, using the host settings of `url`'s host
+    ///
+    /// The repo is parsed by the provider mapped to the host, or the detected provider of unmapped hosts,
+    /// and rendered with [`ToGitUrl`](super::ToGitUrl). Hosts mapped to path patterns return
+    /// [`GitUrlParseError::ProviderUnsupported`]
+    ///
+    /// ```
+    /// use git_url_parse::GitUrl;
+    /// use git_url_parse::types::provider::{HostSettings, ProviderKind, ProviderRegistry};
+    ///
+    /// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+    /// let mut registry = ProviderRegistry::new();
+    /// registry.register_with_settings(
+    ///     "git.corp.example",
+    ///     ProviderKind::GitLab,
+    ///     HostSettings::default()
+    ///         .with_ssh_port(Some(2222))
+    ///         .with_https_base_path(Some("gitlab".to_string())),
+    /// )?;
+    ///
+    /// let url = GitUrl::parse("https://git.corp.example/gitlab/group/repo.git")?;
+    /// let ssh_url = registry.to_ssh(&url)?;
+    /// assert_eq!(ssh_url.to_string(), "ssh://git@git.corp.example:2222/group/repo.git");
+    ///
+    /// let https_url = registry.to_https(&ssh_url)?;
+    /// assert_eq!(https_url, url);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_ssh(&self, url: &GitUrl) -> Result<GitUrl, GitUrlParseError> {
+        let host = url.host().ok_or(GitUrlParseError::ProviderUnsupported)?;
+        let (provider, settings) = self.clone_provider(url)?;
+        let provider = provider
+            .as_to_git_url()
+            .ok_or(GitUrlParseError::ProviderUnsupported)?;
+
+        let ssh_port = settings.ssh_port().or(provider.ssh_port());
+        let ssh_user = settings
+            .ssh_user()
+            .or(if url.hint() == GitUrlParseHint::Sshlike {
+                url.user()
+            } else {
+                None
+            })
+            .or(provider.ssh_user());
+
+        let mut ssh_url = GitUrl::default();
+        ssh_url.set_scheme(Some("ssh".to_string()));
+        ssh_url.set_user(ssh_user.map(|u| u.to_string()));
+        ssh_url.set_host(Some(provider.clone_host(host, CloneProtocol::Ssh)));
+        ssh_url.set_port(ssh_port);
+        ssh_url.set_path(provider.clone_path(CloneProtocol::Ssh));
+        // scp-like urls can't specify a port
+        ssh_url.set_print_scheme(ssh_port.is_some());
+        ssh_url.set_hint(GitUrlParseHint::Sshlike);
+
+        #[cfg(feature = "log")]
+        debug!("{ssh_url:?}");
+
+        ssh_url.is_valid()?;
+
+        Ok(ssh_url)
+    }
+
+    /// Returns `url` converted to an https url, using the host settings of `url`'s host.
+    /// See [`ProviderRegistry::to_ssh()`]
+    pub fn to_https(&self, url: &GitUrl) -> Result<GitUrl, GitUrlParseError> {
+        let host = url.host().ok_or(GitUrlParseError::ProviderUnsupported)?;
+        let (provider, settings) = self.clone_provider(url)?;
+        let provider = provider
+            .as_to_git_url()
+            .ok_or(GitUrlParseError::ProviderUnsupported)?;
+
+        let repo_path = provider.clone_path(CloneProtocol::Https);
+        let path = match settings.https_base_path() {
+            Some(base_path) => format!("/{base_path}/{repo_path}"),
+            None => format!("/{repo_path}"),
+        };
+
+        // Ports are kept between http-like urls, but ssh ports don't apply to https
+        let port = if url.hint() == GitUrlParseHint::Httplike {
+            url.port()
+        } else {
+            None
+        };
+
+        let mut https_url = GitUrl::default();
+        https_url.set_scheme(Some("https".to_string()));
+        https_url.set_host(Some(provider.clone_host(host, CloneProtocol::Https)));
+        https_url.set_port(port);
+        https_url.set_path(path);
+        https_url.set_print_scheme(true);
+        https_url.set_hint(GitUrlParseHint::Httplike);
+
+        #[cfg(feature = "log")]
+        debug!("{https_url:?}");
+
+        https_url.is_valid()?;
+
+        Ok(https_url)
+    }
+
+    /// Entry mapping the host of `url`, from this registry or the built-in registry
+    fn entry_for(&self, url: &GitUrl) -> Option<&ProviderEntry> {
+        self.lookup_url(url)
+            .or_else(|| Self::builtin_ref().lookup_url(url))
+    }
+
+    /// Host settings for `url`, from this registry or the built-in registry
+    fn settings_for(&self, url: &GitUrl) -> HostSettings {
+        self.entry_for(url)
+            .map(|entry| entry.settings.clone())
+            .unwrap_or_default()
+    }
+
+    /// Provider info of `url` without the https base path, and the settings of its host.
+    /// Parsed by the provider mapped to the host, or the detected provider of unmapped hosts
+    fn clone_provider(
+        &self,
+        url: &GitUrl,
+    ) -> Result<(AnyProvider, HostSettings), GitUrlParseError> {
+        let mut repo_url = url.clone();
+        repo_url.set_path(format!("/{}", self.repo_path(url)?));
+
+        let entry = self.entry_for(url);
+        let provider = match entry {
+            Some(entry) => entry.parse(&repo_url)?,
+            None => detect::detect(&repo_url, self)?.into_provider(),
+        };
+
+        #[cfg(feature = "log")]
+        debug!("{provider:?}");
+
+        Ok((provider, self.settings_for(url)))
+    }
+
+    /// The repo path of `url` without a leading `/` or the https base path
+    fn repo_path(&self, url: &GitUrl) -> Result<String, GitUrlParseError> {
+        if url.hint() == GitUrlParseHint::Filelike {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        let path = url.path().trim_start_matches('/');
+
+        let path = match (url.hint(), self.settings_for(url).https_base_path()) {
+            (GitUrlParseHint::Httplike, Some(base_path)) => path
+                .strip_prefix(base_path)
+                .and_then(|p| p.strip_prefix('/'))
+                .unwrap_or(path),
+            _ => path,
+        };
+
+        if path.is_empty() {
+            return Err(GitUrlParseError::InvalidPathEmpty);
+        }
+
+        Ok(path.to_string())
+    }
+}
@@ -0,0 +1,220 @@
+use super::web::{encode_component, encode_path};
+use super::{CloneProtocol, GitProvider, ToGitUrl};
+use crate::types::GitUrlParseHint;
+use crate::{GitUrl, GitUrlParseError};
+
+use getset::{CopyGetters, Getters};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "url")]
+use url::Url;
+
+/// SourceHut services that don't host repositories
+const NON_VCS_SERVICES: &[&str] = &[
+    "todo", "lists", "builds", "man", "paste", "pages", "meta", "chat",
+];
+
+/// The version control system of a SourceHut repo
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SourceHutVcs {
+    /// Git repo, hosted by `git.sr.ht`
+    #[default]
+    Git,
+    /// Mercurial repo, hosted by `hg.sr.ht`
+    Hg,
+}
+
+/// ## SourceHut repository provider
+///
+/// ## Supported URL Formats
+///
+/// - `https://git.sr.ht/~owner/repo`
+/// - `git@git.sr.ht:~owner/repo`
+/// - `https://hg.sr.ht/~owner/repo`
+/// - `https://git.sr.ht/~owner/repo/tree/<ref>/item/<path>`
+///
+/// Self-hosted instances are supported, using the `git.` or `hg.` host prefix for the VCS kind.
+/// Urls of the other SourceHut services (i.e. `todo.sr.ht`) are rejected
+///
+/// ## Examples
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::provider::{SourceHutProvider, SourceHutVcs};
+///
+/// fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+///     let url = GitUrl::parse("git@git.sr.ht:~sircmpwn/scdoc")?;
+///     let provider: SourceHutProvider = url.provider_info()?;
+///     assert_eq!(provider.owner(), "~sircmpwn");
+///     assert_eq!(provider.username(), "sircmpwn");
+///     assert_eq!(provider.repo(), "scdoc");
+///     assert_eq!(provider.vcs(), SourceHutVcs::Git);
+///     assert_eq!(provider.fullname(), "~sircmpwn/scdoc");
+///
+///     assert_eq!(provider.browse_url(), "https://git.sr.ht/~sircmpwn/scdoc");
+///     assert_eq!(
+///         provider.tree_url("master", Some("scdoc.5.scd")),
+///         "https://git.sr.ht/~sircmpwn/scdoc/tree/master/item/scdoc.5.scd"
+///     );
+///     assert_eq!(provider.log_url("master"), "https://git.sr.ht/~sircmpwn/scdoc/log/master");
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Getters, CopyGetters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SourceHutProvider {
+    /// Lowercase host of the VCS service (i.e. `git.sr.ht`)
+    #[getset(get = "pub")]
+    host: String,
+    /// Canonical repo owner, with the `~` prefix
+    #[getset(get = "pub")]
+    owner: String,
+    /// Repo name
+    #[getset(get = "pub")]
+    repo: String,
+    /// Version control system of the repo
+    #[getset(get_copy = "pub")]
+    vcs: SourceHutVcs,
+}
+
+impl SourceHutProvider {
+    /// Repo owner, without the `~` prefix
+    pub fn username(&self) -> &str {
+        self.owner.trim_start_matches('~')
+    }
+
+    /// Helper method to get the full name of a repo: `~{owner}/{repo}`
+    pub fn fullname(&self) -> String {
+        format!("{}/{}", self.owner, self.repo)
+    }
+
+    /// Url of the repo summary page
+    pub fn browse_url(&self) -> String {
+        format!("https://{}/{}", self.host, self.fullname())
+    }
+
+    /// Url of the file tree at `git_ref`, optionally at `path` within the repo
+    pub fn tree_url(&self, git_ref: &str, path: Option<&str>) -> String {
+        let path = path.map(|p| p.trim_matches('/')).filter(|p| !p.is_empty());
+
+        match (self.vcs, path) {
+            (SourceHutVcs::Git, Some(path)) => format!(
+                "{}/tree/{}/item/{}",
+                self.browse_url(),
+                encode_path(git_ref),
+                encode_path(path)
+            ),
+            (SourceHutVcs::Git, None) => {
+                format!("{}/tree/{}", self.browse_url(), encode_path(git_ref))
+            }
+            (SourceHutVcs::Hg, Some(path)) => format!(
+                "{}/browse/{}?rev={}",
+                self.browse_url(),
+                encode_path(path),
+                encode_component(git_ref)
+            ),
+            (SourceHutVcs::Hg, None) => {
+                format!(
+                    "{}/browse?rev={}",
+                    self.browse_url(),
+                    encode_component(git_ref)
+                )
+            }
+        }
+    }
+
+    /// Url of the commit log at `git_ref`
+    pub fn log_url(&self, git_ref: &str) -> String {
+        match self.vcs {
+            SourceHutVcs::Git => format!("{}/log/{}", self.browse_url(), encode_path(git_ref)),
+            SourceHutVcs::Hg => {
+                format!(
+                    "{}/log?rev={}",
+                    self.browse_url(),
+                    encode_component(git_ref)
+                )
+            }
+        }
+    }
+
+    /// Parse the host and path of url for SourceHut patterns
+    fn parse_path(host: Option<&str>, input: &str) -> Result<SourceHutProvider, GitUrlParseError> {
+        let host = host
+            .ok_or_else(|| {
+                GitUrlParseError::ProviderParseFail("SourceHut url needs a host".into())
+            })?
+            .to_ascii_lowercase();
+
+        let vcs = match host.split_once('.') {
+            Some(("hg", _)) => SourceHutVcs::Hg,
+            Some((service, _)) if NON_VCS_SERVICES.contains(&service) => {
+                return Err(GitUrlParseError::ProviderParseFail(format!(
+                    "SourceHut service doesn't host repos: '{host}'"
+                )));
+            }
+            _ => SourceHutVcs::Git,
+        };
+
+        let parts: Vec<&str> = input.split('/').filter(|s| !s.is_empty()).collect();
+
+        let (owner, repo) = match parts.as_slice() {
+            [owner, repo, ..] => (owner.trim_start_matches('~'), *repo),
+            _ => {
+                return Err(GitUrlParseError::ProviderParseFail(
+                    "Path needs 2 parts: ex. '/~owner/repo'".into(),
+                ));
+            }
+        };
+
+        if owner.is_empty() {
+            return Err(GitUrlParseError::ProviderInvalidName(
+                "SourceHut owner can't be empty".into(),
+            ));
+        }
+
+        Ok(SourceHutProvider {
+            host,
+            owner: format!("~{owner}"),
+            repo: repo.strip_suffix(".git").unwrap_or(repo).to_string(),
+            vcs,
+        })
+    }
+}
+
+impl GitProvider<GitUrl, GitUrlParseError> for SourceHutProvider {
+    fn from_git_url(url: &GitUrl) -> Result<Self, GitUrlParseError> {
+        if url.hint() == GitUrlParseHint::Filelike {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Self::parse_path(url.host(), url.path())
+    }
+}
+
+#[cfg(feature = "url")]
+impl GitProvider<Url, GitUrlParseError> for SourceHutProvider {
+    fn from_git_url(url: &Url) -> Result<Self, GitUrlParseError> {
+        if url.scheme() == "file" {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+
+        Self::parse_path(url.host_str(), url.path())
+    }
+}
+
+impl ToGitUrl for SourceHutProvider {
+    fn clone_path(&self, _protocol: CloneProtocol) -> String {
+        self.fullname()
+    }
+
+    fn ssh_user(&self) -> Option<&str> {
+        match self.vcs {
+            SourceHutVcs::Git => Some("git"),
+            SourceHutVcs::Hg => Some("hg"),
+        }
+    }
+}
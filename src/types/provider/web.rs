@@ -0,0 +1,590 @@
+use super::{
+    AnyProvider, AzureDevOpsProvider, BitbucketCloudProvider, BitbucketServerProvider,
+    GitHubProvider, GitLabProvider, GiteaProvider, ProviderKind, ProviderRegistry,
+};
+use crate::types::GitUrlParseHint;
+use crate::{GitUrl, GitUrlParseError};
+
+use getset::{CopyGetters, Getters};
+#[cfg(feature = "log")]
+use log::debug;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Lines of a file, for line anchors of blob and blame urls
+#[derive(Debug, PartialEq, Eq, Clone, Copy, CopyGetters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[getset(get_copy = "pub")]
+pub struct LineRange {
+    /// First line, starting at 1
+    start: u32,
+    /// Last line, if more than one line
+    end: Option<u32>,
+}
+
+impl LineRange {
+    /// Lines `start` through `end`. A range ending on `start` is a single line
+    pub fn new(start: u32, end: u32) -> Self {
+        let (start, end) = if end < start {
+            (end, start)
+        } else {
+            (start, end)
+        };
+
+        LineRange {
+            start,
+            end: (end != start).then_some(end),
+        }
+    }
+
+    /// A single line
+    pub fn line(line: u32) -> Self {
+        LineRange {
+            start: line,
+            end: None,
+        }
+    }
+
+    /// Last line of the range, which is `start` for a single line
+    pub fn last(&self) -> u32 {
+        self.end.unwrap_or(self.start)
+    }
+}
+
+impl fmt::Display for LineRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.end {
+            Some(end) => write!(f, "{}-{end}", self.start),
+            None => write!(f, "{}", self.start),
+        }
+    }
+}
+
+/// A page of the web frontend of a repo
+///
+/// Refs can be branch or tag names, commit shas, or full `refs/heads/...` and `refs/tags/...` names.
+/// Hosts that need the kind of ref in the url treat 40 or 64 character hex refs as commits,
+/// `refs/tags/` refs as tags, and everything else as branches
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum WebTarget<'a> {
+    /// Repo home page
+    Repo,
+    /// Directory listing at a ref, at the repo root without a path
+    Tree {
+        /// Branch, tag or commit
+        git_ref: &'a str,
+        /// Directory within the repo
+        path: Option<&'a str>,
+    },
+    /// File at a ref, optionally highlighting lines
+    Blob {
+        /// Branch, tag or commit
+        git_ref: &'a str,
+        /// File within the repo
+        path: &'a str,
+        /// Lines to highlight
+        lines: Option<LineRange>,
+    },
+    /// Single commit
+    Commit {
+        /// Commit sha
+        sha: &'a str,
+    },
+    /// Changes between two refs
+    Compare {
+        /// Ref the changes are based on
+        base: &'a str,
+        /// Ref with the changes
+        head: &'a str,
+    },
+    /// Blame of a file at a ref, optionally highlighting lines
+    Blame {
+        /// Branch, tag or commit
+        git_ref: &'a str,
+        /// File within the repo
+        path: &'a str,
+        /// Lines to highlight
+        lines: Option<LineRange>,
+    },
+}
+
+/// Render urls of the web frontend of a repo, following the conventions of its host
+///
+/// The url is served by the host of the parsed repo url, keeping the scheme and port of http urls,
+/// same as [`ToApiUrl`](super::ToApiUrl) and [`ToRawFileUrl`]
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::provider::{GitHubProvider, GitLabProvider, LineRange, ToWebUrl, WebTarget};
+///
+/// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+/// let target = WebTarget::Blob {
+///     git_ref: "main",
+///     path: "src/lib.rs",
+///     lines: Some(LineRange::new(10, 20)),
+/// };
+///
+/// let url = GitUrl::parse("git@github.com:tjtelan/git-url-parse-rs.git")?;
+/// let provider: GitHubProvider = url.provider_info()?;
+/// assert_eq!(
+///     provider.web_url(&url, target)?,
+///     "https://github.com/tjtelan/git-url-parse-rs/blob/main/src/lib.rs#L10-L20"
+/// );
+///
+/// let url = GitUrl::parse("http://gitlab.lan:8080/group/sub/repo.git")?;
+/// let provider: GitLabProvider = url.provider_info()?;
+/// assert_eq!(
+///     provider.web_url(&url, target)?,
+///     "http://gitlab.lan:8080/group/sub/repo/-/blob/main/src/lib.rs#L10-20"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub trait ToWebUrl {
+    /// Url of `target` on the web frontend serving the repo of `url`.
+    /// Returns [`GitUrlParseError::ProviderUnsupported`] for urls without a host, or if the host doesn't have the page
+    fn web_url(&self, url: &GitUrl, target: WebTarget) -> Result<String, GitUrlParseError>;
+}
+
+/// Render urls downloading the raw content of a single file, for fetching it without cloning
+///
+/// The url is served by the host of the parsed repo url, keeping the scheme and port of http urls
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::provider::{GitLabProvider, ToRawFileUrl};
+///
+/// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+/// let url = GitUrl::parse("git@gitlab.com:group/sub/repo.git")?;
+/// let provider: GitLabProvider = url.provider_info()?;
+/// assert_eq!(
+///     provider.raw_file_url(&url, "main", "config/app.toml")?,
+///     "https://gitlab.com/group/sub/repo/-/raw/main/config/app.toml"
+/// );
+///
+/// let url = GitUrl::parse("http://gitlab.lan:8080/group/repo.git")?;
+/// let provider: GitLabProvider = url.provider_info()?;
+/// assert_eq!(
+///     provider.raw_file_url(&url, "main", "config/app.toml")?,
+///     "http://gitlab.lan:8080/group/repo/-/raw/main/config/app.toml"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub trait ToRawFileUrl {
+    /// Url downloading the raw content of the file at `path` and `git_ref`, served by the host of `url`.
+    /// Returns [`GitUrlParseError::ProviderUnsupported`] for urls without a host, or repos without files
+    fn raw_file_url(
+        &self,
+        url: &GitUrl,
+        git_ref: &str,
+        path: &str,
+    ) -> Result<String, GitUrlParseError>;
+}
+
+/// Kind of page of a [`WebLink`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum WebPage {
+    /// Repo home page
+    Repo,
+    /// Directory listing. Hosts using the same page for files and directories
+    /// (Azure DevOps, Bitbucket, Gitea) link files as trees, unless lines are selected
+    Tree,
+    /// File
+    Blob,
+    /// Blame of a file
+    Blame,
+    /// Single commit
+    Commit,
+    /// Any other page of the repo (i.e. issues)
+    Other,
+}
+
+/// A browser link to a page of a repo, split into the repo clone url and the location within the repo
+///
+/// Supports GitHub, GitLab, Azure DevOps, Bitbucket and Gitea links. Providers are picked from the host,
+/// or from the path shape for unknown hosts.
+/// Refs are the first path segment after the page (i.e. `blob/<ref>/<path>`),
+/// so branch names with `/` can't be told apart from the path
+///
+/// ```
+/// use git_url_parse::types::provider::{LineRange, ProviderKind, WebLink, WebPage};
+///
+/// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+/// let link = WebLink::parse("https://github.com/tjtelan/git-url-parse-rs/blob/main/src/lib.rs#L5")?;
+/// assert_eq!(link.clone_url().to_string(), "https://github.com/tjtelan/git-url-parse-rs.git");
+/// assert_eq!(link.provider().kind(), ProviderKind::GitHub);
+/// assert_eq!(link.page(), WebPage::Blob);
+/// assert_eq!(link.git_ref(), Some("main"));
+/// assert_eq!(link.path(), Some("src/lib.rs"));
+/// assert_eq!(link.lines(), Some(LineRange::line(5)));
+///
+/// let link = WebLink::parse("https://dev.azure.com/org/project/_git/Repo?path=/docs&version=GBdev")?;
+/// assert_eq!(link.clone_url().to_string(), "https://dev.azure.com/org/project/_git/Repo");
+/// assert_eq!(link.page(), WebPage::Tree);
+/// assert_eq!(link.git_ref(), Some("dev"));
+/// assert_eq!(link.path(), Some("docs"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Getters, CopyGetters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WebLink {
+    /// Https clone url of the repo
+    #[getset(get = "pub")]
+    clone_url: GitUrl,
+    /// Provider info of the repo
+    #[getset(get = "pub")]
+    provider: AnyProvider,
+    /// Kind of page linked
+    #[getset(get_copy = "pub")]
+    page: WebPage,
+    /// Branch, tag (as `refs/tags/<tag>` when the link says so) or commit of the page
+    git_ref: Option<String>,
+    /// File or directory within the repo, without leading `/`
+    path: Option<String>,
+    /// Selected lines
+    #[getset(get_copy = "pub")]
+    lines: Option<LineRange>,
+}
+
+impl WebLink {
+    /// Split a browser link of a built-in provider
+    pub fn parse(input: &str) -> Result<Self, GitUrlParseError> {
+        Self::parse_with(input, &ProviderRegistry::new())
+    }
+
+    /// Split a browser link, with self-hosted instances mapped to providers by `registry`
+    pub fn parse_with(input: &str, registry: &ProviderRegistry) -> Result<Self, GitUrlParseError> {
+        let input = input.trim();
+        let (rest, fragment) = match input.split_once('#') {
+            Some((rest, fragment)) => (rest, Some(fragment)),
+            None => (input, None),
+        };
+        let (rest, query) = rest.split_once('?').unwrap_or((rest, ""));
+
+        let (scheme, rest) = rest
+            .split_once("://")
+            .filter(|(scheme, _)| {
+                scheme.eq_ignore_ascii_case("https") || scheme.eq_ignore_ascii_case("http")
+            })
+            .ok_or(GitUrlParseError::ProviderUnsupported)?;
+
+        // `GitUrl` stops at percent-encoded characters, so the web path is split here
+        let (authority, path) = rest.split_once('/').unwrap_or((rest, ""));
+        let host_port = authority.rsplit_once('@').map_or(authority, |(_, h)| h);
+        let (host, port) = split_host_port(host_port)?;
+
+        let parts: Vec<String> = path
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .map(decode)
+            .collect();
+        let query: Vec<(String, String)> = query
+            .split('&')
+            .filter(|s| !s.is_empty())
+            .map(|pair| {
+                let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                (decode(key), decode(value))
+            })
+            .collect();
+
+        let known = [registry, ProviderRegistry::builtin_ref()]
+            .into_iter()
+            .find_map(|registry| registry.lookup(host, port))
+            .map(|entry| entry.provider());
+        let candidates = match known {
+            Some(kind) => vec![kind],
+            None => WEB_KINDS.to_vec(),
+        };
+
+        let mut error = GitUrlParseError::ProviderParseFail(format!(
+            "Not a web link of a supported provider: '{input}'"
+        ));
+
+        for kind in candidates {
+            let Some(web_path) = split_web_path(kind, &parts, &query) else {
+                continue;
+            };
+
+            // Unknown hosts need a recognized page to tell providers apart
+            if known.is_none() && matches!(web_path.page, WebPage::Repo | WebPage::Other) {
+                continue;
+            }
+
+            // Providers parse the decoded names, the clone url keeps them encoded
+            let mut repo_url = GitUrl::default();
+            repo_url.set_scheme(Some(scheme.to_ascii_lowercase()));
+            repo_url.set_host(Some(host.to_string()));
+            repo_url.set_port(port);
+            repo_url.set_path(format!("/{}", web_path.clone_path.join("/")));
+            repo_url.set_print_scheme(true);
+            repo_url.set_hint(GitUrlParseHint::Httplike);
+
+            match kind.parse(&repo_url) {
+                Ok(provider) => {
+                    let mut clone_url = repo_url;
+                    clone_url.set_path(format!("/{}", encode_path(&web_path.clone_path.join("/"))));
+
+                    let lines = web_path.lines.or_else(|| fragment.and_then(parse_lines));
+                    let page = match web_path.page {
+                        WebPage::Tree if lines.is_some() => WebPage::Blob,
+                        page => page,
+                    };
+
+                    return Ok(WebLink {
+                        clone_url,
+                        provider,
+                        page,
+                        git_ref: web_path.git_ref,
+                        path: web_path.path.filter(|p| !p.is_empty()),
+                        lines,
+                    });
+                }
+                Err(e) => {
+                    #[cfg(feature = "log")]
+                    debug!("Web link candidate {kind:?} failed to parse: {e:?}");
+                    error = e;
+                }
+            }
+        }
+
+        Err(error)
+    }
+
+    /// Branch, tag (as `refs/tags/<tag>` when the link says so) or commit of the page
+    pub fn git_ref(&self) -> Option<&str> {
+        self.git_ref.as_deref()
+    }
+
+    /// File or directory within the repo, without leading `/`
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+}
+
+/// Split the host and port of a url authority. IPv6 literals keep their brackets
+fn split_host_port(host_port: &str) -> Result<(&str, Option<u16>), GitUrlParseError> {
+    let port_start = match host_port.rfind(']') {
+        Some(end) => (end + 1 < host_port.len()).then_some(end + 1),
+        None => host_port.rfind(':'),
+    };
+
+    let Some(port_start) = port_start else {
+        return Ok((host_port, None));
+    };
+
+    let port = host_port[port_start..]
+        .strip_prefix(':')
+        .and_then(|port| port.parse::<u16>().ok())
+        .ok_or(GitUrlParseError::InvalidPortNumber)?;
+
+    Ok((&host_port[..port_start], Some(port)))
+}
+
+/// Providers with web links, in the order they are tried for unknown hosts
+const WEB_KINDS: &[ProviderKind] = &[
+    ProviderKind::AzureDevOps,
+    ProviderKind::GitLab,
+    ProviderKind::BitbucketServer,
+    ProviderKind::Gitea,
+    ProviderKind::GitHub,
+    ProviderKind::BitbucketCloud,
+];
+
+/// A web url path split into the repo and the page within it
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub(crate) struct WebPath {
+    /// Decoded segments of the https clone path
+    pub(crate) clone_path: Vec<String>,
+    /// Kind of page
+    pub(crate) page: WebPage,
+    /// Ref of the page
+    pub(crate) git_ref: Option<String>,
+    /// Path within the repo
+    pub(crate) path: Option<String>,
+    /// Lines selected in the query, for hosts that don't use the fragment
+    pub(crate) lines: Option<LineRange>,
+}
+
+impl WebPath {
+    /// Web path of the `page` of the repo at `clone_path`, at `rest` (`<ref>/<path>`) for pages with a ref
+    pub(crate) fn new(clone_path: Vec<String>, page: WebPage, rest: &[String]) -> Self {
+        let (git_ref, path) = match (page, rest) {
+            (WebPage::Repo | WebPage::Other, _) | (_, []) => (None, None),
+            (_, [git_ref, path @ ..]) => (Some(git_ref.clone()), Some(path.join("/"))),
+        };
+
+        WebPath {
+            clone_path,
+            page,
+            git_ref,
+            path,
+            lines: None,
+        }
+    }
+
+    /// Split `rest` of the `tree`, `blob`, `blame` and `commit` pages used by GitHub and GitLab
+    pub(crate) fn from_pages(clone_path: Vec<String>, rest: &[String]) -> Self {
+        let page = match rest.first().map(|s| s.as_str()) {
+            None => WebPage::Repo,
+            Some("tree") => WebPage::Tree,
+            Some("blob") => WebPage::Blob,
+            Some("blame") => WebPage::Blame,
+            Some("commit") => WebPage::Commit,
+            Some(_) => WebPage::Other,
+        };
+
+        Self::new(clone_path, page, rest.get(1..).unwrap_or_default())
+    }
+}
+
+/// Split a decoded web url path and query for the `kind` provider
+fn split_web_path(
+    kind: ProviderKind,
+    parts: &[String],
+    query: &[(String, String)],
+) -> Option<WebPath> {
+    match kind {
+        ProviderKind::GitHub => GitHubProvider::split_web_path(parts),
+        ProviderKind::GitLab => GitLabProvider::split_web_path(parts),
+        ProviderKind::AzureDevOps => AzureDevOpsProvider::split_web_path(parts, query),
+        ProviderKind::BitbucketCloud => BitbucketCloudProvider::split_web_path(parts),
+        ProviderKind::BitbucketServer => BitbucketServerProvider::split_web_path(parts, query),
+        ProviderKind::Gitea => GiteaProvider::split_web_path(parts),
+        _ => None,
+    }
+}
+
+/// Value of the first `key` in a decoded query
+pub(crate) fn query_value<'a>(query: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    query
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v.as_str())
+}
+
+/// Lines of a url fragment: `L10`, `L10-L20`, `L10-20`, `lines-10:20` or `10-20`
+fn parse_lines(fragment: &str) -> Option<LineRange> {
+    let lines = fragment
+        .strip_prefix("lines-")
+        .or_else(|| fragment.strip_prefix('L'))
+        .unwrap_or(fragment);
+
+    let (start, end) = match lines.split_once(['-', ':']) {
+        Some((start, end)) => (start, Some(end.trim_start_matches('L'))),
+        None => (lines, None),
+    };
+
+    let start = start.parse().ok().filter(|s| *s > 0)?;
+    match end {
+        Some(end) => Some(LineRange::new(start, end.parse().ok().filter(|e| *e > 0)?)),
+        None => Some(LineRange::line(start)),
+    }
+}
+
+/// Kind of a ref, for hosts that need it in urls
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum RefKind {
+    /// Branch name
+    Branch,
+    /// Tag name
+    Tag,
+    /// Commit sha
+    Commit,
+}
+
+/// `{scheme}://{host}[:{port}]` of the web frontend serving `url`.
+/// Http urls keep their scheme and port. Ssh urls use https on the same host, since their port is the ssh port
+pub(crate) fn web_origin(url: &GitUrl) -> Result<String, GitUrlParseError> {
+    let host = match (url.hint(), url.host()) {
+        (GitUrlParseHint::Filelike, _) | (_, None) => {
+            return Err(GitUrlParseError::ProviderUnsupported);
+        }
+        (_, Some(host)) => host,
+    };
+
+    let scheme = if url.scheme() == Some("http") {
+        "http"
+    } else {
+        "https"
+    };
+
+    match url
+        .port()
+        .filter(|_| url.hint() == GitUrlParseHint::Httplike)
+    {
+        Some(port) => Ok(format!("{scheme}://{host}:{port}")),
+        None => Ok(format!("{scheme}://{host}")),
+    }
+}
+
+/// Kind and short name of `git_ref`
+pub(crate) fn ref_kind(git_ref: &str) -> (RefKind, &str) {
+    if let Some(tag) = git_ref.strip_prefix("refs/tags/") {
+        (RefKind::Tag, tag)
+    } else if let Some(branch) = git_ref.strip_prefix("refs/heads/") {
+        (RefKind::Branch, branch)
+    } else if matches!(git_ref.len(), 40 | 64) && git_ref.chars().all(|c| c.is_ascii_hexdigit()) {
+        (RefKind::Commit, git_ref)
+    } else {
+        (RefKind::Branch, git_ref)
+    }
+}
+
+/// Percent-encode all but the unreserved characters of RFC 3986, for query values and single path segments
+pub(crate) fn encode_component(input: &str) -> String {
+    encode(input, "")
+}
+
+/// Percent-encode a repo path, keeping the `/` separators. Leading and trailing `/` are removed
+pub(crate) fn encode_path(input: &str) -> String {
+    encode(input.trim_matches('/'), "/")
+}
+
+/// Percent-encode `input`, keeping unreserved characters and `keep`
+fn encode(input: &str, keep: &str) -> String {
+    let mut encoded = String::with_capacity(input.len());
+
+    for byte in input.bytes() {
+        let c = byte as char;
+        if c.is_ascii_alphanumeric() || "-._~".contains(c) || keep.contains(c) {
+            encoded.push(c);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+
+    encoded
+}
+
+/// Decode percent-encoded bytes. Invalid escapes are kept as is
+pub(crate) fn decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| input.get(i + 1..i + 3))
+            .flatten()
+            .filter(|hex| hex.chars().all(|c| c.is_ascii_hexdigit()))
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
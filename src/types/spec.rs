@@ -10,7 +10,7 @@ use nom::Finish;
 use nom::branch::alt;
 use nom::bytes::complete::{tag, take_while};
 use nom::character::complete::alpha1;
-use nom::combinator::{map_opt, peek, recognize, verify};
+use nom::combinator::{map_opt, not, peek, recognize, verify};
 use nom::error::context;
 use nom::multi::{many0, many1};
 use nom::sequence::{pair, preceded, separated_pair, terminated};
@@ -31,11 +31,13 @@ impl UrlSpecParser {
     /// Based on rfc3986, but does not strictly cover the spec
     /// * No support for:
     ///     * query, fragment, percent-encoding, and much of the edges for path support
-    ///     * many forms of ip representations like ipv6, hexdigits
+    ///     * many forms of ip representations like hexdigits, or ipv6 zone ids
+    ///     * ipv6 literals are kept in brackets (i.e. `[::1]`)
     /// * Added support for:
     ///     * parsing ssh git urls which use ":" as a delimiter between the authority and path
     ///     * parsing userinfo into user:token (but its officially deprecated, per #section-3.2.1)
-    ///     * some limited support for windows/linux filepaths
+    ///     * some limited support for linux filepaths
+    ///     * windows filepaths are identified before this parser, in `GitUrl::parse()`
     pub(crate) fn parse(input: &str) -> IResult<&str, Self> {
         let (input, scheme) = Self::parse_scheme.parse(input).finish().unwrap_or_default();
         let (input, heir_part) = Self::parse_hier_part(input).finish().unwrap_or_default();
@@ -176,33 +178,6 @@ impl UrlSpecParser {
         // Optional: username / token
         let (input, userinfo) = Self::parse_userinfo(input)?;
 
-        // Host
-        #[cfg(feature = "log")]
-        {
-            debug!("Looking ahead for windows-style path vs host");
-        }
-
-        // peek ahead to check for windows path stuff
-        let check = context(
-            "Host check for windows path",
-            peek(preceded(
-                take_while(|c| reg_name_uri_chars(c) && c != '\\'),
-                tag::<&str, &str, nom::error::Error<&str>>(":\\"),
-            )),
-        )
-        .parse(input);
-
-        if check.is_ok() {
-            #[cfg(feature = "log")]
-            {
-                debug!(
-                    "Host check failed. Found potential windows-style path while looking for host"
-                );
-            }
-
-            return Ok((input, UrlAuthority::default()));
-        }
-
         #[cfg(feature = "log")]
         {
             debug!("Parsing for host");
@@ -210,15 +185,27 @@ impl UrlSpecParser {
 
         let (input, host) = context(
             "Host parser",
-            opt(verify(
-                recognize(take_while(|c: char| reg_name_uri_chars(c))),
-                |s: &str| {
-                    let has_alphanum = s.chars().any(char::is_alphanumeric);
-                    let starts_with_alphanum = s.chars().next().is_some_and(char::is_alphanumeric);
-
-                    has_alphanum && starts_with_alphanum && !s.is_empty()
-                },
-            )),
+            opt(alt((
+                // IP-literal, keeping the brackets
+                recognize((
+                    tag("["),
+                    verify(
+                        take_while(|c: char| c.is_ascii_hexdigit() || c == ':' || c == '.'),
+                        |s: &str| s.contains(':'),
+                    ),
+                    tag("]"),
+                )),
+                verify(
+                    recognize(take_while(|c: char| reg_name_uri_chars(c))),
+                    |s: &str| {
+                        let has_alphanum = s.chars().any(char::is_alphanumeric);
+                        let starts_with_alphanum =
+                            s.chars().next().is_some_and(char::is_alphanumeric);
+
+                        has_alphanum && starts_with_alphanum && !s.is_empty()
+                    },
+                ),
+            ))),
         )
         .parse(input)?;
 
@@ -464,13 +451,17 @@ impl UrlSpecParser {
         )
     }
 
-    /// Non-consuming check for `git:` (short git) as scheme for normalizing
+    /// Non-consuming check for `git:` (short git) as scheme for normalizing.
+    /// `git://` is left for the regular scheme parser
     fn short_git_scheme_check(input: &str) -> bool {
         context(
             "short git validate",
             peek(terminated(
                 tag::<&str, &str, nom::error::Error<&str>>("git"),
-                tag::<&str, &str, nom::error::Error<&str>>(":"),
+                pair(
+                    tag::<&str, &str, nom::error::Error<&str>>(":"),
+                    not(tag::<&str, &str, nom::error::Error<&str>>("//")),
+                ),
             )),
         )
         .parse(input)
@@ -527,7 +518,7 @@ pub(crate) fn unreserved_uri_chars(c: char) -> bool {
     c.is_alphanumeric() || c == '-' || c == '.' || c == '_' || c == '~'
 }
 
-/// RFC 3986 sub-delims (mostly)
+/// RFC 3986 sub-delims
 pub(crate) fn subdelims_uri_chars(c: char) -> bool {
     c == '!'
         || c == '$'
@@ -540,5 +531,4 @@ pub(crate) fn subdelims_uri_chars(c: char) -> bool {
         || c == ','
         || c == ';'
         || c == '='
-        || c == '\\' // This is not part of spec, but used for windows paths
 }
@@ -0,0 +1,199 @@
+//! # GitUrl transport helpers
+//!
+//! Typed parts of urls for git remote helpers, where the host and path don't name a network location.
+//! Radicle urls can omit the path, so they are identified before url parsing.
+//!
+
+use crate::{GitUrl, GitUrlParseError};
+
+use getset::{CopyGetters, Getters};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Scheme of Radicle urls
+pub(crate) const RADICLE_SCHEME: &str = "rad";
+
+/// Scheme of Keybase urls
+pub(crate) const KEYBASE_SCHEME: &str = "keybase";
+
+/// Characters of base58btc, used by Radicle repo and node ids
+const BASE58_CHARS: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// A git remote helper transport with its own url layout
+///
+/// ```
+/// use git_url_parse::GitUrl;
+/// use git_url_parse::types::{KeybaseVisibility, Transport};
+///
+/// # fn main() -> Result<(), git_url_parse::GitUrlParseError> {
+/// let url = GitUrl::parse("rad://z3gqcJUoA1n9HaHKufZs5FCSGazv5")?;
+/// let Some(Transport::Radicle(radicle)) = url.transport() else {
+///     panic!("Radicle transport expected");
+/// };
+/// assert_eq!(radicle.rid(), "z3gqcJUoA1n9HaHKufZs5FCSGazv5");
+/// assert_eq!(radicle.nid(), None);
+///
+/// let url = GitUrl::parse("keybase://team/acme.devs/infra")?;
+/// let Some(Transport::Keybase(keybase)) = url.transport() else {
+///     panic!("Keybase transport expected");
+/// };
+/// assert_eq!(keybase.visibility(), KeybaseVisibility::Team);
+/// assert_eq!(keybase.owner(), "acme.devs");
+/// assert_eq!(keybase.repo(), "infra");
+///
+/// let url = GitUrl::parse("https://github.com/tjtelan/git-url-parse-rs.git")?;
+/// assert_eq!(url.transport(), None);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Transport {
+    /// Radicle (i.e. `rad://<repo id>[/<node id>]`)
+    Radicle(RadicleTransport),
+    /// Keybase encrypted git (i.e. `keybase://private/<user>/<repo>`)
+    Keybase(KeybaseTransport),
+}
+
+impl Transport {
+    /// Typed parts of `url`, if its scheme is a known transport
+    pub(crate) fn from_git_url(url: &GitUrl) -> Option<Result<Self, GitUrlParseError>> {
+        let scheme = url.scheme()?.to_ascii_lowercase();
+
+        match scheme.as_str() {
+            RADICLE_SCHEME => {
+                Some(RadicleTransport::parse(url.host(), url.path()).map(Self::Radicle))
+            }
+            KEYBASE_SCHEME => {
+                Some(KeybaseTransport::parse(url.host(), url.path()).map(Self::Keybase))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Radicle repo, optionally on a specific node
+#[derive(Clone, Debug, PartialEq, Eq, Getters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RadicleTransport {
+    /// Repository id, without the `rad:` prefix (i.e. `z3gqcJUoA1n9HaHKufZs5FCSGazv5`)
+    #[getset(get = "pub")]
+    rid: String,
+    /// Node id of the peer to fetch from
+    nid: Option<String>,
+}
+
+impl RadicleTransport {
+    /// Node id of the peer to fetch from
+    pub fn nid(&self) -> Option<&str> {
+        self.nid.as_deref()
+    }
+
+    /// Parse the repo id host and node id path of a Radicle url
+    fn parse(host: Option<&str>, path: &str) -> Result<Self, GitUrlParseError> {
+        let rid = host.unwrap_or_default();
+        Self::validate_id("repo id", rid)?;
+
+        let nid = path.trim_matches('/');
+        if !nid.is_empty() {
+            Self::validate_id("node id", nid)?;
+        }
+
+        Ok(RadicleTransport {
+            rid: rid.to_string(),
+            nid: (!nid.is_empty()).then(|| nid.to_string()),
+        })
+    }
+
+    /// Radicle ids are multibase base58btc strings, starting with `z`
+    fn validate_id(kind: &str, id: &str) -> Result<(), GitUrlParseError> {
+        let valid = id
+            .strip_prefix('z')
+            .is_some_and(|rest| !rest.is_empty() && rest.chars().all(|c| BASE58_CHARS.contains(c)));
+
+        if valid {
+            Ok(())
+        } else {
+            Err(GitUrlParseError::InvalidTransport(format!(
+                "Radicle {kind} must be base58 starting with 'z': '{id}'"
+            )))
+        }
+    }
+}
+
+/// Who can access a Keybase repo
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum KeybaseVisibility {
+    /// Only the owning user (i.e. `keybase://private/<user>/<repo>`)
+    Private,
+    /// Readable by anyone (i.e. `keybase://public/<user>/<repo>`)
+    Public,
+    /// Members of a team (i.e. `keybase://team/<team>/<repo>`)
+    Team,
+}
+
+/// Keybase encrypted git repo
+#[derive(Clone, Debug, PartialEq, Eq, Getters, CopyGetters)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KeybaseTransport {
+    /// Who can access the repo
+    #[getset(get_copy = "pub")]
+    visibility: KeybaseVisibility,
+    /// User, or team for team repos
+    #[getset(get = "pub")]
+    owner: String,
+    /// Repo name
+    #[getset(get = "pub")]
+    repo: String,
+}
+
+impl KeybaseTransport {
+    /// Parse the visibility host and `<owner>/<repo>` path of a Keybase url
+    fn parse(host: Option<&str>, path: &str) -> Result<Self, GitUrlParseError> {
+        let visibility = match host.map(|h| h.to_ascii_lowercase()).as_deref() {
+            Some("private") => KeybaseVisibility::Private,
+            Some("public") => KeybaseVisibility::Public,
+            Some("team") => KeybaseVisibility::Team,
+            _ => {
+                return Err(GitUrlParseError::InvalidTransport(format!(
+                    "Keybase url must start with 'private', 'public' or 'team': '{}'",
+                    host.unwrap_or_default()
+                )));
+            }
+        };
+
+        let parts: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+        let [owner, repo] = parts.as_slice() else {
+            return Err(GitUrlParseError::InvalidTransport(format!(
+                "Keybase path expected: ex. '/user/repo', found '{path}'"
+            )));
+        };
+
+        Ok(KeybaseTransport {
+            visibility,
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+        })
+    }
+}
+
+/// Split `rad://<repo id>[/<node id>]` into the repo id and optional node id
+pub(crate) fn radicle_url(input: &str) -> Option<(&str, Option<&str>)> {
+    let scheme_len = RADICLE_SCHEME.len() + "://".len();
+    let prefix = input.get(..scheme_len)?;
+
+    if !prefix.eq_ignore_ascii_case(&format!("{RADICLE_SCHEME}://")) {
+        return None;
+    }
+
+    let rest = &input[scheme_len..];
+
+    match rest.split_once('/') {
+        Some((rid, nid)) => Some((rid, (!nid.is_empty()).then_some(nid))),
+        None => Some((rest, None)),
+    }
+}
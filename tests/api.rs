@@ -0,0 +1,263 @@
+use git_url_parse::types::provider::{
+    ApiResource, AzureDevOpsProvider, BitbucketCloudProvider, BitbucketServerProvider,
+    GitHubProvider, GitLabProvider, GiteaProvider, ToApiUrl,
+};
+use git_url_parse::{GitUrl, GitUrlParseError};
+use log::debug;
+
+/// Parse a url, and its provider info
+fn parse<P>(url: &str) -> (GitUrl, P)
+where
+    P: git_url_parse::types::provider::GitProvider<GitUrl, GitUrlParseError>,
+{
+    let parsed = GitUrl::parse(url).expect("URL parse failed");
+    let provider: P = parsed
+        .provider_info()
+        .expect("Failed to parse provider info");
+
+    (parsed, provider)
+}
+
+/// Api urls of every resource, in order: repo, branches, pull requests, file
+fn api_urls<P: ToApiUrl>(provider: &P, url: &GitUrl) -> Vec<String> {
+    [
+        ApiResource::Repo,
+        ApiResource::Branches,
+        ApiResource::PullRequests,
+        ApiResource::File {
+            git_ref: "feature/x",
+            path: "/docs/my file.md",
+        },
+    ]
+    .into_iter()
+    .map(|resource| {
+        let api_url = provider.api_url(url, resource);
+        debug!("{resource:?}: {api_url:?}");
+        api_url.expect("Api url expected")
+    })
+    .collect()
+}
+
+#[test]
+fn github_com_api_hosts() {
+    let _ = env_logger::try_init();
+
+    for url in [
+        "https://github.com/owner/repo.git",
+        "https://GitHub.com/owner/repo.git",
+        "https://WWW.GITHUB.COM/owner/repo.git",
+        "ssh://git@SSH.GitHub.com:443/owner/repo.git",
+        "https://api.github.com/repos/owner/repo",
+        "https://API.GitHub.com/repos/owner/repo",
+        "https://raw.githubusercontent.com/owner/repo/main/README.md",
+        "https://Raw.GitHubUserContent.com/owner/repo/main/README.md",
+    ] {
+        debug!("{url}");
+        let (url, provider) = parse::<GitHubProvider>(url);
+        assert_eq!(
+            provider.api_url(&url, ApiResource::Repo),
+            Ok("https://api.github.com/repos/owner/repo".to_string())
+        );
+    }
+}
+
+#[test]
+fn github_api_urls() {
+    let _ = env_logger::try_init();
+
+    let (url, provider) = parse::<GitHubProvider>("git@ssh.github.com:owner/repo.git");
+    assert_eq!(
+        provider.api_base_url(&url),
+        Ok("https://api.github.com".to_string())
+    );
+    assert_eq!(
+        api_urls(&provider, &url),
+        [
+            "https://api.github.com/repos/owner/repo",
+            "https://api.github.com/repos/owner/repo/branches",
+            "https://api.github.com/repos/owner/repo/pulls",
+            "https://api.github.com/repos/owner/repo/contents/docs/my%20file.md?ref=feature%2Fx",
+        ]
+    );
+
+    let (url, provider) = parse::<GitHubProvider>("https://ghe.corp.example/owner/repo.git");
+    assert_eq!(
+        provider.api_url(&url, ApiResource::Repo),
+        Ok("https://ghe.corp.example/api/v3/repos/owner/repo".to_string())
+    );
+
+    let (url, provider) =
+        parse::<GitHubProvider>("https://gist.github.com/aa5a315d61ae9438b18d.git");
+    assert_eq!(
+        provider.api_url(&url, ApiResource::Repo),
+        Ok("https://api.github.com/gists/aa5a315d61ae9438b18d".to_string())
+    );
+    assert_eq!(
+        provider.api_url(&url, ApiResource::Branches),
+        Err(GitUrlParseError::ProviderUnsupported)
+    );
+}
+
+#[test]
+fn gitlab_api_urls() {
+    let _ = env_logger::try_init();
+
+    let (url, provider) = parse::<GitLabProvider>("git@gitlab.corp.example:group/sub/repo.git");
+    assert_eq!(
+        api_urls(&provider, &url),
+        [
+            "https://gitlab.corp.example/api/v4/projects/group%2Fsub%2Frepo",
+            "https://gitlab.corp.example/api/v4/projects/group%2Fsub%2Frepo/repository/branches",
+            "https://gitlab.corp.example/api/v4/projects/group%2Fsub%2Frepo/merge_requests",
+            "https://gitlab.corp.example/api/v4/projects/group%2Fsub%2Frepo/repository/files/docs%2Fmy%20file.md/raw?ref=feature%2Fx",
+        ]
+    );
+
+    let (url, provider) = parse::<GitLabProvider>("https://gitlab.com/snippets/123.git");
+    assert_eq!(
+        provider.api_url(&url, ApiResource::Repo),
+        Ok("https://gitlab.com/api/v4/snippets/123".to_string())
+    );
+}
+
+#[test]
+fn azure_devops_api_urls() {
+    let _ = env_logger::try_init();
+
+    let (url, provider) =
+        parse::<AzureDevOpsProvider>("git@ssh.dev.azure.com:v3/CompanyName/ProjectName/RepoName");
+    assert_eq!(
+        api_urls(&provider, &url),
+        [
+            "https://dev.azure.com/CompanyName/ProjectName/_apis/git/repositories/RepoName",
+            "https://dev.azure.com/CompanyName/ProjectName/_apis/git/repositories/RepoName/refs?filter=heads/",
+            "https://dev.azure.com/CompanyName/ProjectName/_apis/git/repositories/RepoName/pullrequests",
+            "https://dev.azure.com/CompanyName/ProjectName/_apis/git/repositories/RepoName/items?path=/docs/my%20file.md&versionDescriptor.version=feature%2Fx&versionDescriptor.versionType=branch",
+        ]
+    );
+
+    let provider = AzureDevOpsProvider::new("CompanyName", "Project Name", "RepoName")
+        .expect("Constructor failed");
+    assert_eq!(
+        provider.api_url(&url, ApiResource::Repo),
+        Ok(
+            "https://dev.azure.com/CompanyName/Project%20Name/_apis/git/repositories/RepoName"
+                .to_string()
+        )
+    );
+
+    let (url, provider) = parse::<AzureDevOpsProvider>(
+        "https://tfs.corp.example/DefaultCollection/Project/_git/Repo",
+    );
+    assert_eq!(
+        provider.api_url(
+            &url,
+            ApiResource::File {
+                git_ref: "refs/tags/v1.0",
+                path: "README.md"
+            }
+        ),
+        Ok("https://tfs.corp.example/DefaultCollection/Project/_apis/git/repositories/Repo/items?path=/README.md&versionDescriptor.version=v1.0&versionDescriptor.versionType=tag".to_string())
+    );
+
+    let (url, provider) =
+        parse::<AzureDevOpsProvider>("https://tfs.corp.example/tfs/Collection/Project/_git/Repo");
+    assert_eq!(
+        provider.api_base_url(&url),
+        Ok("https://tfs.corp.example/tfs/Collection".to_string())
+    );
+}
+
+#[test]
+fn bitbucket_api_urls() {
+    let _ = env_logger::try_init();
+
+    let (url, provider) = parse::<BitbucketCloudProvider>("git@bitbucket.org:workspace/repo.git");
+    assert_eq!(
+        api_urls(&provider, &url),
+        [
+            "https://api.bitbucket.org/2.0/repositories/workspace/repo",
+            "https://api.bitbucket.org/2.0/repositories/workspace/repo/refs/branches",
+            "https://api.bitbucket.org/2.0/repositories/workspace/repo/pullrequests",
+            "https://api.bitbucket.org/2.0/repositories/workspace/repo/src/feature%2Fx/docs/my%20file.md",
+        ]
+    );
+
+    let (url, provider) =
+        parse::<BitbucketServerProvider>("https://bitbucket.corp.example/scm/~jdoe/repo.git");
+    assert_eq!(
+        api_urls(&provider, &url),
+        [
+            "https://bitbucket.corp.example/rest/api/1.0/projects/~jdoe/repos/repo",
+            "https://bitbucket.corp.example/rest/api/1.0/projects/~jdoe/repos/repo/branches",
+            "https://bitbucket.corp.example/rest/api/1.0/projects/~jdoe/repos/repo/pull-requests",
+            "https://bitbucket.corp.example/rest/api/1.0/projects/~jdoe/repos/repo/raw/docs/my%20file.md?at=feature%2Fx",
+        ]
+    );
+
+    let (url, provider) =
+        parse::<BitbucketServerProvider>("https://corp.example/bitbucket/scm/PROJ/repo.git");
+    assert_eq!(
+        provider.api_url(&url, ApiResource::Repo),
+        Ok("https://corp.example/bitbucket/rest/api/1.0/projects/PROJ/repos/repo".to_string())
+    );
+}
+
+#[test]
+fn gitea_api_urls() {
+    let _ = env_logger::try_init();
+
+    let (url, provider) = parse::<GiteaProvider>("https://codeberg.org/owner/repo.git");
+    assert_eq!(
+        api_urls(&provider, &url),
+        [
+            "https://codeberg.org/api/v1/repos/owner/repo",
+            "https://codeberg.org/api/v1/repos/owner/repo/branches",
+            "https://codeberg.org/api/v1/repos/owner/repo/pulls",
+            "https://codeberg.org/api/v1/repos/owner/repo/raw/docs/my%20file.md?ref=feature%2Fx",
+        ]
+    );
+
+    let (url, provider) =
+        parse::<GiteaProvider>("https://git.corp.example/gitea/owner/repo.wiki.git");
+    assert_eq!(
+        provider.api_url(&url, ApiResource::Repo),
+        Ok("https://git.corp.example/gitea/api/v1/repos/owner/repo/wiki/pages".to_string())
+    );
+    assert_eq!(
+        provider.api_url(&url, ApiResource::PullRequests),
+        Err(GitUrlParseError::ProviderUnsupported)
+    );
+}
+
+#[test]
+fn api_urls_keep_port_and_scheme() {
+    let _ = env_logger::try_init();
+
+    let (url, provider) = parse::<GitLabProvider>("https://gitlab.corp:8443/g/r.git");
+    assert_eq!(
+        provider.api_url(&url, ApiResource::Repo),
+        Ok("https://gitlab.corp:8443/api/v4/projects/g%2Fr".to_string())
+    );
+
+    let (url, provider) = parse::<GiteaProvider>("http://gitea.lan:3000/o/r.git");
+    assert_eq!(
+        provider.api_base_url(&url),
+        Ok("http://gitea.lan:3000/api/v1".to_string())
+    );
+
+    // The port of ssh urls is the ssh port, not the API port
+    let (url, provider) =
+        parse::<BitbucketServerProvider>("ssh://git@bitbucket.corp.example:7999/proj/repo.git");
+    assert_eq!(
+        provider.api_url(&url, ApiResource::Repo),
+        Ok("https://bitbucket.corp.example/rest/api/1.0/projects/proj/repos/repo".to_string())
+    );
+
+    let provider = GitLabProvider::new("g", &[], "r").expect("Constructor failed");
+    let url = GitUrl::parse("file:///srv/git/g/r.git").expect("URL parse failed");
+    assert_eq!(
+        provider.api_base_url(&url),
+        Err(GitUrlParseError::ProviderUnsupported)
+    );
+}
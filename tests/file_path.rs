@@ -0,0 +1,308 @@
+use git_url_parse::types::FilePathKind;
+use git_url_parse::*;
+use log::debug;
+use std::path::{Path, PathBuf};
+
+#[test]
+fn file_scheme_to_file_path() {
+    let _ = env_logger::try_init();
+    let test_url = "file:///path/to/repo.git/";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let path = parsed.to_file_path().expect("File path conversion failed");
+    assert_eq!(path, PathBuf::from("/path/to/repo.git/"));
+}
+
+#[test]
+fn bare_path_to_file_path() {
+    let _ = env_logger::try_init();
+    let test_url = "../project-name.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let path = parsed.to_file_path().expect("File path conversion failed");
+    assert_eq!(path, PathBuf::from("../project-name.git"));
+}
+
+#[test]
+fn file_localhost_to_file_path() {
+    let _ = env_logger::try_init();
+    let test_url = "file://localhost/path/to/repo.git";
+    let expected = "file:///path/to/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    assert_eq!(parsed.to_string(), expected);
+    assert_eq!(parsed.host(), None);
+
+    let path = parsed.to_file_path().expect("File path conversion failed");
+    assert_eq!(path, PathBuf::from("/path/to/repo.git"));
+}
+
+#[test]
+fn file_drive_letter_to_file_path() {
+    let _ = env_logger::try_init();
+    let test_url = "file:///C:/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let path = parsed.to_file_path().expect("File path conversion failed");
+    assert_eq!(path, PathBuf::from("C:/repo.git"));
+}
+
+#[cfg(unix)]
+#[test]
+fn file_unc_to_file_path() {
+    let _ = env_logger::try_init();
+    let test_url = "file:////server/share/repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let path = parsed.to_file_path().expect("File path conversion failed");
+    assert_eq!(path, PathBuf::from("//server/share/repo"));
+}
+
+#[cfg(windows)]
+#[test]
+fn file_unc_to_file_path() {
+    let _ = env_logger::try_init();
+    let test_url = "file:////server/share/repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let path = parsed.to_file_path().expect("File path conversion failed");
+    assert_eq!(path, PathBuf::from(r"\\server\share\repo"));
+}
+
+#[test]
+fn bare_unc_to_file_path() {
+    let _ = env_logger::try_init();
+    let test_url = r"\\server\share\repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let path = parsed.to_file_path().expect("File path conversion failed");
+    assert_eq!(path, PathBuf::from(r"\\server\share\repo"));
+}
+
+#[test]
+fn home_expansion() {
+    let _ = env_logger::try_init();
+    let test_url = "~/path/to/repo.git/";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let unexpanded = parsed.to_file_path().expect("File path conversion failed");
+    assert_eq!(unexpanded, PathBuf::from("~/path/to/repo.git/"));
+
+    let expanded = parsed
+        .to_file_path_with_home(Path::new("/home/user"))
+        .expect("File path conversion failed");
+    assert_eq!(expanded, PathBuf::from("/home/user/path/to/repo.git/"));
+}
+
+#[test]
+fn not_filelike_to_file_path() {
+    let _ = env_logger::try_init();
+    let test_url = "git@github.com:tjtelan/git-url-parse-rs.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let e = parsed.to_file_path();
+    assert!(e.is_err());
+    if let Err(err) = e {
+        assert_eq!(err, GitUrlParseError::FilePathUnsupported)
+    }
+}
+
+#[test]
+fn from_file_path_absolute() {
+    let _ = env_logger::try_init();
+    let test_path = Path::new("/path/to/repo.git");
+    let parsed = GitUrl::from_file_path(test_path).expect("File path parse failed");
+    debug!("{:#?}", parsed);
+
+    assert_eq!(parsed.to_string(), "/path/to/repo.git");
+    assert_eq!(parsed.scheme(), Some("file"));
+    assert_eq!(parsed.host(), None);
+    assert_eq!(parsed.path(), "/path/to/repo.git");
+    assert!(!parsed.print_scheme());
+    assert_eq!(parsed, GitUrl::parse("/path/to/repo.git").unwrap());
+}
+
+#[test]
+fn from_file_path_windows() {
+    let _ = env_logger::try_init();
+    let test_path = Path::new(r"c:\project-name.git");
+    let parsed = GitUrl::from_file_path(test_path).expect("File path parse failed");
+    debug!("{:#?}", parsed);
+
+    assert_eq!(parsed.to_string(), r"c:\project-name.git");
+    assert_eq!(parsed.path(), r"c:\project-name.git");
+    assert_eq!(parsed, GitUrl::parse(r"c:\project-name.git").unwrap());
+}
+
+#[test]
+fn from_file_path_round_trip() {
+    let _ = env_logger::try_init();
+    let test_path = PathBuf::from("/path/to/my repo.git");
+    let parsed = GitUrl::from_file_path(&test_path).expect("File path parse failed");
+    debug!("{:#?}", parsed);
+
+    let path = parsed.to_file_path().expect("File path conversion failed");
+    assert_eq!(path, test_path);
+}
+
+#[test]
+fn from_file_path_empty() {
+    let _ = env_logger::try_init();
+    let e = GitUrl::from_file_path(Path::new(""));
+    debug!("{:#?}", e);
+
+    assert!(e.is_err());
+    if let Err(err) = e {
+        assert_eq!(err, GitUrlParseError::InvalidPathEmpty)
+    }
+}
+
+#[test]
+fn windows_drive_forward_slash() {
+    let _ = env_logger::try_init();
+    let test_url = "C:/path/to/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    assert_eq!(parsed.to_string(), test_url);
+    assert_eq!(parsed.scheme(), Some("file"));
+    assert_eq!(parsed.host(), None);
+    assert_eq!(parsed.path(), "C:/path/to/repo.git");
+    assert!(!parsed.print_scheme());
+    assert_eq!(parsed.file_path_kind(), Some(FilePathKind::WindowsDrive));
+}
+
+#[test]
+fn windows_drive_mixed_separators() {
+    let _ = env_logger::try_init();
+    let test_url = r"C:\path/to\repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    assert_eq!(parsed.to_string(), test_url);
+    assert_eq!(parsed.path(), r"C:\path/to\repo.git");
+    assert_eq!(parsed.file_path_kind(), Some(FilePathKind::WindowsDrive));
+}
+
+#[test]
+fn windows_file_scheme_drive() {
+    let _ = env_logger::try_init();
+    let test_url = "file:///C:/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    assert_eq!(parsed.to_string(), test_url);
+    assert_eq!(parsed.path(), "/C:/repo.git");
+    assert!(parsed.print_scheme());
+    assert_eq!(parsed.file_path_kind(), Some(FilePathKind::WindowsDrive));
+}
+
+#[test]
+fn windows_file_scheme_backslash() {
+    let _ = env_logger::try_init();
+    let test_url = r"file://C:\repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    assert_eq!(parsed.to_string(), test_url);
+    assert_eq!(parsed.path(), r"C:\repo.git");
+    assert!(parsed.print_scheme());
+    assert_eq!(parsed.file_path_kind(), Some(FilePathKind::WindowsDrive));
+}
+
+#[test]
+fn windows_unc() {
+    let _ = env_logger::try_init();
+    let test_url = r"\\server\share\repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    assert_eq!(parsed.to_string(), test_url);
+    assert_eq!(parsed.scheme(), Some("file"));
+    assert_eq!(parsed.host(), None);
+    assert_eq!(parsed.path(), r"\\server\share\repo.git");
+    assert_eq!(parsed.file_path_kind(), Some(FilePathKind::WindowsUnc));
+
+    let file_url = GitUrl::parse("file:////server/share/repo.git").expect("URL parse failed");
+    assert_eq!(file_url.file_path_kind(), Some(FilePathKind::WindowsUnc));
+}
+
+#[test]
+fn windows_verbatim() {
+    let _ = env_logger::try_init();
+    for test_url in [r"\\?\C:\path\to\repo.git", r"\\?\UNC\server\share\repo.git"] {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        debug!("{:#?}", parsed);
+
+        assert_eq!(parsed.to_string(), test_url);
+        assert_eq!(parsed.path(), test_url);
+        assert_eq!(parsed.file_path_kind(), Some(FilePathKind::WindowsVerbatim));
+        assert_eq!(
+            parsed.to_file_path().expect("File path conversion failed"),
+            PathBuf::from(test_url)
+        );
+    }
+}
+
+#[test]
+fn windows_relative() {
+    let _ = env_logger::try_init();
+    let test_url = r"..\test_repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    assert_eq!(parsed.file_path_kind(), Some(FilePathKind::WindowsRelative));
+    assert!(
+        parsed
+            .file_path_kind()
+            .is_some_and(|kind| kind.is_windows())
+    );
+}
+
+#[test]
+fn posix_path_kind() {
+    let _ = env_logger::try_init();
+    for test_url in [
+        "/path/to/repo.git",
+        "../repo",
+        "~/repo.git",
+        "file:///repo.git",
+    ] {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        debug!("{:#?}", parsed);
+
+        assert_eq!(parsed.file_path_kind(), Some(FilePathKind::Posix));
+    }
+
+    let ssh_url = GitUrl::parse("host.tld:user/project-name.git").expect("URL parse failed");
+    assert_eq!(ssh_url.file_path_kind(), None);
+}
+
+#[test]
+fn backslash_not_in_host() {
+    let _ = env_logger::try_init();
+    let test_url = r"https://exa\mple.org/owner/repo.git";
+    let parsed = GitUrl::parse(test_url);
+    debug!("{:#?}", parsed);
+
+    assert!(parsed.is_err());
+}
+
+#[test]
+fn backslash_not_in_userinfo() {
+    let _ = env_logger::try_init();
+    let test_url = r"ssh://us\er@host.tld/owner/repo.git";
+    let parsed = GitUrl::parse(test_url);
+    debug!("{:#?}", parsed);
+
+    assert!(parsed.is_err());
+}
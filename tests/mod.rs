@@ -1,3 +1,11 @@
+mod api;
+mod file_path;
+mod normalize;
 mod parse;
+mod pattern;
 mod provider;
+mod registry;
+mod render;
+mod transport;
 mod trim_auth;
+mod web;
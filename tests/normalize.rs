@@ -0,0 +1,189 @@
+use git_url_parse::*;
+use log::debug;
+use std::path::Path;
+
+#[test]
+fn http_dot_segments() {
+    let _ = env_logger::try_init();
+    let test_url = "https://host.tld/a/../b.git";
+    let expected = "https://host.tld/b.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let normalized = parsed.normalize_path();
+    debug!("{:#?}", parsed);
+    debug!("{:#?}", normalized);
+
+    assert_eq!(normalized.to_string(), expected);
+    assert_eq!(normalized.path(), "/b.git");
+}
+
+#[test]
+fn http_dot_segments_above_root() {
+    let _ = env_logger::try_init();
+    let test_url = "https://host.tld/../../owner/./repo.git";
+    let expected = "https://host.tld/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let normalized = parsed.normalize_path();
+    debug!("{:#?}", normalized);
+
+    assert_eq!(normalized.to_string(), expected);
+}
+
+#[test]
+fn http_trailing_dot_segment() {
+    let _ = env_logger::try_init();
+    let test_url = "https://host.tld/owner/repo.git/sub/..";
+    let expected = "https://host.tld/owner/repo.git/";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let normalized = parsed.normalize_path();
+    debug!("{:#?}", normalized);
+
+    assert_eq!(normalized.to_string(), expected);
+}
+
+#[test]
+fn ssh_dot_segments() {
+    let _ = env_logger::try_init();
+    let test_url = "git@host.tld:owner/./sub/../repo.git";
+    let expected = "git@host.tld:owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let normalized = parsed.normalize_path();
+    debug!("{:#?}", normalized);
+
+    assert_eq!(normalized.to_string(), expected);
+}
+
+#[test]
+fn relative_file_dot_segments() {
+    let _ = env_logger::try_init();
+    let cases = [
+        ("./path/to/repo.git/", "path/to/repo.git/"),
+        ("./path/to/repo.git", "path/to/repo.git"),
+        ("../test_repo", "../test_repo"),
+        ("../a/../../test_repo", "../../test_repo"),
+        ("./path/./to/../repo.git", "path/repo.git"),
+        ("~/path/../repo.git/", "~/repo.git/"),
+    ];
+
+    for (test_url, expected) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let normalized = parsed.normalize_path();
+        debug!("{:#?}", normalized);
+
+        assert_eq!(normalized.path(), expected);
+        assert_eq!(normalized.to_string(), expected);
+    }
+}
+
+#[test]
+fn empty_dot_segments() {
+    let _ = env_logger::try_init();
+    let cases = [
+        ("https://host.tld/a/..", "/"),
+        ("git@host.tld:a/..", ""),
+        ("./a/..", "."),
+        (r"a\..", "."),
+    ];
+
+    for (test_url, expected) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let normalized = parsed.normalize_path();
+        debug!("{:#?}", normalized);
+
+        assert_eq!(normalized.path(), expected);
+    }
+}
+
+#[test]
+fn absolute_file_dot_segments() {
+    let _ = env_logger::try_init();
+    let test_url = "file:///path/to/../../../repo.git/";
+    let expected = "file:///repo.git/";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let normalized = parsed.normalize_path();
+    debug!("{:#?}", normalized);
+
+    assert_eq!(normalized.to_string(), expected);
+}
+
+#[test]
+fn windows_dot_segments() {
+    let _ = env_logger::try_init();
+    let cases = [
+        (r"C:\path\to\..\repo.git", r"C:\path\repo.git"),
+        (r"C:\..\repo.git", r"C:\repo.git"),
+        (r"C:/path/./repo.git", r"C:/path/repo.git"),
+        (r"\\server\share\..\repo.git", r"\\server\share\repo.git"),
+        (r"..\a\..\test_repo", r"..\test_repo"),
+        (r"\\?\C:\path\..\repo.git", r"\\?\C:\path\..\repo.git"),
+    ];
+
+    for (test_url, expected) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let normalized = parsed.normalize_path();
+        debug!("{:#?}", normalized);
+
+        assert_eq!(normalized.path(), expected);
+    }
+}
+
+#[test]
+fn resolve_relative_file_path() {
+    let _ = env_logger::try_init();
+    let from_repo_parent = GitUrl::parse("./path/to/repo.git")
+        .unwrap()
+        .resolve_file_path(Path::new("/home/user"))
+        .expect("File path resolve failed");
+    let from_sibling = GitUrl::parse("../../path/to/repo.git")
+        .unwrap()
+        .resolve_file_path(Path::new("/home/user/src/project/"))
+        .expect("File path resolve failed");
+    debug!("{:#?}", from_repo_parent);
+    debug!("{:#?}", from_sibling);
+
+    assert_eq!(from_repo_parent.path(), "/home/user/path/to/repo.git");
+    assert_eq!(from_repo_parent, from_sibling);
+}
+
+#[test]
+fn resolve_absolute_file_path() {
+    let _ = env_logger::try_init();
+    let resolved = GitUrl::parse("/path/./to/repo.git")
+        .unwrap()
+        .resolve_file_path(Path::new("/home/user"))
+        .expect("File path resolve failed");
+    debug!("{:#?}", resolved);
+
+    assert_eq!(resolved.path(), "/path/to/repo.git");
+
+    let home = GitUrl::parse("~/repo.git")
+        .unwrap()
+        .resolve_file_path(Path::new("/home/user"))
+        .expect("File path resolve failed");
+
+    assert_eq!(home.path(), "~/repo.git");
+}
+
+#[test]
+fn resolve_windows_file_path() {
+    let _ = env_logger::try_init();
+    let resolved = GitUrl::parse(r"..\test_repo")
+        .unwrap()
+        .resolve_file_path(Path::new(r"C:\Users\user\src"))
+        .expect("File path resolve failed");
+    debug!("{:#?}", resolved);
+
+    assert_eq!(resolved.path(), r"C:\Users\user\test_repo");
+}
+
+#[test]
+fn resolve_not_filelike() {
+    let _ = env_logger::try_init();
+    let parsed = GitUrl::parse("https://host.tld/owner/repo.git").unwrap();
+    let e = parsed.resolve_file_path(Path::new("/home/user"));
+    debug!("{:#?}", e);
+
+    assert!(e.is_err());
+    if let Err(err) = e {
+        assert_eq!(err, GitUrlParseError::FilePathUnsupported)
+    }
+}
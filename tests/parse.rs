@@ -1,3 +1,6 @@
+// Scheme printing is asserted against the expected bool, like the other parts
+#![allow(clippy::bool_assert_comparison)]
+
 use git_url_parse::*;
 use log::debug;
 
@@ -35,6 +38,36 @@ fn ssh_no_scheme_no_user() {
     assert_eq!(parsed.print_scheme(), false);
 }
 
+#[test]
+fn ssh_ipv6_host() {
+    let _ = env_logger::try_init();
+    let test_url = "ssh://git@[2001:db8::1]:2222/user/project-name.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    assert_eq!(parsed.to_string(), test_url);
+    assert_eq!(parsed.scheme(), Some("ssh"));
+    assert_eq!(parsed.user(), Some("git"));
+    assert_eq!(parsed.host(), Some("[2001:db8::1]"));
+    assert_eq!(parsed.port(), Some(2222));
+    assert_eq!(parsed.path(), "user/project-name.git");
+    assert_eq!(parsed.print_scheme(), true);
+}
+
+#[test]
+fn ssh_ipv6_host_no_scheme() {
+    let _ = env_logger::try_init();
+    let test_url = "git@[::1]:user/project-name.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    assert_eq!(parsed.to_string(), test_url);
+    assert_eq!(parsed.host(), Some("[::1]"));
+    assert_eq!(parsed.port(), None);
+    assert_eq!(parsed.path(), "user/project-name.git");
+    assert_eq!(parsed.print_scheme(), false);
+}
+
 // Specific service support
 #[test]
 fn https_user_bitbucket() {
@@ -367,3 +400,20 @@ fn short_git() {
     assert_eq!(parsed.path(), "/owner/name.git");
     assert_eq!(parsed.print_scheme(), true);
 }
+
+#[test]
+fn git_scheme() {
+    let _ = env_logger::try_init();
+    let test_url = "git://git.savannah.gnu.org/emacs.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    assert_eq!(parsed.to_string(), test_url);
+    assert_eq!(parsed.scheme(), Some("git"));
+    assert_eq!(parsed.user(), None);
+    assert_eq!(parsed.password(), None);
+    assert_eq!(parsed.host(), Some("git.savannah.gnu.org"));
+    assert_eq!(parsed.port(), None);
+    assert_eq!(parsed.path(), "/emacs.git");
+    assert_eq!(parsed.print_scheme(), true);
+}
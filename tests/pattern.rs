@@ -0,0 +1,368 @@
+use git_url_parse::types::provider::{
+    AnyProvider, Confidence, GitProvider, PathPattern, PatternCaptures, PatternProvider,
+    PatternSet, ProviderKind, ProviderRegistry,
+};
+use git_url_parse::{GitUrl, GitUrlParseError};
+use log::debug;
+use std::sync::LazyLock;
+
+#[test]
+fn path_pattern_captures() {
+    let _ = env_logger::try_init();
+    let pattern: PathPattern = "/{org}/{project}/_git/{repo}"
+        .parse()
+        .expect("Pattern parse failed");
+    debug!("{:#?}", pattern);
+
+    assert_eq!(pattern.to_string(), "/{org}/{project}/_git/{repo}");
+
+    let captures = pattern
+        .captures("/contoso/tools/_git/cli")
+        .expect("Pattern should match");
+    debug!("{:#?}", captures);
+
+    assert_eq!(captures.get("org"), Some("contoso"));
+    assert_eq!(captures.get("project"), Some("tools"));
+    assert_eq!(captures.get("repo"), Some("cli"));
+    assert_eq!(captures.get("missing"), None);
+    assert_eq!(captures.fullname(), "contoso/tools/cli");
+
+    assert!(pattern.captures("/contoso/tools/cli").is_none());
+    assert!(pattern.captures("/contoso/tools/_git/cli/extra").is_none());
+}
+
+#[test]
+fn path_pattern_repeating() {
+    let _ = env_logger::try_init();
+    let pattern: PathPattern = "/{owner}/{subgroup*}/{repo}"
+        .parse()
+        .expect("Pattern parse failed");
+
+    let captures = pattern
+        .captures("/group/sub/deeper/project")
+        .expect("Pattern should match");
+    debug!("{:#?}", captures);
+
+    assert_eq!(captures.get("owner"), Some("group"));
+    assert_eq!(captures.get("subgroup"), None);
+    assert_eq!(
+        captures.segments("subgroup"),
+        Some(&["sub".to_string(), "deeper".to_string()][..])
+    );
+    assert_eq!(captures.get("repo"), Some("project"));
+    assert_eq!(captures.fullname(), "group/sub/deeper/project");
+
+    let captures = pattern
+        .captures("/group/project")
+        .expect("Pattern should match");
+    assert_eq!(captures.segments("subgroup"), Some(&[][..]));
+    assert_eq!(captures.fullname(), "group/project");
+
+    let pattern: PathPattern = "/{owner}/{subgroup+}/{repo}"
+        .parse()
+        .expect("Pattern parse failed");
+    assert!(pattern.captures("/group/project").is_none());
+}
+
+#[test]
+fn path_pattern_optional_literals() {
+    let _ = env_logger::try_init();
+    let pattern: PathPattern = "/[scm]/{project}/{repo}[.git]"
+        .parse()
+        .expect("Pattern parse failed");
+
+    for path in [
+        "/scm/tools/cli.git",
+        "/scm/tools/cli",
+        "/tools/cli.git",
+        "tools/cli",
+    ] {
+        let captures = pattern.captures(path).expect("Pattern should match");
+        debug!("{path}: {:#?}", captures);
+
+        assert_eq!(captures.get("project"), Some("tools"));
+        assert_eq!(captures.get("repo"), Some("cli"));
+    }
+
+    let pattern: PathPattern = "/~{owner}/{repo}.git"
+        .parse()
+        .expect("Pattern parse failed");
+    let captures = pattern
+        .captures("/~jdoe/dotfiles.git")
+        .expect("Pattern should match");
+    assert_eq!(captures.get("owner"), Some("jdoe"));
+    assert_eq!(captures.get("repo"), Some("dotfiles"));
+    assert!(pattern.captures("/jdoe/dotfiles.git").is_none());
+    assert!(pattern.captures("/~jdoe/dotfiles").is_none());
+
+    // Optional literals are matched by backtracking, not by expanding every combination
+    let source = format!("/{{repo}}{}", "[.x]".repeat(64));
+    let pattern: PathPattern = source.parse().expect("Pattern parse failed");
+    let captures = pattern.captures("/cli.x.x").expect("Pattern should match");
+    assert_eq!(captures.get("repo"), Some("cli"));
+}
+
+#[test]
+fn path_pattern_typed() {
+    let _ = env_logger::try_init();
+    let pattern: PathPattern = "/c/{project+}/+/{change:int}"
+        .parse()
+        .expect("Pattern parse failed");
+
+    let captures = pattern
+        .captures("/c/platform/build/+/12345")
+        .expect("Pattern should match");
+    debug!("{:#?}", captures);
+
+    assert_eq!(captures.parse::<u64>("change"), Ok(12345));
+    assert_eq!(
+        captures.parse::<String>("project"),
+        Ok("platform/build".to_string())
+    );
+    assert!(matches!(
+        captures.parse::<u8>("change"),
+        Err(GitUrlParseError::ProviderParseFail(_))
+    ));
+    assert!(matches!(
+        captures.parse::<u64>("missing"),
+        Err(GitUrlParseError::ProviderParseFail(_))
+    ));
+    assert!(pattern.captures("/c/platform/build/+/abc").is_none());
+
+    let pattern: PathPattern = "/commit/{sha:hex}".parse().expect("Pattern parse failed");
+    assert!(pattern.captures("/commit/8f3e2a1").is_some());
+    assert!(pattern.captures("/commit/main").is_none());
+}
+
+#[test]
+fn path_pattern_invalid() {
+    let _ = env_logger::try_init();
+
+    for pattern in [
+        "",
+        "/",
+        "/{owner",
+        "/{owner}}",
+        "/{}",
+        "/{1owner}",
+        "/{owner}/{owner}",
+        "/{owner}{repo}",
+        "/{id:float}",
+        "/{rest*:int}",
+        "/x{rest*}",
+        "/[opt",
+        "/[]",
+        "/[{owner}]",
+    ] {
+        let result: Result<PathPattern, _> = pattern.parse();
+        debug!("{pattern}: {:?}", result);
+
+        assert!(
+            matches!(result, Err(GitUrlParseError::InvalidPathPattern(_))),
+            "{pattern} should be invalid"
+        );
+    }
+}
+
+#[test]
+fn pattern_set_transports() {
+    let _ = env_logger::try_init();
+    let patterns = PatternSet::new(
+        "/scm/{project}/{repo}[.git]"
+            .parse()
+            .expect("Pattern parse failed"),
+    )
+    .with_ssh(
+        "/{project}/{repo}[.git]"
+            .parse()
+            .expect("Pattern parse failed"),
+    );
+
+    let url =
+        GitUrl::parse("https://forge.corp.example/scm/tools/cli.git").expect("URL parse failed");
+    let captures = patterns.parse(&url).expect("Patterns should match");
+    assert_eq!(captures.fullname(), "tools/cli");
+
+    let url = GitUrl::parse("git@forge.corp.example:tools/cli.git").expect("URL parse failed");
+    let captures = patterns.parse(&url).expect("Patterns should match");
+    assert_eq!(captures.fullname(), "tools/cli");
+
+    let url = GitUrl::parse("https://forge.corp.example/tools/cli.git").expect("URL parse failed");
+    assert!(matches!(
+        patterns.parse(&url),
+        Err(GitUrlParseError::ProviderParseFail(_))
+    ));
+
+    let url = GitUrl::parse("file:///scm/tools/cli.git").expect("URL parse failed");
+    assert_eq!(
+        patterns.parse(&url),
+        Err(GitUrlParseError::ProviderUnsupported)
+    );
+}
+
+/// Provider info of an in-house forge, declared with path patterns
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ForgeProvider {
+    /// Project key
+    project: String,
+    /// Repo name
+    repo: String,
+}
+
+/// Path patterns of [`ForgeProvider`]
+static FORGE_PATTERNS: LazyLock<PatternSet> = LazyLock::new(|| {
+    PatternSet::new(
+        "/scm/{project}/{repo}[.git]"
+            .parse()
+            .expect("Pattern is valid"),
+    )
+    .with_ssh("/{project}/{repo}[.git]".parse().expect("Pattern is valid"))
+});
+
+impl PatternProvider for ForgeProvider {
+    fn patterns() -> &'static PatternSet {
+        &FORGE_PATTERNS
+    }
+
+    fn from_captures(captures: &PatternCaptures) -> Result<Self, GitUrlParseError> {
+        Ok(ForgeProvider {
+            project: captures.parse("project")?,
+            repo: captures.parse("repo")?,
+        })
+    }
+}
+
+impl GitProvider<GitUrl, GitUrlParseError> for ForgeProvider {
+    fn from_git_url(url: &GitUrl) -> Result<Self, GitUrlParseError> {
+        Self::from_pattern_git_url(url)
+    }
+}
+
+#[test]
+fn pattern_provider() {
+    let _ = env_logger::try_init();
+
+    for test_url in [
+        "https://forge.corp.example/scm/tools/cli.git",
+        "ssh://git@forge.corp.example:7999/tools/cli.git",
+        "git@forge.corp.example:tools/cli",
+    ] {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider: ForgeProvider = parsed
+            .provider_info()
+            .expect("Failed to parse provider info");
+        debug!("{test_url}: {:#?}", provider);
+
+        assert_eq!(
+            provider,
+            ForgeProvider {
+                project: "tools".to_string(),
+                repo: "cli".to_string(),
+            }
+        );
+    }
+
+    let parsed =
+        GitUrl::parse("https://forge.corp.example/tools/cli.git").expect("URL parse failed");
+    let result: Result<ForgeProvider, _> = parsed.provider_info();
+    assert!(matches!(
+        result,
+        Err(GitUrlParseError::ProviderParseFail(_))
+    ));
+}
+
+#[test]
+fn registry_patterns() {
+    let _ = env_logger::try_init();
+    let mut registry = ProviderRegistry::new();
+    registry
+        .register_patterns(
+            "*.forge.corp.example",
+            PatternSet::new(
+                "/{org}/{project}/_git/{repo}"
+                    .parse()
+                    .expect("Pattern parse failed"),
+            ),
+        )
+        .expect("Register failed");
+
+    let entry = &registry.entries()[0];
+    assert_eq!(entry.provider(), ProviderKind::Pattern);
+    assert!(entry.patterns().is_some());
+
+    let url = GitUrl::parse("https://eu.forge.corp.example/contoso/tools/_git/cli")
+        .expect("URL parse failed");
+    let detected = url.detect_provider_with(&registry).expect("Detect failed");
+    debug!("{:#?}", detected);
+
+    assert_eq!(detected.kind(), ProviderKind::Pattern);
+    assert_eq!(detected.confidence(), Confidence::High);
+    assert_eq!(detected.provider().fullname(), "contoso/tools/cli");
+    let AnyProvider::Pattern(captures) = detected.provider() else {
+        panic!("Pattern provider expected");
+    };
+    assert_eq!(captures.get("org"), Some("contoso"));
+
+    // Paths not matching the patterns fall back to detection from the url shape
+    let url =
+        GitUrl::parse("https://eu.forge.corp.example/owner/repo.git").expect("URL parse failed");
+    let detected = url.detect_provider_with(&registry).expect("Detect failed");
+    assert_eq!(detected.kind(), ProviderKind::Generic);
+    assert_eq!(detected.confidence(), Confidence::Low);
+
+    // Only registry entries hold the path patterns
+    let url = GitUrl::parse("https://eu.forge.corp.example/contoso/tools/_git/cli")
+        .expect("URL parse failed");
+    let provider = entry.parse(&url).expect("Entry parse failed");
+    assert_eq!(provider.fullname(), "contoso/tools/cli");
+    assert_eq!(
+        ProviderKind::Pattern.parse(&url),
+        Err(GitUrlParseError::ProviderUnsupported)
+    );
+    assert!(matches!(
+        registry.register("git.corp.example", ProviderKind::Pattern),
+        Err(GitUrlParseError::ProviderUnsupported)
+    ));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn registry_patterns_from_config() {
+    let _ = env_logger::try_init();
+    let config = r#"
+    {
+        "hosts": [
+            {
+                "host": "forge.corp.example",
+                "provider": "pattern",
+                "patterns": { "ssh": "/{project}/{repo}[.git]", "https": "/scm/{project}/{repo}[.git]" }
+            }
+        ]
+    }
+    "#;
+
+    let registry: ProviderRegistry = serde_json::from_str(config).expect("Config parse failed");
+    debug!("{:#?}", registry);
+
+    let mut expected = ProviderRegistry::new();
+    expected
+        .register_patterns(
+            "forge.corp.example",
+            PatternSet::new(
+                "/scm/{project}/{repo}[.git]"
+                    .parse()
+                    .expect("Pattern parse failed"),
+            )
+            .with_ssh(
+                "/{project}/{repo}[.git]"
+                    .parse()
+                    .expect("Pattern parse failed"),
+            ),
+        )
+        .unwrap();
+
+    assert_eq!(registry, expected);
+
+    let invalid = r#"{ "hosts": [ { "host": "forge.corp.example", "provider": "pattern", "patterns": { "ssh": "/{repo", "https": "/{repo}" } } ] }"#;
+    assert!(serde_json::from_str::<ProviderRegistry>(invalid).is_err());
+}
@@ -1,5 +1,10 @@
 use git_url_parse::types::provider::{
-    AzureDevOpsProvider, GenericProvider, GitLabProvider, GitProvider,
+    AnyProvider, AzureDevOpsHost, AzureDevOpsProvider, BitbucketCloudProvider,
+    BitbucketServerProvider, CgitFlavor, CgitProvider, CodeCommitEndpoint, CodeCommitProvider,
+    Confidence, GenericProvider, GerritProvider, GitHubProvider, GitHubRepoKind, GitLabProvider,
+    GitLabRepoKind, GitProvider, GiteaProvider, GiteaRepoKind, GoogleSourceProvider,
+    GoogleSourceService, HuggingFaceProvider, HuggingFaceRepoType, LaunchpadProvider,
+    PagureProvider, ProviderKind, SourceHutProvider, SourceHutVcs,
 };
 use git_url_parse::{GitUrl, GitUrlParseError};
 use log::debug;
@@ -243,3 +248,1688 @@ fn filepath() {
         assert_eq!(e, GitUrlParseError::ProviderUnsupported)
     }
 }
+
+#[test]
+fn http_github() {
+    let _ = env_logger::try_init();
+    let test_url = "https://github.com/tjtelan/git-url-parse-rs.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let provider_info: GitHubProvider = parsed.provider_info().unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.owner(), Some("tjtelan"));
+    assert_eq!(provider_info.repo(), "git-url-parse-rs");
+    assert_eq!(provider_info.kind(), GitHubRepoKind::Repository);
+    assert_eq!(provider_info.fullname(), "tjtelan/git-url-parse-rs");
+}
+
+#[test]
+fn ssh_github_port_443() {
+    let _ = env_logger::try_init();
+    let test_url = "ssh://git@ssh.github.com:443/tjtelan/git-url-parse-rs.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let provider_info: GitHubProvider = parsed.provider_info().unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.owner(), Some("tjtelan"));
+    assert_eq!(provider_info.repo(), "git-url-parse-rs");
+    assert_eq!(provider_info.kind(), GitHubRepoKind::Repository);
+}
+
+#[test]
+fn github_wiki() {
+    let _ = env_logger::try_init();
+    for test_url in [
+        "https://github.com/tjtelan/git-url-parse-rs.wiki.git",
+        "git@github.com:tjtelan/git-url-parse-rs.wiki.git",
+    ] {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: GitHubProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.owner(), Some("tjtelan"));
+        assert_eq!(provider_info.repo(), "git-url-parse-rs");
+        assert_eq!(provider_info.kind(), GitHubRepoKind::Wiki);
+    }
+}
+
+#[test]
+fn github_gist() {
+    let _ = env_logger::try_init();
+    let cases = [
+        ("https://gist.github.com/aa5a315d61ae9438b18d.git", None),
+        ("git@gist.github.com:aa5a315d61ae9438b18d.git", None),
+        (
+            "https://gist.github.com/tjtelan/aa5a315d61ae9438b18d",
+            Some("tjtelan"),
+        ),
+        (
+            "https://ghe.corp.example/gist/aa5a315d61ae9438b18d.git",
+            None,
+        ),
+    ];
+
+    for (test_url, owner) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: GitHubProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.owner(), owner);
+        assert_eq!(provider_info.repo(), "aa5a315d61ae9438b18d");
+        assert_eq!(provider_info.kind(), GitHubRepoKind::Gist);
+    }
+
+    // Only GitHub Enterprise Server serves gists under `/gist`
+    let parsed = GitUrl::parse("https://github.com/gist/abc").expect("URL parse failed");
+    let provider_info: GitHubProvider = parsed.provider_info().unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.fullname(), "gist/abc");
+    assert_eq!(provider_info.kind(), GitHubRepoKind::Repository);
+}
+
+#[test]
+fn github_enterprise() {
+    let _ = env_logger::try_init();
+    for test_url in [
+        "https://ghe.corp.example/owner/repo.git",
+        "git@ghe.corp.example:owner/repo.git",
+        "https://ghe.corp.example/api/v3/repos/owner/repo",
+        "https://ghe.corp.example/raw/owner/repo/main/README.md",
+        "https://api.github.com/repos/owner/repo",
+        "https://raw.githubusercontent.com/owner/repo/main/README.md",
+    ] {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: GitHubProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.fullname(), "owner/repo");
+        assert_eq!(provider_info.kind(), GitHubRepoKind::Repository);
+    }
+}
+
+#[test]
+fn github_invalid_names() {
+    let _ = env_logger::try_init();
+    for test_url in [
+        "https://github.com/-owner/repo.git",
+        "https://github.com/own_er/repo.git",
+        "https://github.com/aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa/repo.git",
+        "https://github.com/owner/re~po.git",
+        "https://github.com/owner/...git",
+    ] {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: Result<GitHubProvider, GitUrlParseError> = parsed.provider_info();
+        debug!("{:#?}", provider_info);
+
+        assert!(matches!(
+            provider_info,
+            Err(GitUrlParseError::ProviderInvalidName(_))
+        ));
+    }
+}
+
+#[test]
+fn github_nested_path() {
+    let _ = env_logger::try_init();
+    let test_url = "https://github.com/owner/group/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    let provider_info: Result<GitHubProvider, GitUrlParseError> = parsed.provider_info();
+    debug!("{:#?}", provider_info);
+
+    assert!(matches!(
+        provider_info,
+        Err(GitUrlParseError::ProviderParseFail(_))
+    ));
+}
+
+#[test]
+fn detect_known_host() {
+    let _ = env_logger::try_init();
+    let cases = [
+        (
+            "https://github.com/tjtelan/git-url-parse-rs.git",
+            ProviderKind::GitHub,
+        ),
+        ("git@gitlab.com:gitlab-org/gitlab.git", ProviderKind::GitLab),
+        (
+            "git@bitbucket.org:workspace/repo.git",
+            ProviderKind::BitbucketCloud,
+        ),
+        ("https://codeberg.org/owner/repo.git", ProviderKind::Gitea),
+        ("git@git.sr.ht:~user/repo", ProviderKind::SourceHut),
+        (
+            "https://git-codecommit.us-east-1.amazonaws.com/v1/repos/MyRepo",
+            ProviderKind::CodeCommit,
+        ),
+        ("codecommit::us-east-1://MyRepo", ProviderKind::CodeCommit),
+        (
+            "https://android.googlesource.com/platform/build/soong",
+            ProviderKind::Gerrit,
+        ),
+        (
+            "https://huggingface.co/datasets/stanfordnlp/imdb",
+            ProviderKind::HuggingFace,
+        ),
+        ("git@hf.co:openai-community/gpt2", ProviderKind::HuggingFace),
+        (
+            "https://git.kernel.org/pub/scm/git/git.git",
+            ProviderKind::Cgit,
+        ),
+        (
+            "ssh://jdoe@source.developers.google.com:2022/p/my-project/r/my-repo",
+            ProviderKind::GoogleSource,
+        ),
+        (
+            "https://prod-123456789-git.us-central1.sourcemanager.dev/my-project/my-repo.git",
+            ProviderKind::GoogleSource,
+        ),
+        (
+            "https://CompanyName@dev.azure.com/CompanyName/ProjectName/_git/RepoName",
+            ProviderKind::AzureDevOps,
+        ),
+        (
+            "git@ssh.dev.azure.com:v3/CompanyName/ProjectName/RepoName.git",
+            ProviderKind::AzureDevOps,
+        ),
+    ];
+
+    for (test_url, kind) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let detected = parsed.detect_provider().expect("Provider detect failed");
+        debug!("{:#?}", detected);
+
+        assert_eq!(detected.kind(), kind);
+        assert_eq!(detected.confidence(), Confidence::High);
+    }
+}
+
+#[test]
+fn detect_gitlab_host_naming() {
+    let _ = env_logger::try_init();
+    let test_url = "https://gitlab.example.com/group/subgroup/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let detected = parsed.detect_provider().expect("Provider detect failed");
+    debug!("{:#?}", detected);
+
+    assert_eq!(detected.confidence(), Confidence::Medium);
+
+    let expected: GitLabProvider = parsed.provider_info().unwrap();
+    assert_eq!(detected.into_provider(), AnyProvider::GitLab(expected));
+}
+
+#[test]
+fn detect_azure_devops_path_shape() {
+    let _ = env_logger::try_init();
+    let test_url = "https://git.example.com/org/project/_git/repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let detected = parsed.detect_provider().expect("Provider detect failed");
+    debug!("{:#?}", detected);
+
+    assert_eq!(detected.kind(), ProviderKind::AzureDevOps);
+    assert_eq!(detected.confidence(), Confidence::Medium);
+    assert_eq!(detected.provider().fullname(), "org/project/repo");
+}
+
+#[test]
+fn detect_nested_groups() {
+    let _ = env_logger::try_init();
+    let test_url = "https://git.example.com/group/subgroup/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let detected = parsed.detect_provider().expect("Provider detect failed");
+    debug!("{:#?}", detected);
+
+    assert_eq!(detected.kind(), ProviderKind::GitLab);
+    assert_eq!(detected.confidence(), Confidence::Low);
+}
+
+#[test]
+fn detect_fallback_generic() {
+    let _ = env_logger::try_init();
+    let test_url = "http://git.example.com:3000/user/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let detected = parsed.detect_provider().expect("Provider detect failed");
+    debug!("{:#?}", detected);
+
+    assert_eq!(detected.confidence(), Confidence::Low);
+
+    let expected: GenericProvider = parsed.provider_info().unwrap();
+    assert_eq!(detected.into_provider(), AnyProvider::Generic(expected));
+}
+
+#[test]
+fn detect_filepath() {
+    let _ = env_logger::try_init();
+    let test_url = "file:///home/user/Documents/";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    let detected = parsed.detect_provider();
+    debug!("{:#?}", detected);
+
+    assert!(detected.is_err());
+    if let Err(e) = detected {
+        assert_eq!(e, GitUrlParseError::ProviderUnsupported)
+    }
+}
+
+#[test]
+fn detect_github() {
+    let _ = env_logger::try_init();
+    let cases = [
+        (
+            "https://gist.github.com/aa5a315d61ae9438b18d.git",
+            Confidence::High,
+        ),
+        (
+            "https://github.corp.example/owner/repo.git",
+            Confidence::Medium,
+        ),
+        (
+            "https://ghe.corp.example/api/v3/repos/owner/repo",
+            Confidence::Medium,
+        ),
+    ];
+
+    for (test_url, confidence) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let detected = parsed.detect_provider().expect("Provider detect failed");
+        debug!("{:#?}", detected);
+
+        assert_eq!(detected.kind(), ProviderKind::GitHub);
+        assert_eq!(detected.confidence(), confidence);
+    }
+}
+
+#[test]
+fn http_bitbucket_cloud() {
+    let _ = env_logger::try_init();
+    let test_url = "https://user@bitbucket.org/workspace/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider_info: BitbucketCloudProvider = parsed.provider_info().unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.workspace(), "workspace");
+    assert_eq!(provider_info.repo(), "repo");
+    assert_eq!(provider_info.fullname(), "workspace/repo");
+}
+
+#[test]
+fn ssh_bitbucket_cloud() {
+    let _ = env_logger::try_init();
+    let test_url = "git@bitbucket.org:workspace/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider_info: BitbucketCloudProvider = parsed.provider_info().unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.workspace(), "workspace");
+    assert_eq!(provider_info.repo(), "repo");
+}
+
+#[test]
+fn http_bitbucket_server() {
+    let _ = env_logger::try_init();
+    let cases = [
+        "https://bitbucket.corp.example/scm/PROJ/repo.git",
+        "https://corp.example/bitbucket/scm/PROJ/repo.git",
+        "https://bitbucket.corp.example/projects/PROJ/repos/repo/browse",
+    ];
+
+    for test_url in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: BitbucketServerProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.project_key(), "PROJ");
+        assert_eq!(provider_info.repo(), "repo");
+        assert!(!provider_info.personal());
+    }
+
+    let parsed = GitUrl::parse("https://corp.example/bitbucket/scm/PROJ/repo.git")
+        .expect("URL parse failed");
+    let provider_info: BitbucketServerProvider = parsed.provider_info().unwrap();
+    assert_eq!(provider_info.base_path(), Some("bitbucket"));
+}
+
+#[test]
+fn ssh_bitbucket_server() {
+    let _ = env_logger::try_init();
+    let test_url = "ssh://git@bitbucket.corp.example:7999/proj/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider_info: BitbucketServerProvider = parsed.provider_info().unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.project_key(), "proj");
+    assert_eq!(provider_info.repo(), "repo");
+    assert_eq!(provider_info.fullname(), "proj/repo");
+}
+
+#[test]
+fn bitbucket_server_personal() {
+    let _ = env_logger::try_init();
+    let cases = [
+        "https://bitbucket.corp.example/scm/~jdoe/repo.git",
+        "ssh://git@bitbucket.corp.example:7999/~jdoe/repo.git",
+        "https://bitbucket.corp.example/users/jdoe/repos/repo/browse",
+    ];
+
+    for test_url in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: BitbucketServerProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.project_key(), "~jdoe");
+        assert_eq!(provider_info.repo(), "repo");
+        assert!(provider_info.personal());
+    }
+}
+
+#[test]
+fn detect_bitbucket_server() {
+    let _ = env_logger::try_init();
+    let cases = [
+        "https://git.corp.example/scm/PROJ/repo.git",
+        "ssh://git@git.corp.example:7999/proj/repo.git",
+        "https://bitbucket.corp.example/projects/PROJ/repos/repo/browse",
+    ];
+
+    for test_url in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let detected = parsed.detect_provider().expect("Provider detect failed");
+        debug!("{:#?}", detected);
+
+        assert_eq!(detected.kind(), ProviderKind::BitbucketServer);
+        assert_eq!(detected.confidence(), Confidence::Medium);
+    }
+}
+
+#[test]
+fn http_gitea() {
+    let _ = env_logger::try_init();
+    let test_url = "https://codeberg.org/forgejo/forgejo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider_info: GiteaProvider = parsed.provider_info().unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.base_path(), None);
+    assert_eq!(provider_info.owner(), "forgejo");
+    assert_eq!(provider_info.repo(), "forgejo");
+    assert_eq!(provider_info.kind(), GiteaRepoKind::Repository);
+    assert_eq!(provider_info.fullname(), "forgejo/forgejo");
+}
+
+#[test]
+fn ssh_gitea_wiki() {
+    let _ = env_logger::try_init();
+    let test_url = "git@codeberg.org:owner/repo.wiki.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider_info: GiteaProvider = parsed.provider_info().unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.owner(), "owner");
+    assert_eq!(provider_info.repo(), "repo");
+    assert_eq!(provider_info.kind(), GiteaRepoKind::Wiki);
+}
+
+#[test]
+fn gitea_sub_url() {
+    let _ = env_logger::try_init();
+    let test_url = "https://git.example.com/apps/gitea/owner/repo.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider_info: GiteaProvider = parsed.provider_info().unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.base_path(), Some("apps/gitea"));
+    assert_eq!(provider_info.fullname(), "owner/repo");
+}
+
+#[test]
+fn gitea_web_urls() {
+    let _ = env_logger::try_init();
+    let cases = [
+        (
+            "https://codeberg.org/owner/repo/src/branch/main/src/lib.rs",
+            None,
+        ),
+        ("https://codeberg.org/owner/repo/src/tag/v1.0.0", None),
+        (
+            "https://codeberg.org/owner/repo/raw/commit/0a1b2c3d/README.md",
+            None,
+        ),
+        (
+            "https://codeberg.org/owner/repo/media/branch/main/logo.png",
+            None,
+        ),
+        (
+            "https://git.example.com/gitea/owner/repo/src/branch/main/docs",
+            Some("gitea"),
+        ),
+    ];
+
+    for (test_url, base_path) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: GiteaProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.base_path(), base_path);
+        assert_eq!(provider_info.owner(), "owner");
+        assert_eq!(provider_info.repo(), "repo");
+        assert_eq!(provider_info.kind(), GiteaRepoKind::Repository);
+    }
+
+    let wiki = GitUrl::parse("https://codeberg.org/owner/repo/wiki/Home").unwrap();
+    let provider_info: GiteaProvider = wiki.provider_info().unwrap();
+    assert_eq!(provider_info.kind(), GiteaRepoKind::Wiki);
+}
+
+#[test]
+fn detect_gitea() {
+    let _ = env_logger::try_init();
+    let cases = [
+        "https://forgejo.example.com/owner/repo.git",
+        "https://git.example.com/owner/repo/src/branch/main/README.md",
+    ];
+
+    for test_url in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let detected = parsed.detect_provider().expect("Provider detect failed");
+        debug!("{:#?}", detected);
+
+        assert_eq!(detected.kind(), ProviderKind::Gitea);
+        assert_eq!(detected.confidence(), Confidence::Medium);
+    }
+}
+
+#[test]
+fn http_sourcehut() {
+    let _ = env_logger::try_init();
+    let test_url = "https://git.sr.ht/~sircmpwn/scdoc";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider_info: SourceHutProvider = parsed.provider_info().unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.host(), "git.sr.ht");
+    assert_eq!(provider_info.owner(), "~sircmpwn");
+    assert_eq!(provider_info.username(), "sircmpwn");
+    assert_eq!(provider_info.repo(), "scdoc");
+    assert_eq!(provider_info.vcs(), SourceHutVcs::Git);
+    assert_eq!(provider_info.fullname(), "~sircmpwn/scdoc");
+}
+
+#[test]
+fn ssh_sourcehut() {
+    let _ = env_logger::try_init();
+    let test_url = "git@git.sr.ht:~sircmpwn/scdoc";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider_info: SourceHutProvider = parsed.provider_info().unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.owner(), "~sircmpwn");
+    assert_eq!(provider_info.repo(), "scdoc");
+
+    let generic: GenericProvider = parsed.provider_info().unwrap();
+    assert_eq!(generic.owner(), "~sircmpwn");
+}
+
+#[test]
+fn sourcehut_hg_urls() {
+    let _ = env_logger::try_init();
+    let test_url = "https://hg.sr.ht/~user/repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider_info: SourceHutProvider = parsed.provider_info().unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.vcs(), SourceHutVcs::Hg);
+    assert_eq!(provider_info.browse_url(), "https://hg.sr.ht/~user/repo");
+    assert_eq!(
+        provider_info.tree_url("tip", Some("/src/main.c")),
+        "https://hg.sr.ht/~user/repo/browse/src/main.c?rev=tip"
+    );
+    assert_eq!(
+        provider_info.log_url("tip"),
+        "https://hg.sr.ht/~user/repo/log?rev=tip"
+    );
+    assert_eq!(
+        provider_info.tree_url("a b#1", Some("docs/read me?.md")),
+        "https://hg.sr.ht/~user/repo/browse/docs/read%20me%3F.md?rev=a%20b%231"
+    );
+}
+
+#[test]
+fn sourcehut_git_urls() {
+    let _ = env_logger::try_init();
+    let test_url = "https://git.sr.ht/~user/repo/tree/main/item/src";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider_info: SourceHutProvider = parsed.provider_info().unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.fullname(), "~user/repo");
+    assert_eq!(provider_info.browse_url(), "https://git.sr.ht/~user/repo");
+    assert_eq!(
+        provider_info.tree_url("main", None),
+        "https://git.sr.ht/~user/repo/tree/main"
+    );
+    assert_eq!(
+        provider_info.log_url("main"),
+        "https://git.sr.ht/~user/repo/log/main"
+    );
+    assert_eq!(
+        provider_info.tree_url("feature/a#1", Some("docs/read me?.md")),
+        "https://git.sr.ht/~user/repo/tree/feature/a%231/item/docs/read%20me%3F.md"
+    );
+    assert_eq!(
+        provider_info.log_url("v1.0 rc?"),
+        "https://git.sr.ht/~user/repo/log/v1.0%20rc%3F"
+    );
+}
+
+#[test]
+fn sourcehut_non_vcs_service() {
+    let _ = env_logger::try_init();
+    let test_url = "https://todo.sr.ht/~user/tracker";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+
+    let provider_info: Result<SourceHutProvider, GitUrlParseError> = parsed.provider_info();
+    debug!("{:#?}", provider_info);
+
+    assert!(matches!(
+        provider_info,
+        Err(GitUrlParseError::ProviderParseFail(_))
+    ));
+}
+
+#[test]
+fn detect_sourcehut_self_hosted() {
+    let _ = env_logger::try_init();
+    let test_url = "https://git.example.org/~user/repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let detected = parsed.detect_provider().expect("Provider detect failed");
+    debug!("{:#?}", detected);
+
+    assert_eq!(detected.kind(), ProviderKind::SourceHut);
+    assert_eq!(detected.confidence(), Confidence::Medium);
+}
+
+#[test]
+fn http_codecommit() {
+    let _ = env_logger::try_init();
+    let cases = [
+        (
+            "https://git-codecommit.us-east-2.amazonaws.com/v1/repos/MyDemoRepo",
+            "us-east-2",
+            CodeCommitEndpoint::Standard,
+        ),
+        (
+            "https://git-codecommit-fips.us-gov-west-1.amazonaws.com/v1/repos/MyDemoRepo",
+            "us-gov-west-1",
+            CodeCommitEndpoint::Fips,
+        ),
+        (
+            "https://git-codecommit.cn-north-1.amazonaws.com.cn/v1/repos/MyDemoRepo",
+            "cn-north-1",
+            CodeCommitEndpoint::China,
+        ),
+    ];
+
+    for (test_url, region, endpoint) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: CodeCommitProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.region(), Some(region));
+        assert_eq!(provider_info.repo(), "MyDemoRepo");
+        assert_eq!(provider_info.profile(), None);
+        assert_eq!(provider_info.endpoint(), endpoint);
+        assert_eq!(provider_info.https_url().as_deref(), Some(test_url));
+    }
+}
+
+#[test]
+fn ssh_codecommit() {
+    let _ = env_logger::try_init();
+    let test_url =
+        "ssh://APKAEIBAERJR2EXAMPLE@git-codecommit.us-east-2.amazonaws.com/v1/repos/MyDemoRepo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider_info: CodeCommitProvider = parsed.provider_info().unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.region(), Some("us-east-2"));
+    assert_eq!(provider_info.repo(), "MyDemoRepo");
+    assert_eq!(provider_info.fullname(), "us-east-2/MyDemoRepo");
+    assert_eq!(
+        provider_info
+            .ssh_url(Some("APKAEIBAERJR2EXAMPLE"))
+            .as_deref(),
+        Some(test_url)
+    );
+    assert_eq!(
+        provider_info.helper_url(),
+        "codecommit::us-east-2://MyDemoRepo"
+    );
+}
+
+#[test]
+fn codecommit_helper_url() {
+    let _ = env_logger::try_init();
+    let cases = [
+        (
+            "codecommit::us-east-1://dev@MyDemoRepo",
+            Some("us-east-1"),
+            Some("dev"),
+        ),
+        (
+            "codecommit::us-east-1://MyDemoRepo",
+            Some("us-east-1"),
+            None,
+        ),
+        ("codecommit://dev@MyDemoRepo", None, Some("dev")),
+        ("codecommit://MyDemoRepo", None, None),
+    ];
+
+    for (test_url, region, profile) in cases {
+        let provider_info =
+            CodeCommitProvider::from_helper_url(test_url).expect("Helper url parse failed");
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.region(), region);
+        assert_eq!(provider_info.profile(), profile);
+        assert_eq!(provider_info.repo(), "MyDemoRepo");
+        assert_eq!(provider_info.helper_url(), test_url);
+    }
+
+    let no_region = CodeCommitProvider::from_helper_url("codecommit://MyDemoRepo").unwrap();
+    assert_eq!(no_region.https_url(), None);
+
+    let china = CodeCommitProvider::from_helper_url("codecommit::cn-north-1://MyDemoRepo").unwrap();
+    assert_eq!(china.endpoint(), CodeCommitEndpoint::China);
+    assert_eq!(
+        china.https_url().as_deref(),
+        Some("https://git-codecommit.cn-north-1.amazonaws.com.cn/v1/repos/MyDemoRepo")
+    );
+}
+
+#[test]
+fn codecommit_helper_from_git_url() {
+    let _ = env_logger::try_init();
+    let test_url = "codecommit::eu-west-1://dev@MyDemoRepo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider_info: CodeCommitProvider = parsed.provider_info().unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.region(), Some("eu-west-1"));
+    assert_eq!(provider_info.profile(), Some("dev"));
+    assert_eq!(provider_info.repo(), "MyDemoRepo");
+}
+
+#[test]
+fn codecommit_invalid() {
+    let _ = env_logger::try_init();
+    let cases = [
+        "https://s3.us-east-1.amazonaws.com/v1/repos/MyDemoRepo",
+        "https://git-codecommit.us-east-1.amazonaws.com/repos/MyDemoRepo",
+    ];
+
+    for test_url in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: Result<CodeCommitProvider, GitUrlParseError> = parsed.provider_info();
+        debug!("{:#?}", provider_info);
+
+        assert!(matches!(
+            provider_info,
+            Err(GitUrlParseError::ProviderParseFail(_))
+        ));
+    }
+
+    let bad_helper = CodeCommitProvider::from_helper_url("codecommit:us-east-1://MyDemoRepo");
+    assert!(matches!(
+        bad_helper,
+        Err(GitUrlParseError::ProviderParseFail(_))
+    ));
+}
+
+#[test]
+fn ssh_gerrit() {
+    let _ = env_logger::try_init();
+    let test_url = "ssh://jdoe@review.example.com:29418/platform/build/soong";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider_info: GerritProvider = parsed.provider_info().unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.host(), "review.example.com");
+    assert_eq!(provider_info.project(), "platform/build/soong");
+    assert_eq!(provider_info.fullname(), "platform/build/soong");
+    assert_eq!(provider_info.ssh_port(), Some(29418));
+    assert!(!provider_info.authenticated());
+}
+
+#[test]
+fn http_gerrit() {
+    let _ = env_logger::try_init();
+    let cases = [
+        ("https://review.example.com/platform/build/soong", false),
+        ("https://review.example.com/platform/build/soong.git", false),
+        ("https://review.example.com/a/platform/build/soong", true),
+        (
+            "https://review.example.com/c/platform/build/soong/+/12345",
+            false,
+        ),
+    ];
+
+    for (test_url, authenticated) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: GerritProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.project(), "platform/build/soong");
+        assert_eq!(provider_info.authenticated(), authenticated);
+        assert_eq!(provider_info.ssh_port(), None);
+    }
+}
+
+#[test]
+fn gerrit_change_refs() {
+    let _ = env_logger::try_init();
+    let test_url = "https://review.example.com/a/project";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider_info: GerritProvider = parsed.provider_info().unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(
+        provider_info.change_url(7),
+        "https://review.example.com/c/project/+/7"
+    );
+    assert_eq!(GerritProvider::change_ref(7, 1), "refs/changes/07/7/1");
+    assert_eq!(
+        GerritProvider::change_ref(123456, 12),
+        "refs/changes/56/123456/12"
+    );
+    assert_eq!(GerritProvider::push_ref("main"), "refs/for/main");
+    assert_eq!(
+        GerritProvider::push_ref("refs/heads/stable/1.0"),
+        "refs/for/stable/1.0"
+    );
+}
+
+#[test]
+fn detect_gerrit() {
+    let _ = env_logger::try_init();
+    let cases = [
+        "ssh://jdoe@git.example.com:29418/platform/build/soong",
+        "https://gerrit.example.com/project",
+        "https://git.example.com/c/project/+/12345",
+    ];
+
+    for test_url in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let detected = parsed.detect_provider().expect("Provider detect failed");
+        debug!("{:#?}", detected);
+
+        assert_eq!(detected.kind(), ProviderKind::Gerrit);
+        assert_eq!(detected.confidence(), Confidence::Medium);
+    }
+}
+
+#[test]
+fn azure_devops_visualstudio() {
+    let _ = env_logger::try_init();
+    let cases = [
+        (
+            "https://contoso.visualstudio.com/DefaultCollection/Fabrikam/_git/Fiber",
+            Some("DefaultCollection"),
+        ),
+        ("https://contoso.visualstudio.com/Fabrikam/_git/Fiber", None),
+        (
+            "ssh://contoso@contoso.visualstudio.com:22/DefaultCollection/Fabrikam/_ssh/Fiber",
+            Some("DefaultCollection"),
+        ),
+        (
+            "contoso@vs-ssh.visualstudio.com:v3/contoso/Fabrikam/Fiber",
+            None,
+        ),
+    ];
+
+    for (test_url, collection) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: AzureDevOpsProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.host_kind(), AzureDevOpsHost::VisualStudio);
+        assert_eq!(provider_info.org(), "contoso");
+        assert_eq!(provider_info.project(), "Fabrikam");
+        assert_eq!(provider_info.repo(), "Fiber");
+        assert_eq!(provider_info.collection(), collection);
+        assert_eq!(
+            provider_info.canonical_https_url().as_deref(),
+            Some("https://dev.azure.com/contoso/Fabrikam/_git/Fiber")
+        );
+        assert_eq!(
+            provider_info.canonical_ssh_url().as_deref(),
+            Some("git@ssh.dev.azure.com:v3/contoso/Fabrikam/Fiber")
+        );
+    }
+}
+
+#[test]
+fn azure_devops_server() {
+    let _ = env_logger::try_init();
+    let cases = [
+        "https://tfs.corp.example/tfs/Collection/Project/_git/Repo",
+        "https://tfs.corp.example/Collection/Project/_git/Repo",
+        "ssh://tfs.corp.example:22/tfs/Collection/Project/_ssh/Repo",
+    ];
+
+    for test_url in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: AzureDevOpsProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.host_kind(), AzureDevOpsHost::Server);
+        assert_eq!(provider_info.org(), "Collection");
+        assert_eq!(provider_info.collection(), Some("Collection"));
+        assert_eq!(provider_info.project(), "Project");
+        assert_eq!(provider_info.repo(), "Repo");
+        assert_eq!(provider_info.canonical_https_url(), None);
+    }
+
+    let parsed = GitUrl::parse("https://tfs.corp.example/tfs/Collection/Project/_git/Repo")
+        .expect("URL parse failed");
+    let provider_info: AzureDevOpsProvider = parsed.provider_info().unwrap();
+    assert_eq!(provider_info.base_path(), Some("tfs"));
+}
+
+#[test]
+fn azure_devops_optimized_and_project_less() {
+    let _ = env_logger::try_init();
+    let cases = [
+        (
+            "https://dev.azure.com/org/project/_git/_optimized/repo",
+            "project",
+        ),
+        (
+            "https://dev.azure.com/org/project/_git/_full/repo",
+            "project",
+        ),
+        ("https://dev.azure.com/org/_git/repo", "repo"),
+        ("https://dev.azure.com/org/_git/_optimized/repo", "repo"),
+    ];
+
+    for (test_url, project) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: AzureDevOpsProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.host_kind(), AzureDevOpsHost::DevAzure);
+        assert_eq!(provider_info.org(), "org");
+        assert_eq!(provider_info.project(), project);
+        assert_eq!(provider_info.repo(), "repo");
+        assert_eq!(provider_info.collection(), None);
+    }
+}
+
+#[test]
+fn gitlab_web_urls() {
+    let _ = env_logger::try_init();
+    let cases = [
+        "https://gitlab.com/group/sub/repo/-/tree/main/src",
+        "https://gitlab.com/group/sub/repo/-/blob/v1.2/docs/index.md#L5",
+        "https://gitlab.com/group/sub/repo/-/merge_requests/42",
+    ];
+
+    for test_url in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: GitLabProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.owner(), "group");
+        assert_eq!(provider_info.subgroup(), Some(vec!["sub"]));
+        assert_eq!(provider_info.repo(), "repo");
+        assert_eq!(provider_info.kind(), GitLabRepoKind::Project);
+    }
+}
+
+#[test]
+fn gitlab_wiki_and_design() {
+    let _ = env_logger::try_init();
+    let cases = [
+        (
+            "https://gitlab.com/group/sub/repo.wiki.git",
+            GitLabRepoKind::Wiki,
+        ),
+        (
+            "git@gitlab.com:group/sub/repo.wiki.git",
+            GitLabRepoKind::Wiki,
+        ),
+        (
+            "https://gitlab.com/group/sub/repo/-/wikis/home",
+            GitLabRepoKind::Wiki,
+        ),
+        (
+            "https://gitlab.com/group/sub/repo.design.git",
+            GitLabRepoKind::Design,
+        ),
+    ];
+
+    for (test_url, kind) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: GitLabProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.fullname(), "group/sub/repo");
+        assert_eq!(provider_info.kind(), kind);
+    }
+}
+
+#[test]
+fn gitlab_snippets() {
+    let _ = env_logger::try_init();
+    let cases = [
+        "https://gitlab.com/group/repo/snippets/1234.git",
+        "https://gitlab.com/group/repo/-/snippets/1234",
+    ];
+
+    for test_url in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: GitLabProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.kind(), GitLabRepoKind::Snippet);
+        assert_eq!(provider_info.snippet_id(), Some("1234"));
+        assert_eq!(provider_info.fullname(), "group/repo");
+    }
+
+    let cases = [
+        "https://gitlab.com/snippets/1234.git",
+        "https://gitlab.com/-/snippets/1234",
+    ];
+
+    for test_url in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: GitLabProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.kind(), GitLabRepoKind::Snippet);
+        assert_eq!(provider_info.snippet_id(), Some("1234"));
+        assert_eq!(provider_info.owner(), "");
+        assert_eq!(provider_info.fullname(), "snippets/1234");
+    }
+}
+
+#[test]
+fn gitlab_reserved_names() {
+    let _ = env_logger::try_init();
+    let cases = [
+        "https://gitlab.com/admin/repo.git",
+        "https://gitlab.com/group/sub/tree.git",
+        "https://gitlab.com/Users/repo.git",
+    ];
+
+    for test_url in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: Result<GitLabProvider, GitUrlParseError> = parsed.provider_info();
+        debug!("{:#?}", provider_info);
+
+        assert!(matches!(
+            provider_info,
+            Err(GitUrlParseError::ProviderReservedName(_))
+        ));
+    }
+}
+
+#[test]
+fn gitlab_invalid_names() {
+    let _ = env_logger::try_init();
+    let cases = [
+        "https://gitlab.com/-group/repo.git",
+        "https://gitlab.com/group/.repo.git",
+        "https://gitlab.com/group/sub~/repo.git",
+        "https://gitlab.com/group/repo.atom",
+    ];
+
+    for test_url in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: Result<GitLabProvider, GitUrlParseError> = parsed.provider_info();
+        debug!("{:#?}", provider_info);
+
+        assert!(matches!(
+            provider_info,
+            Err(GitUrlParseError::ProviderInvalidName(_))
+        ));
+    }
+}
+
+#[test]
+fn detect_gitlab_web_url() {
+    let _ = env_logger::try_init();
+    let test_url = "https://git.example.com/group/repo/-/tree/main";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let detected = parsed.detect_provider().expect("Provider detect failed");
+    debug!("{:#?}", detected);
+
+    assert_eq!(detected.kind(), ProviderKind::GitLab);
+    assert_eq!(detected.confidence(), Confidence::Medium);
+    assert_eq!(detected.provider().fullname(), "group/repo");
+}
+
+#[test]
+fn huggingface_repo_types() {
+    let _ = env_logger::try_init();
+    let cases = [
+        (
+            "https://huggingface.co/openai-community/gpt2",
+            HuggingFaceRepoType::Model,
+            Some("openai-community"),
+            "gpt2",
+        ),
+        (
+            "https://huggingface.co/datasets/stanfordnlp/imdb.git",
+            HuggingFaceRepoType::Dataset,
+            Some("stanfordnlp"),
+            "imdb",
+        ),
+        (
+            "https://huggingface.co/spaces/gradio/hello_world",
+            HuggingFaceRepoType::Space,
+            Some("gradio"),
+            "hello_world",
+        ),
+        (
+            "git@hf.co:datasets/stanfordnlp/imdb",
+            HuggingFaceRepoType::Dataset,
+            Some("stanfordnlp"),
+            "imdb",
+        ),
+        (
+            "https://huggingface.co/openai-community/gpt2/blob/main/config.json",
+            HuggingFaceRepoType::Model,
+            Some("openai-community"),
+            "gpt2",
+        ),
+        (
+            "https://huggingface.co/gpt2",
+            HuggingFaceRepoType::Model,
+            None,
+            "gpt2",
+        ),
+        (
+            "https://huggingface.co/gpt2/resolve/main/config.json",
+            HuggingFaceRepoType::Model,
+            None,
+            "gpt2",
+        ),
+        (
+            "https://huggingface.co/org/tree",
+            HuggingFaceRepoType::Model,
+            Some("org"),
+            "tree",
+        ),
+        (
+            "https://huggingface.co/org/raw",
+            HuggingFaceRepoType::Model,
+            Some("org"),
+            "raw",
+        ),
+    ];
+
+    for (test_url, repo_type, namespace, name) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: HuggingFaceProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.repo_type(), repo_type);
+        assert_eq!(provider_info.namespace(), namespace);
+        assert_eq!(provider_info.name(), name);
+    }
+}
+
+#[test]
+fn huggingface_resolve_url() {
+    let _ = env_logger::try_init();
+    let cases = [
+        (
+            "git@hf.co:openai-community/gpt2",
+            "https://huggingface.co/openai-community/gpt2/resolve/main/config.json",
+        ),
+        (
+            "https://huggingface.co/spaces/gradio/hello_world",
+            "https://huggingface.co/spaces/gradio/hello_world/resolve/main/config.json",
+        ),
+        (
+            "https://huggingface.co/gpt2",
+            "https://huggingface.co/gpt2/resolve/main/config.json",
+        ),
+    ];
+
+    for (test_url, expected) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: HuggingFaceProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.resolve_url("main", "config.json"), expected);
+    }
+
+    let parsed = GitUrl::parse("https://huggingface.co/gpt2").expect("URL parse failed");
+    let provider_info: HuggingFaceProvider = parsed.provider_info().unwrap();
+    assert_eq!(
+        provider_info.resolve_url("refs/pr/1", "/onnx/model file.onnx"),
+        "https://huggingface.co/gpt2/resolve/refs%2Fpr%2F1/onnx/model%20file.onnx"
+    );
+}
+
+#[test]
+fn huggingface_invalid() {
+    let _ = env_logger::try_init();
+    let test_url = "https://huggingface.co/datasets";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider_info: Result<HuggingFaceProvider, GitUrlParseError> = parsed.provider_info();
+    debug!("{:#?}", provider_info);
+
+    assert!(matches!(
+        provider_info,
+        Err(GitUrlParseError::ProviderParseFail(_))
+    ));
+
+    let test_url = "https://huggingface.co/org/na~me";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider_info: Result<HuggingFaceProvider, GitUrlParseError> = parsed.provider_info();
+    debug!("{:#?}", provider_info);
+
+    assert!(matches!(
+        provider_info,
+        Err(GitUrlParseError::ProviderInvalidName(_))
+    ));
+}
+
+#[test]
+fn launchpad_urls() {
+    let _ = env_logger::try_init();
+    let cases = [
+        (
+            "git+ssh://git.launchpad.net/~user/project/+git/repo",
+            Some("user"),
+            Some("project"),
+            Some("repo"),
+        ),
+        (
+            "https://git.launchpad.net/project",
+            None,
+            Some("project"),
+            None,
+        ),
+        (
+            "https://git.launchpad.net/~team/project",
+            Some("team"),
+            Some("project"),
+            None,
+        ),
+        (
+            "git+ssh://user@git.launchpad.net/~user/+git/dotfiles",
+            Some("user"),
+            None,
+            Some("dotfiles"),
+        ),
+        (
+            "https://code.launchpad.net/~user/project/+git/repo/+ref/main",
+            Some("user"),
+            Some("project"),
+            Some("repo"),
+        ),
+        ("lp:project", None, Some("project"), None),
+        (
+            "lp:~user/project/+git/repo",
+            Some("user"),
+            Some("project"),
+            Some("repo"),
+        ),
+    ];
+
+    for (test_url, owner, project, repo) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: LaunchpadProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.owner(), owner);
+        assert_eq!(provider_info.project(), project);
+        assert_eq!(provider_info.repo(), repo);
+    }
+}
+
+#[test]
+fn launchpad_shorthand() {
+    let _ = env_logger::try_init();
+    let cases = [
+        ("lp:project", "https://git.launchpad.net/project"),
+        (
+            "lp:~user/project/+git/repo",
+            "https://git.launchpad.net/~user/project/+git/repo",
+        ),
+        (
+            "lp:~user/+git/dotfiles",
+            "https://git.launchpad.net/~user/+git/dotfiles",
+        ),
+    ];
+
+    for (shorthand, expected) in cases {
+        let expanded = LaunchpadProvider::expand_shorthand(shorthand).unwrap();
+        debug!("{:#?}", expanded);
+        assert_eq!(expanded, expected);
+
+        let parsed = GitUrl::parse(&expanded).expect("URL parse failed");
+        let provider_info: LaunchpadProvider = parsed.provider_info().unwrap();
+        assert_eq!(provider_info.shorthand(), shorthand);
+    }
+
+    let parsed = GitUrl::parse("lp:~user/project/+git/repo").expect("URL parse failed");
+    let provider_info: LaunchpadProvider = parsed.provider_info().unwrap();
+    assert_eq!(
+        provider_info.ssh_url(Some("user")),
+        "git+ssh://user@git.launchpad.net/~user/project/+git/repo"
+    );
+
+    assert!(matches!(
+        LaunchpadProvider::expand_shorthand("project"),
+        Err(GitUrlParseError::ProviderParseFail(_))
+    ));
+}
+
+#[test]
+fn launchpad_invalid() {
+    let _ = env_logger::try_init();
+    let cases = [
+        "https://git.launchpad.net/~user",
+        "https://git.launchpad.net/~user/project/+git",
+        "https://git.launchpad.net/user/project/+git/repo",
+        "https://git.launchpad.net/project/+git/repo",
+    ];
+
+    for test_url in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: Result<LaunchpadProvider, GitUrlParseError> = parsed.provider_info();
+        debug!("{:#?}", provider_info);
+
+        assert!(matches!(
+            provider_info,
+            Err(GitUrlParseError::ProviderParseFail(_))
+        ));
+    }
+}
+
+#[test]
+fn detect_launchpad() {
+    let _ = env_logger::try_init();
+    let cases = [
+        ("lp:project", Confidence::High),
+        ("https://git.launchpad.net/~user/project", Confidence::High),
+        (
+            "https://git.example.com/~user/project/+git/repo",
+            Confidence::Medium,
+        ),
+    ];
+
+    for (test_url, confidence) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let detected = parsed.detect_provider().expect("Provider detect failed");
+        debug!("{:#?}", detected);
+
+        assert_eq!(detected.kind(), ProviderKind::Launchpad);
+        assert_eq!(detected.confidence(), confidence);
+    }
+}
+
+#[test]
+fn cgit_urls() {
+    let _ = env_logger::try_init();
+    let cases = [
+        (
+            "https://git.kernel.org/pub/scm/linux/kernel/git/torvalds/linux.git",
+            None,
+            "pub/scm/linux/kernel/git/torvalds/linux.git",
+        ),
+        ("git://git.savannah.gnu.org/emacs.git", None, "emacs.git"),
+        (
+            "https://git.example.com/cgit/tools/hello.git/tree/src/main.c?h=main",
+            Some("cgit"),
+            "tools/hello.git",
+        ),
+        ("https://git.example.com/hello.git/log/", None, "hello.git"),
+    ];
+
+    for (test_url, web_base, project) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: CgitProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.flavor(), CgitFlavor::Cgit);
+        assert_eq!(provider_info.web_base(), web_base);
+        assert_eq!(provider_info.project(), project);
+        assert_eq!(provider_info.fullname(), project);
+    }
+}
+
+#[test]
+fn cgit_links() {
+    let _ = env_logger::try_init();
+    let test_url = "https://git.example.com/cgit/hello.git";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider_info: CgitProvider = parsed.provider_info().unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(
+        provider_info.commit_url("abc123"),
+        "https://git.example.com/cgit/hello.git/commit/?id=abc123"
+    );
+    assert_eq!(
+        provider_info.tree_url("v1.0", None),
+        "https://git.example.com/cgit/hello.git/tree/?id=v1.0"
+    );
+    assert_eq!(
+        provider_info.tree_url("main", Some("src/")),
+        "https://git.example.com/cgit/hello.git/tree/src?id=main"
+    );
+    assert_eq!(
+        provider_info.blob_url("main", "src/main.c"),
+        "https://git.example.com/cgit/hello.git/blob/src/main.c?id=main"
+    );
+
+    assert_eq!(
+        provider_info.blob_url("feat/a b&x", "dir/a#b?.c"),
+        "https://git.example.com/cgit/hello.git/blob/dir/a%23b%3F.c?id=feat%2Fa%20b%26x"
+    );
+
+    // Web urls keep the scheme and port of http urls, and use https for git urls
+    let parsed = GitUrl::parse("http://cgit.lan:8080/hello.git").expect("URL parse failed");
+    let provider_info: CgitProvider = parsed.provider_info().unwrap();
+    assert_eq!(
+        provider_info.commit_url("abc123"),
+        "http://cgit.lan:8080/hello.git/commit/?id=abc123"
+    );
+
+    let parsed = GitUrl::parse("git://git.savannah.gnu.org/emacs.git").expect("URL parse failed");
+    let provider_info: CgitProvider = parsed.provider_info().unwrap();
+    assert_eq!(provider_info.web_scheme(), "https");
+    assert_eq!(
+        provider_info.commit_url("abc123"),
+        "https://git.savannah.gnu.org/emacs.git/commit/?id=abc123"
+    );
+}
+
+#[test]
+fn gitweb_urls() {
+    let _ = env_logger::try_init();
+    let cases = [
+        ("https://git.example.com/?p=hello.git;a=summary", None),
+        (
+            "https://git.example.com/gitweb.cgi?p=hello.git;a=tree;hb=main",
+            Some("gitweb.cgi"),
+        ),
+        (
+            "https://git.example.com/gitweb/?a=summary&p=hello.git",
+            Some("gitweb"),
+        ),
+    ];
+
+    for (test_url, web_base) in cases {
+        let provider_info = CgitProvider::from_gitweb_url(test_url).unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.flavor(), CgitFlavor::Gitweb);
+        assert_eq!(provider_info.host(), "git.example.com");
+        assert_eq!(provider_info.web_base(), web_base);
+        assert_eq!(provider_info.project(), "hello.git");
+    }
+
+    let provider_info = CgitProvider::from_gitweb_url(cases[0].0).unwrap();
+    assert_eq!(
+        provider_info.commit_url("abc123"),
+        "https://git.example.com/?p=hello.git;a=commit;h=abc123"
+    );
+    assert_eq!(
+        provider_info.tree_url("main", None),
+        "https://git.example.com/?p=hello.git;a=tree;hb=main"
+    );
+    assert_eq!(
+        provider_info.tree_url("main", Some("src")),
+        "https://git.example.com/?p=hello.git;a=tree;f=src;hb=main"
+    );
+    assert_eq!(
+        provider_info.blob_url("main", "src/main.c"),
+        "https://git.example.com/?p=hello.git;a=blob;f=src/main.c;hb=main"
+    );
+    assert_eq!(
+        provider_info.blob_url("feat/a b&x", "dir/a#b?.c"),
+        "https://git.example.com/?p=hello.git;a=blob;f=dir/a%23b%3F.c;hb=feat%2Fa%20b%26x"
+    );
+
+    let provider_info = CgitProvider::from_gitweb_url(
+        "https://git.example.com/gitweb/?p=tools%2Fhello.git;a=summary",
+    )
+    .unwrap();
+    assert_eq!(provider_info.project(), "tools/hello.git");
+    assert_eq!(
+        provider_info.commit_url("abc123"),
+        "https://git.example.com/gitweb?p=tools/hello.git;a=commit;h=abc123"
+    );
+}
+
+#[test]
+fn gitweb_invalid() {
+    let _ = env_logger::try_init();
+    let cases = [
+        "https://git.example.com/gitweb.cgi",
+        "https://git.example.com/?a=summary",
+    ];
+
+    for test_url in cases {
+        let provider_info = CgitProvider::from_gitweb_url(test_url);
+        debug!("{:#?}", provider_info);
+
+        assert!(matches!(
+            provider_info,
+            Err(GitUrlParseError::ProviderParseFail(_))
+        ));
+    }
+
+    // The query is dropped by `GitUrl`
+    let parsed =
+        GitUrl::parse("https://git.example.com/gitweb.cgi?p=hello.git").expect("URL parse failed");
+    let provider_info: Result<CgitProvider, GitUrlParseError> = parsed.provider_info();
+    debug!("{:#?}", provider_info);
+
+    assert!(matches!(
+        provider_info,
+        Err(GitUrlParseError::ProviderParseFail(_))
+    ));
+}
+
+#[test]
+fn pagure_urls() {
+    let _ = env_logger::try_init();
+    let cases = [
+        ("https://pagure.io/pagure.git", None, "pagure", None),
+        (
+            "https://src.fedoraproject.org/rpms/bash.git",
+            Some("rpms"),
+            "bash",
+            None,
+        ),
+        (
+            "ssh://pkgs.fedoraproject.org/rpms/bash",
+            Some("rpms"),
+            "bash",
+            None,
+        ),
+        (
+            "https://src.fedoraproject.org/forks/jdoe/rpms/bash.git",
+            Some("rpms"),
+            "bash",
+            Some("jdoe"),
+        ),
+        (
+            "ssh://git@pagure.io/forks/jdoe/pagure.git",
+            None,
+            "pagure",
+            Some("jdoe"),
+        ),
+        (
+            "https://src.fedoraproject.org/rpms/libstdc++/blob/rawhide/f/libstdc++.spec",
+            Some("rpms"),
+            "libstdc++",
+            None,
+        ),
+        ("https://pagure.io/pagure/issues", None, "pagure", None),
+    ];
+
+    for (test_url, namespace, repo, fork_owner) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: PagureProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(provider_info.namespace(), namespace);
+        assert_eq!(provider_info.repo(), repo);
+        assert_eq!(provider_info.fork_owner(), fork_owner);
+        assert_eq!(provider_info.is_fork(), fork_owner.is_some());
+    }
+}
+
+#[test]
+fn pagure_invalid() {
+    let _ = env_logger::try_init();
+    let test_url = "https://src.fedoraproject.org/rpms/bash/extra/path";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider_info: Result<PagureProvider, GitUrlParseError> = parsed.provider_info();
+    debug!("{:#?}", provider_info);
+
+    assert!(matches!(
+        provider_info,
+        Err(GitUrlParseError::ProviderParseFail(_))
+    ));
+
+    let test_url = "https://src.fedoraproject.org/rpms/ba~sh";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider_info: Result<PagureProvider, GitUrlParseError> = parsed.provider_info();
+    debug!("{:#?}", provider_info);
+
+    assert!(matches!(
+        provider_info,
+        Err(GitUrlParseError::ProviderInvalidName(_))
+    ));
+}
+
+#[test]
+fn detect_pagure() {
+    let _ = env_logger::try_init();
+    let cases = [
+        (
+            "https://src.fedoraproject.org/forks/jdoe/rpms/bash.git",
+            Confidence::High,
+        ),
+        ("https://pagure.example.com/project.git", Confidence::Medium),
+        (
+            "https://git.example.com/forks/jdoe/project.git",
+            Confidence::Medium,
+        ),
+    ];
+
+    for (test_url, confidence) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let detected = parsed.detect_provider().expect("Provider detect failed");
+        debug!("{:#?}", detected);
+
+        assert_eq!(detected.kind(), ProviderKind::Pagure);
+        assert_eq!(detected.confidence(), confidence);
+    }
+}
+
+#[test]
+fn google_source_repositories() {
+    let _ = env_logger::try_init();
+    let cases = [
+        "https://source.developers.google.com/p/my-project/r/my-repo",
+        "ssh://jdoe@source.developers.google.com:2022/p/my-project/r/my-repo",
+        "https://source.cloud.google.com/my-project/my-repo/+/main:README.md",
+    ];
+
+    for test_url in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: GoogleSourceProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(
+            provider_info.service(),
+            GoogleSourceService::CloudSourceRepositories
+        );
+        assert_eq!(provider_info.project(), "my-project");
+        assert_eq!(provider_info.repo(), "my-repo");
+        assert_eq!(provider_info.fullname(), "my-project/my-repo");
+        assert_eq!(provider_info.instance(), None);
+        assert_eq!(
+            provider_info.https_url(),
+            "https://source.developers.google.com/p/my-project/r/my-repo"
+        );
+        assert_eq!(
+            provider_info.ssh_url(Some("jdoe")),
+            "ssh://jdoe@source.developers.google.com:2022/p/my-project/r/my-repo"
+        );
+    }
+}
+
+#[test]
+fn google_secure_source_manager() {
+    let _ = env_logger::try_init();
+    let cases = [
+        "https://prod-instance-123456789-git.us-central1.sourcemanager.dev/my-project/my-repo.git",
+        "ssh://prod-instance-123456789-ssh.us-central1.sourcemanager.dev/my-project/my-repo.git",
+    ];
+
+    for test_url in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: GoogleSourceProvider = parsed.provider_info().unwrap();
+        debug!("{:#?}", provider_info);
+
+        assert_eq!(
+            provider_info.service(),
+            GoogleSourceService::SecureSourceManager
+        );
+        assert_eq!(provider_info.instance(), Some("prod-instance"));
+        assert_eq!(provider_info.project_number(), Some("123456789"));
+        assert_eq!(provider_info.location(), Some("us-central1"));
+        assert_eq!(provider_info.project(), "my-project");
+        assert_eq!(provider_info.repo(), "my-repo");
+        assert_eq!(provider_info.https_url(), cases[0]);
+        assert_eq!(provider_info.ssh_url(None), cases[1]);
+    }
+}
+
+#[test]
+fn google_source_invalid() {
+    let _ = env_logger::try_init();
+    let cases = [
+        "https://source.developers.google.com/my-project/my-repo",
+        "https://instance-git.us-central1.sourcemanager.dev/my-project/my-repo.git",
+        "https://instance-123-git.us-central1.sourcemanager.dev/my-repo.git",
+    ];
+
+    for test_url in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let provider_info: Result<GoogleSourceProvider, GitUrlParseError> = parsed.provider_info();
+        debug!("{:#?}", provider_info);
+
+        assert!(matches!(
+            provider_info,
+            Err(GitUrlParseError::ProviderParseFail(_))
+        ));
+    }
+
+    let test_url = "https://source.developers.google.com/p/My_Project/r/my-repo";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider_info: Result<GoogleSourceProvider, GitUrlParseError> = parsed.provider_info();
+    debug!("{:#?}", provider_info);
+
+    assert!(matches!(
+        provider_info,
+        Err(GitUrlParseError::ProviderInvalidName(_))
+    ));
+}
@@ -0,0 +1,370 @@
+use git_url_parse::types::provider::{
+    Confidence, HostPattern, HostSettings, PatternSet, ProviderKind, ProviderRegistry,
+};
+use git_url_parse::{GitUrl, GitUrlParseError};
+use log::debug;
+
+#[test]
+fn host_pattern_exact() {
+    let _ = env_logger::try_init();
+    let pattern: HostPattern = "Git.Corp.Example".parse().expect("Pattern parse failed");
+    debug!("{:#?}", pattern);
+
+    assert_eq!(pattern.host(), "git.corp.example");
+    assert!(!pattern.wildcard());
+    assert_eq!(pattern.port(), None);
+    assert_eq!(pattern.to_string(), "git.corp.example");
+
+    assert!(pattern.matches("git.corp.example", None));
+    assert!(pattern.matches("GIT.corp.example", Some(8443)));
+    assert!(!pattern.matches("sub.git.corp.example", None));
+}
+
+#[test]
+fn host_pattern_wildcard() {
+    let _ = env_logger::try_init();
+    let pattern: HostPattern = "*.corp.example".parse().expect("Pattern parse failed");
+    debug!("{:#?}", pattern);
+
+    assert_eq!(pattern.host(), "corp.example");
+    assert!(pattern.wildcard());
+    assert_eq!(pattern.to_string(), "*.corp.example");
+
+    assert!(pattern.matches("git.corp.example", None));
+    assert!(pattern.matches("a.b.corp.example", None));
+    assert!(!pattern.matches("corp.example", None));
+    assert!(!pattern.matches("notcorp.example", None));
+}
+
+#[test]
+fn host_pattern_port() {
+    let _ = env_logger::try_init();
+    let pattern: HostPattern = "git.corp.example:7999"
+        .parse()
+        .expect("Pattern parse failed");
+    debug!("{:#?}", pattern);
+
+    assert_eq!(pattern.port(), Some(7999));
+    assert_eq!(pattern.to_string(), "git.corp.example:7999");
+
+    assert!(pattern.matches("git.corp.example", Some(7999)));
+    assert!(!pattern.matches("git.corp.example", None));
+}
+
+#[test]
+fn host_pattern_label_wildcard() {
+    let _ = env_logger::try_init();
+    let pattern: HostPattern = "git.*.corp.example".parse().expect("Pattern parse failed");
+    debug!("{:#?}", pattern);
+
+    assert!(pattern.matches("git.eu.corp.example", None));
+    assert!(!pattern.matches("git.corp.example", None));
+    assert!(!pattern.matches("git.eu.west.corp.example", None));
+    assert!(!pattern.matches("www.eu.corp.example", None));
+    assert_eq!(pattern.to_string(), "git.*.corp.example");
+}
+
+#[test]
+fn host_pattern_ipv6() {
+    let _ = env_logger::try_init();
+    let pattern: HostPattern = "[::1]:8080".parse().expect("Pattern parse failed");
+    debug!("{:#?}", pattern);
+
+    assert_eq!(pattern.host(), "[::1]");
+    assert_eq!(pattern.port(), Some(8080));
+    assert!(pattern.matches("[::1]", Some(8080)));
+    assert!(!pattern.matches("[::1]", None));
+    assert_eq!(pattern.to_string(), "[::1]:8080");
+
+    let pattern: HostPattern = "[FE80::1]".parse().expect("Pattern parse failed");
+    assert_eq!(pattern.port(), None);
+    assert!(pattern.matches("[fe80::1]", Some(22)));
+}
+
+#[test]
+fn host_pattern_invalid() {
+    let _ = env_logger::try_init();
+    for pattern in [
+        "",
+        "*",
+        "*.",
+        "git.corp.example:port",
+        "git/corp",
+        "a*.corp.example",
+        "[::1",
+        "[::1]8080",
+        "[::1]:port",
+        "[git.corp.example]",
+    ] {
+        let e = pattern.parse::<HostPattern>();
+        debug!("{:#?}", e);
+
+        assert_eq!(
+            e,
+            Err(GitUrlParseError::InvalidHostPattern(pattern.to_string()))
+        );
+    }
+}
+
+#[test]
+fn lookup_most_specific() {
+    let _ = env_logger::try_init();
+    let mut registry = ProviderRegistry::new();
+    registry
+        .register("*.corp.example", ProviderKind::Generic)
+        .unwrap()
+        .register("git.corp.example", ProviderKind::GitLab)
+        .unwrap()
+        .register("git.corp.example:8080", ProviderKind::AzureDevOps)
+        .unwrap();
+
+    let lookup = |host, port| registry.lookup(host, port).map(|e| e.provider());
+
+    assert_eq!(
+        lookup("other.corp.example", None),
+        Some(ProviderKind::Generic)
+    );
+    assert_eq!(lookup("git.corp.example", None), Some(ProviderKind::GitLab));
+    assert_eq!(
+        lookup("git.corp.example", Some(8080)),
+        Some(ProviderKind::AzureDevOps)
+    );
+    assert_eq!(lookup("example.com", None), None);
+}
+
+#[test]
+fn detect_registered_host() {
+    let _ = env_logger::try_init();
+    let mut registry = ProviderRegistry::new();
+    registry
+        .register("git.corp.example", ProviderKind::GitLab)
+        .unwrap()
+        .register("tfs.corp.example", ProviderKind::AzureDevOps)
+        .unwrap();
+
+    let gitlab = GitUrl::parse("git@git.corp.example:group/repo.git").unwrap();
+    let detected = gitlab
+        .detect_provider_with(&registry)
+        .expect("Provider detect failed");
+    debug!("{:#?}", detected);
+
+    assert_eq!(detected.kind(), ProviderKind::GitLab);
+    assert_eq!(detected.confidence(), Confidence::High);
+
+    // Without the registry, this is a generic host
+    let detected = gitlab.detect_provider().expect("Provider detect failed");
+    assert_eq!(detected.kind(), ProviderKind::Generic);
+    assert_eq!(detected.confidence(), Confidence::Low);
+
+    let azure = GitUrl::parse("https://tfs.corp.example/org/project/_git/repo").unwrap();
+    let detected = azure
+        .detect_provider_with(&registry)
+        .expect("Provider detect failed");
+    debug!("{:#?}", detected);
+
+    assert_eq!(detected.kind(), ProviderKind::AzureDevOps);
+    assert_eq!(detected.confidence(), Confidence::High);
+}
+
+#[test]
+fn builtin_registry() {
+    let _ = env_logger::try_init();
+    let registry = ProviderRegistry::builtin();
+
+    let lookup = |host| registry.lookup(host, None).map(|e| e.provider());
+
+    assert_eq!(lookup("gitlab.com"), Some(ProviderKind::GitLab));
+    assert_eq!(lookup("dev.azure.com"), Some(ProviderKind::AzureDevOps));
+    assert_eq!(
+        lookup("org.visualstudio.com"),
+        Some(ProviderKind::AzureDevOps)
+    );
+    assert_eq!(lookup("git.corp.example"), None);
+
+    assert_eq!(
+        lookup("git-codecommit.us-east-2.amazonaws.com"),
+        Some(ProviderKind::CodeCommit)
+    );
+    assert_eq!(
+        lookup("git-codecommit.cn-north-1.amazonaws.com.cn"),
+        Some(ProviderKind::CodeCommit)
+    );
+    assert_eq!(lookup("s3.us-east-2.amazonaws.com"), None);
+    assert_eq!(lookup("git-codecommit.amazonaws.com"), None);
+}
+
+#[test]
+fn convert_https_to_ssh() {
+    let _ = env_logger::try_init();
+    let registry = ProviderRegistry::new();
+    let test_url = "https://user@github.com/tjtelan/git-url-parse-rs.git";
+    let expected = "git@github.com:tjtelan/git-url-parse-rs.git";
+
+    let parsed = GitUrl::parse(test_url).unwrap();
+    let converted = registry.to_ssh(&parsed).expect("SSH conversion failed");
+    debug!("{:#?}", converted);
+
+    assert_eq!(converted.to_string(), expected);
+    assert_eq!(converted, GitUrl::parse(expected).unwrap());
+}
+
+#[test]
+fn convert_ssh_to_https() {
+    let _ = env_logger::try_init();
+    let registry = ProviderRegistry::new();
+    let test_url = "git@github.com:tjtelan/git-url-parse-rs.git";
+    let expected = "https://github.com/tjtelan/git-url-parse-rs.git";
+
+    let parsed = GitUrl::parse(test_url).unwrap();
+    let converted = registry.to_https(&parsed).expect("HTTPS conversion failed");
+    debug!("{:#?}", converted);
+
+    assert_eq!(converted.to_string(), expected);
+    assert_eq!(converted, GitUrl::parse(expected).unwrap());
+}
+
+#[test]
+fn convert_with_host_settings() {
+    let _ = env_logger::try_init();
+    let mut registry = ProviderRegistry::new();
+    registry
+        .register_with_settings(
+            "*.corp.example",
+            ProviderKind::GitLab,
+            HostSettings::default()
+                .with_ssh_user(Some("gitlab".to_string()))
+                .with_ssh_port(Some(2222))
+                .with_https_base_path(Some("/gitlab/".to_string())),
+        )
+        .unwrap();
+
+    let https_url = "https://git.corp.example/gitlab/group/subgroup/repo.git";
+    let ssh_url = "ssh://gitlab@git.corp.example:2222/group/subgroup/repo.git";
+
+    let converted = registry
+        .to_ssh(&GitUrl::parse(https_url).unwrap())
+        .expect("SSH conversion failed");
+    debug!("{:#?}", converted);
+    assert_eq!(converted.to_string(), ssh_url);
+
+    let converted = registry
+        .to_https(&GitUrl::parse(ssh_url).unwrap())
+        .expect("HTTPS conversion failed");
+    debug!("{:#?}", converted);
+    assert_eq!(converted.to_string(), https_url);
+}
+
+#[test]
+fn convert_azure_devops() {
+    let _ = env_logger::try_init();
+    let mut registry = ProviderRegistry::new();
+    registry
+        .register("tfs.corp.example", ProviderKind::AzureDevOps)
+        .unwrap();
+
+    let cases = [
+        (
+            "https://dev.azure.com/org/proj/_git/repo",
+            "git@ssh.dev.azure.com:v3/org/proj/repo",
+        ),
+        (
+            "https://contoso.visualstudio.com/DefaultCollection/Fabrikam/_git/Fiber",
+            "contoso@vs-ssh.visualstudio.com:v3/contoso/Fabrikam/Fiber",
+        ),
+        (
+            "https://tfs.corp.example/Coll/Proj/_git/repo",
+            "git@tfs.corp.example:Coll/Proj/_ssh/repo",
+        ),
+    ];
+
+    for (https_url, ssh_url) in cases {
+        let converted = registry
+            .to_ssh(&GitUrl::parse(https_url).unwrap())
+            .expect("SSH conversion failed");
+        debug!("{:#?}", converted);
+        assert_eq!(converted.to_string(), ssh_url);
+    }
+
+    let converted = registry
+        .to_https(&GitUrl::parse("git@ssh.dev.azure.com:v3/org/proj/repo").unwrap())
+        .expect("HTTPS conversion failed");
+    debug!("{:#?}", converted);
+    assert_eq!(
+        converted.to_string(),
+        "https://dev.azure.com/org/proj/_git/repo"
+    );
+
+    let converted = registry
+        .to_https(
+            &GitUrl::parse("contoso@vs-ssh.visualstudio.com:v3/contoso/Fabrikam/Fiber").unwrap(),
+        )
+        .expect("HTTPS conversion failed");
+    debug!("{:#?}", converted);
+    assert_eq!(
+        converted.to_string(),
+        "https://contoso.visualstudio.com/Fabrikam/_git/Fiber"
+    );
+}
+
+#[test]
+fn convert_unsupported_provider() {
+    let _ = env_logger::try_init();
+    let mut registry = ProviderRegistry::new();
+    registry
+        .register_patterns(
+            "forge.corp.example",
+            PatternSet::new("/{org}/{repo}".parse().expect("Pattern parse failed")),
+        )
+        .unwrap();
+
+    let parsed = GitUrl::parse("https://forge.corp.example/org/repo.git").unwrap();
+    let e = registry.to_ssh(&parsed);
+    debug!("{:#?}", e);
+
+    assert_eq!(e, Err(GitUrlParseError::ProviderUnsupported));
+}
+
+#[test]
+fn convert_filepath() {
+    let _ = env_logger::try_init();
+    let registry = ProviderRegistry::new();
+    let parsed = GitUrl::parse("file:///path/to/repo.git").unwrap();
+
+    let e = registry.to_ssh(&parsed);
+    debug!("{:#?}", e);
+
+    assert_eq!(e, Err(GitUrlParseError::ProviderUnsupported));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn registry_from_config() {
+    let _ = env_logger::try_init();
+    let config = r#"
+    {
+        "hosts": [
+            { "host": "git.corp.example", "provider": "gitlab", "ssh_port": 2222 },
+            { "host": "*.tfs.corp.example", "provider": "azure_devops" }
+        ]
+    }
+    "#;
+
+    let registry: ProviderRegistry = serde_json::from_str(config).expect("Config parse failed");
+    debug!("{:#?}", registry);
+
+    let mut expected = ProviderRegistry::new();
+    expected
+        .register_with_settings(
+            "git.corp.example",
+            ProviderKind::GitLab,
+            HostSettings::default().with_ssh_port(Some(2222)),
+        )
+        .unwrap()
+        .register("*.tfs.corp.example", ProviderKind::AzureDevOps)
+        .unwrap();
+
+    assert_eq!(registry, expected);
+
+    let invalid = r#"{ "hosts": [ { "host": "bad host", "provider": "generic" } ] }"#;
+    assert!(serde_json::from_str::<ProviderRegistry>(invalid).is_err());
+}
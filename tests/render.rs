@@ -0,0 +1,351 @@
+use git_url_parse::types::provider::{
+    AzureDevOpsProvider, BitbucketCloudProvider, BitbucketServerProvider, CgitProvider,
+    CloneProtocol, CodeCommitProvider, GenericProvider, GerritProvider, GitHubProvider,
+    GitLabProvider, GitProvider, GiteaProvider, GoogleSourceProvider, HuggingFaceProvider,
+    LaunchpadProvider, PagureProvider, SourceHutProvider, ToGitUrl,
+};
+use git_url_parse::{GitUrl, GitUrlParseError};
+use log::debug;
+
+/// Render the provider info of `test_url` on `host` and `port` for `protocol`, and check it parses back to the same provider info
+fn assert_round_trip<P>(
+    test_url: &str,
+    host: &str,
+    port: Option<u16>,
+    protocol: CloneProtocol,
+    expected: &str,
+) where
+    P: GitProvider<GitUrl, GitUrlParseError> + ToGitUrl + PartialEq,
+{
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    let provider: P = parsed
+        .provider_info()
+        .expect("Failed to parse provider info");
+
+    let rendered = provider
+        .to_git_url(host, port, protocol)
+        .expect("Failed to render url");
+    debug!("{test_url} -> {rendered}");
+    assert_eq!(rendered.to_string(), expected);
+
+    let reparsed: P = rendered
+        .provider_info()
+        .expect("Failed to parse rendered provider info");
+    assert_eq!(reparsed, provider);
+}
+
+#[test]
+fn render_constructors() {
+    let _ = env_logger::try_init();
+
+    let provider = GenericProvider::new("owner", "repo").expect("Constructor failed");
+    assert_eq!(
+        provider
+            .to_git_url("git.example.com", None, CloneProtocol::Ssh)
+            .map(|u| u.to_string()),
+        Ok("git@git.example.com:owner/repo.git".to_string())
+    );
+
+    let provider = GitHubProvider::new("tjtelan", "git-url-parse-rs").expect("Constructor failed");
+    assert_eq!(
+        provider
+            .to_git_url("github.com", None, CloneProtocol::Https)
+            .map(|u| u.to_string()),
+        Ok("https://github.com/tjtelan/git-url-parse-rs.git".to_string())
+    );
+
+    let provider =
+        GitLabProvider::new("group", &["sub1", "sub2"], "repo").expect("Constructor failed");
+    assert_eq!(provider.fullname(), "group/sub1/sub2/repo");
+    assert_eq!(
+        provider
+            .to_git_url("gitlab.corp.example", None, CloneProtocol::Https)
+            .map(|u| u.to_string()),
+        Ok("https://gitlab.corp.example/group/sub1/sub2/repo.git".to_string())
+    );
+
+    let provider = AzureDevOpsProvider::new("org", "project", "repo").expect("Constructor failed");
+    assert_eq!(
+        provider
+            .to_git_url("dev.azure.com", None, CloneProtocol::Https)
+            .map(|u| u.to_string()),
+        Ok("https://dev.azure.com/org/project/_git/repo".to_string())
+    );
+    assert_eq!(
+        provider
+            .to_git_url("ssh.dev.azure.com", None, CloneProtocol::Ssh)
+            .map(|u| u.to_string()),
+        Ok("git@ssh.dev.azure.com:v3/org/project/repo".to_string())
+    );
+
+    let provider = BitbucketCloudProvider::new("workspace", "repo").expect("Constructor failed");
+    assert_eq!(provider.fullname(), "workspace/repo");
+
+    let provider = BitbucketServerProvider::new("~jdoe", "repo").expect("Constructor failed");
+    assert!(provider.personal());
+    assert_eq!(
+        provider
+            .to_git_url("bitbucket.corp.example", Some(7999), CloneProtocol::Ssh)
+            .map(|u| u.to_string()),
+        Ok("ssh://git@bitbucket.corp.example:7999/~jdoe/repo.git".to_string())
+    );
+
+    let provider = GiteaProvider::new("owner", "repo").expect("Constructor failed");
+    assert_eq!(provider.fullname(), "owner/repo");
+}
+
+#[test]
+fn render_constructors_invalid() {
+    let _ = env_logger::try_init();
+
+    assert!(matches!(
+        GitHubProvider::new("owner/extra", "repo"),
+        Err(GitUrlParseError::ProviderInvalidName(_))
+    ));
+    assert!(matches!(
+        GitLabProvider::new("group", &[""], "repo"),
+        Err(GitUrlParseError::ProviderInvalidName(_))
+    ));
+    assert!(matches!(
+        GitHubProvider::new("-owner", "repo"),
+        Err(GitUrlParseError::ProviderInvalidName(_))
+    ));
+    assert!(GitLabProvider::new("group", &[], "-").is_err());
+}
+
+#[test]
+fn render_round_trip() {
+    let _ = env_logger::try_init();
+    use CloneProtocol::{Https, Ssh};
+
+    assert_round_trip::<GitHubProvider>(
+        "git@github.com:tjtelan/git-url-parse-rs.wiki.git",
+        "github.com",
+        None,
+        Https,
+        "https://github.com/tjtelan/git-url-parse-rs.wiki.git",
+    );
+    assert_round_trip::<GitHubProvider>(
+        "https://gist.github.com/tjtelan/2c5d8a7f0e1b",
+        "gist.github.com",
+        None,
+        Https,
+        "https://gist.github.com/tjtelan/2c5d8a7f0e1b.git",
+    );
+    assert_round_trip::<GitHubProvider>(
+        "https://gist.github.com/2c5d8a7f0e1b",
+        "gist.github.com",
+        None,
+        Ssh,
+        "git@gist.github.com:2c5d8a7f0e1b.git",
+    );
+    assert_round_trip::<GitLabProvider>(
+        "https://gitlab.com/group/project/snippets/42.git",
+        "gitlab.com",
+        None,
+        Ssh,
+        "git@gitlab.com:group/project/snippets/42.git",
+    );
+    assert_round_trip::<AzureDevOpsProvider>(
+        "https://tfs.corp.example/tfs/Collection/Project/_git/Repo",
+        "tfs.corp.example",
+        Some(22),
+        Ssh,
+        "ssh://git@tfs.corp.example:22/tfs/Collection/Project/_ssh/Repo",
+    );
+    assert_round_trip::<AzureDevOpsProvider>(
+        "ssh://tfs.corp.example:22/tfs/Collection/Project/_ssh/Repo",
+        "tfs.corp.example",
+        None,
+        Https,
+        "https://tfs.corp.example/tfs/Collection/Project/_git/Repo",
+    );
+    assert_round_trip::<BitbucketServerProvider>(
+        "ssh://git@bitbucket.corp.example:7999/proj/repo.git",
+        "bitbucket.corp.example",
+        None,
+        Https,
+        "https://bitbucket.corp.example/scm/proj/repo.git",
+    );
+    assert_round_trip::<BitbucketServerProvider>(
+        "https://corp.example/bitbucket/projects/PROJ/repos/repo/browse",
+        "corp.example",
+        None,
+        Https,
+        "https://corp.example/bitbucket/scm/PROJ/repo.git",
+    );
+    assert_round_trip::<GiteaProvider>(
+        "https://corp.example/gitea/owner/repo.wiki.git",
+        "corp.example",
+        None,
+        Https,
+        "https://corp.example/gitea/owner/repo.wiki.git",
+    );
+    assert_round_trip::<SourceHutProvider>(
+        "https://hg.sr.ht/~owner/repo",
+        "hg.sr.ht",
+        None,
+        Ssh,
+        "hg@hg.sr.ht:~owner/repo",
+    );
+    assert_round_trip::<CodeCommitProvider>(
+        "https://git-codecommit.us-east-1.amazonaws.com/v1/repos/MyRepo",
+        "git-codecommit.us-east-1.amazonaws.com",
+        None,
+        Ssh,
+        "git-codecommit.us-east-1.amazonaws.com:v1/repos/MyRepo",
+    );
+    assert_round_trip::<GerritProvider>(
+        "ssh://review.opendev.org:29418/openstack/nova",
+        "review.opendev.org",
+        None,
+        Ssh,
+        "ssh://review.opendev.org:29418/openstack/nova",
+    );
+    assert_round_trip::<HuggingFaceProvider>(
+        "https://huggingface.co/datasets/org/name",
+        "huggingface.co",
+        None,
+        Ssh,
+        "git@huggingface.co:datasets/org/name",
+    );
+    assert_round_trip::<LaunchpadProvider>(
+        "https://git.launchpad.net/~owner/project/+git/repo",
+        "git.launchpad.net",
+        None,
+        Https,
+        "https://git.launchpad.net/~owner/project/+git/repo",
+    );
+    assert_round_trip::<CgitProvider>(
+        "https://git.kernel.org/pub/scm/git/git.git",
+        "git.kernel.org",
+        None,
+        Https,
+        "https://git.kernel.org/pub/scm/git/git.git",
+    );
+    assert_round_trip::<PagureProvider>(
+        "https://src.fedoraproject.org/forks/jdoe/rpms/bash.git",
+        "pkgs.fedoraproject.org",
+        None,
+        Ssh,
+        "git@pkgs.fedoraproject.org:forks/jdoe/rpms/bash.git",
+    );
+    assert_round_trip::<GoogleSourceProvider>(
+        "https://source.developers.google.com/p/my-project/r/my-repo",
+        "source.developers.google.com",
+        None,
+        Ssh,
+        "ssh://git@source.developers.google.com:2022/p/my-project/r/my-repo",
+    );
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn render_url() {
+    let _ = env_logger::try_init();
+    let provider = GitLabProvider::new("group", &["sub"], "repo").expect("Constructor failed");
+
+    let url = provider
+        .to_url("gitlab.corp.example", None, CloneProtocol::Ssh)
+        .expect("Failed to render url");
+    assert_eq!(
+        url.as_str(),
+        "ssh://git@gitlab.corp.example/group/sub/repo.git"
+    );
+
+    let url = provider
+        .to_url("gitlab.corp.example", Some(8443), CloneProtocol::Https)
+        .expect("Failed to render url");
+    assert_eq!(
+        url.as_str(),
+        "https://gitlab.corp.example:8443/group/sub/repo.git"
+    );
+
+    let reparsed = GitLabProvider::from_git_url(&url).expect("Failed to parse provider info");
+    assert_eq!(reparsed, provider);
+
+    assert!(
+        provider
+            .to_url("bad host", None, CloneProtocol::Https)
+            .is_err()
+    );
+}
+
+#[test]
+fn render_ports() {
+    let _ = env_logger::try_init();
+    let provider = GitLabProvider::new("g", &[], "r").expect("Constructor failed");
+
+    let url = provider
+        .to_git_url("gl.lan", Some(8443), CloneProtocol::Https)
+        .expect("Failed to render url");
+    assert_eq!(url.port(), Some(8443));
+    assert_eq!(url.to_string(), "https://gl.lan:8443/g/r.git");
+
+    let reparsed: GitLabProvider = url
+        .provider_info()
+        .expect("Failed to parse rendered provider info");
+    assert_eq!(reparsed, provider);
+
+    let url = provider
+        .to_git_url("gl.lan", Some(2222), CloneProtocol::Ssh)
+        .expect("Failed to render url");
+    assert_eq!(url.to_string(), "ssh://git@gl.lan:2222/g/r.git");
+
+    // The ssh port of the provider isn't used for https
+    let parsed =
+        GitUrl::parse("ssh://review.opendev.org:29418/openstack/nova").expect("URL parse failed");
+    let provider: GerritProvider = parsed
+        .provider_info()
+        .expect("Failed to parse provider info");
+    assert_eq!(
+        provider
+            .to_git_url("review.opendev.org", None, CloneProtocol::Https)
+            .map(|u| u.to_string()),
+        Ok("https://review.opendev.org/openstack/nova".to_string())
+    );
+
+    assert!(matches!(
+        provider.to_git_url("review.opendev.org:29418", None, CloneProtocol::Ssh),
+        Err(GitUrlParseError::ProviderInvalidName(_))
+    ));
+}
+
+#[test]
+fn render_ipv6() {
+    let _ = env_logger::try_init();
+    let provider = GitLabProvider::new("g", &[], "r").expect("Constructor failed");
+
+    let cases = [
+        (None, CloneProtocol::Https, "https://[::1]/g/r.git"),
+        (
+            Some(8443),
+            CloneProtocol::Https,
+            "https://[::1]:8443/g/r.git",
+        ),
+        (None, CloneProtocol::Ssh, "git@[::1]:g/r.git"),
+        (
+            Some(2222),
+            CloneProtocol::Ssh,
+            "ssh://git@[::1]:2222/g/r.git",
+        ),
+    ];
+
+    for (port, protocol, expected) in cases {
+        let url = provider
+            .to_git_url("[::1]", port, protocol)
+            .expect("Failed to render url");
+        debug!("{:#?}", url);
+
+        assert_eq!(url.host(), Some("[::1]"));
+        assert_eq!(url.port(), port);
+        assert_eq!(url.to_string(), expected);
+    }
+
+    for host in ["::1", "[::1]:22", "[::1"] {
+        assert!(matches!(
+            provider.to_git_url(host, None, CloneProtocol::Ssh),
+            Err(GitUrlParseError::ProviderInvalidName(_))
+        ));
+    }
+}
@@ -0,0 +1,121 @@
+use git_url_parse::types::{KeybaseVisibility, Transport};
+use git_url_parse::*;
+use log::debug;
+
+#[test]
+fn radicle_repo() {
+    let _ = env_logger::try_init();
+    let test_url = "rad://z3gqcJUoA1n9HaHKufZs5FCSGazv5";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    assert_eq!(parsed.to_string(), test_url);
+    assert_eq!(parsed.scheme(), Some("rad"));
+    assert_eq!(parsed.host(), Some("z3gqcJUoA1n9HaHKufZs5FCSGazv5"));
+    assert_eq!(parsed.path(), "");
+
+    let Some(Transport::Radicle(radicle)) = parsed.transport() else {
+        panic!("Radicle transport expected");
+    };
+    assert_eq!(radicle.rid(), "z3gqcJUoA1n9HaHKufZs5FCSGazv5");
+    assert_eq!(radicle.nid(), None);
+}
+
+#[test]
+fn radicle_repo_on_node() {
+    let _ = env_logger::try_init();
+    let test_url =
+        "rad://z3gqcJUoA1n9HaHKufZs5FCSGazv5/z6MknSLrJoTcukLrE435hVNQT4JUhbvWLX4kF8LhaboMTv";
+    let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    assert_eq!(parsed.to_string(), test_url);
+
+    let Some(Transport::Radicle(radicle)) = parsed.transport() else {
+        panic!("Radicle transport expected");
+    };
+    assert_eq!(radicle.rid(), "z3gqcJUoA1n9HaHKufZs5FCSGazv5");
+    assert_eq!(
+        radicle.nid(),
+        Some("z6MknSLrJoTcukLrE435hVNQT4JUhbvWLX4kF8LhaboMTv")
+    );
+}
+
+#[test]
+fn keybase_repos() {
+    let _ = env_logger::try_init();
+    let cases = [
+        (
+            "keybase://private/alice/notes",
+            KeybaseVisibility::Private,
+            "alice",
+            "notes",
+        ),
+        (
+            "keybase://public/alice/dotfiles",
+            KeybaseVisibility::Public,
+            "alice",
+            "dotfiles",
+        ),
+        (
+            "keybase://team/acme.devs/infra",
+            KeybaseVisibility::Team,
+            "acme.devs",
+            "infra",
+        ),
+    ];
+
+    for (test_url, visibility, owner, repo) in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        debug!("{:#?}", parsed);
+
+        assert_eq!(parsed.to_string(), test_url);
+
+        let Some(Transport::Keybase(keybase)) = parsed.transport() else {
+            panic!("Keybase transport expected");
+        };
+        assert_eq!(keybase.visibility(), visibility);
+        assert_eq!(keybase.owner(), owner);
+        assert_eq!(keybase.repo(), repo);
+    }
+}
+
+#[test]
+fn transport_invalid() {
+    let _ = env_logger::try_init();
+    let cases = [
+        "rad://repo",
+        "rad://z3gqcJUoA1n9HaHKufZs5FCSGazv5/node",
+        "keybase://shared/alice/notes",
+        "keybase://private/alice",
+        "keybase://team/acme/infra/extra",
+    ];
+
+    for test_url in cases {
+        let e = GitUrl::parse(test_url);
+        debug!("{:#?}", e);
+
+        assert!(matches!(e, Err(GitUrlParseError::InvalidTransport(_))));
+    }
+}
+
+#[test]
+fn transport_no_provider() {
+    let _ = env_logger::try_init();
+    let cases = [
+        "rad://z3gqcJUoA1n9HaHKufZs5FCSGazv5",
+        "keybase://team/acme.devs/infra",
+    ];
+
+    for test_url in cases {
+        let parsed = GitUrl::parse(test_url).expect("URL parse failed");
+        let detected = parsed.detect_provider();
+        debug!("{:#?}", detected);
+
+        assert_eq!(detected, Err(GitUrlParseError::ProviderUnsupported));
+    }
+
+    let parsed =
+        GitUrl::parse("https://github.com/tjtelan/git-url-parse-rs.git").expect("URL parse failed");
+    assert_eq!(parsed.transport(), None);
+}
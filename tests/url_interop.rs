@@ -1,6 +1,9 @@
 use git_url_parse::GitUrl;
 use git_url_parse::types::provider::{
-    AzureDevOpsProvider, GenericProvider, GitLabProvider, GitProvider,
+    AzureDevOpsProvider, BitbucketCloudProvider, BitbucketServerProvider, CgitFlavor, CgitProvider,
+    CodeCommitProvider, GenericProvider, GerritProvider, GitHubProvider, GitHubRepoKind,
+    GitLabProvider, GitProvider, GiteaProvider, GoogleSourceProvider, HuggingFaceProvider,
+    HuggingFaceRepoType, LaunchpadProvider, PagureProvider, SourceHutProvider,
 };
 
 use log::debug;
@@ -274,3 +277,189 @@ fn url_ssh_gitlab_subgroups() {
     assert_eq!(provider_info.repo(), repo);
     assert_eq!(provider_info.fullname(), full);
 }
+
+#[cfg(feature = "url")]
+#[test]
+fn url_http_github_wiki() {
+    let _ = env_logger::try_init();
+    let test_url = "https://github.com/tjtelan/git-url-parse-rs.wiki.git";
+    let parsed = Url::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let provider_info = GitHubProvider::from_git_url(&parsed).unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.owner(), Some("tjtelan"));
+    assert_eq!(provider_info.repo(), "git-url-parse-rs");
+    assert_eq!(provider_info.kind(), GitHubRepoKind::Wiki);
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn url_http_bitbucket_cloud() {
+    let _ = env_logger::try_init();
+    let test_url = "https://user@bitbucket.org/workspace/repo.git";
+    let parsed = Url::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let provider_info = BitbucketCloudProvider::from_git_url(&parsed).unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.workspace(), "workspace");
+    assert_eq!(provider_info.repo(), "repo");
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn url_ssh_bitbucket_server() {
+    let _ = env_logger::try_init();
+    let test_url = "ssh://git@bitbucket.corp.example:7999/~jdoe/repo.git";
+    let parsed = Url::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let provider_info = BitbucketServerProvider::from_git_url(&parsed).unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.project_key(), "~jdoe");
+    assert_eq!(provider_info.repo(), "repo");
+    assert!(provider_info.personal());
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn url_http_gitea_raw() {
+    let _ = env_logger::try_init();
+    let test_url = "https://codeberg.org/owner/repo/raw/branch/main/README.md";
+    let parsed = Url::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let provider_info = GiteaProvider::from_git_url(&parsed).unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.owner(), "owner");
+    assert_eq!(provider_info.repo(), "repo");
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn url_http_sourcehut() {
+    let _ = env_logger::try_init();
+    let test_url = "https://git.sr.ht/~user/repo";
+    let parsed = Url::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let provider_info = SourceHutProvider::from_git_url(&parsed).unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.owner(), "~user");
+    assert_eq!(provider_info.repo(), "repo");
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn url_codecommit_helper() {
+    let _ = env_logger::try_init();
+    let test_url = "codecommit::us-east-1://dev@MyDemoRepo";
+    let parsed = Url::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let provider_info = CodeCommitProvider::from_git_url(&parsed).unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.region(), Some("us-east-1"));
+    assert_eq!(provider_info.profile(), Some("dev"));
+    assert_eq!(provider_info.repo(), "MyDemoRepo");
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn url_ssh_gerrit() {
+    let _ = env_logger::try_init();
+    let test_url = "ssh://jdoe@review.example.com:29418/platform/build/soong";
+    let parsed = Url::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let provider_info = GerritProvider::from_git_url(&parsed).unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.project(), "platform/build/soong");
+    assert_eq!(provider_info.ssh_port(), Some(29418));
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn url_http_huggingface() {
+    let _ = env_logger::try_init();
+    let test_url = "https://huggingface.co/datasets/stanfordnlp/imdb";
+    let parsed = Url::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let provider_info = HuggingFaceProvider::from_git_url(&parsed).unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.repo_type(), HuggingFaceRepoType::Dataset);
+    assert_eq!(provider_info.fullname(), "stanfordnlp/imdb");
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn url_launchpad_shorthand() {
+    let _ = env_logger::try_init();
+    let test_url = "lp:~user/project/+git/repo";
+    let parsed = Url::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let provider_info = LaunchpadProvider::from_git_url(&parsed).unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.owner(), Some("user"));
+    assert_eq!(provider_info.project(), Some("project"));
+    assert_eq!(provider_info.repo(), Some("repo"));
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn url_gitweb() {
+    let _ = env_logger::try_init();
+    let test_url = "https://git.example.com/gitweb.cgi?p=tools/hello.git;a=summary";
+    let parsed = Url::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let provider_info = CgitProvider::from_git_url(&parsed).unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.flavor(), CgitFlavor::Gitweb);
+    assert_eq!(provider_info.web_base(), Some("gitweb.cgi"));
+    assert_eq!(provider_info.project(), "tools/hello.git");
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn url_http_pagure_fork() {
+    let _ = env_logger::try_init();
+    let test_url = "https://src.fedoraproject.org/forks/jdoe/rpms/bash.git";
+    let parsed = Url::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let provider_info = PagureProvider::from_git_url(&parsed).unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.fullname(), "forks/jdoe/rpms/bash");
+    assert!(provider_info.is_fork());
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn url_ssh_google_source() {
+    let _ = env_logger::try_init();
+    let test_url = "ssh://jdoe@source.developers.google.com:2022/p/my-project/r/my-repo";
+    let parsed = Url::parse(test_url).expect("URL parse failed");
+    debug!("{:#?}", parsed);
+
+    let provider_info = GoogleSourceProvider::from_git_url(&parsed).unwrap();
+    debug!("{:#?}", provider_info);
+
+    assert_eq!(provider_info.project(), "my-project");
+    assert_eq!(provider_info.repo(), "my-repo");
+    assert_eq!(provider_info.ssh_url(Some("jdoe")), test_url);
+}
@@ -0,0 +1,788 @@
+use git_url_parse::types::provider::{
+    AnyProvider, AzureDevOpsProvider, BitbucketCloudProvider, BitbucketServerProvider,
+    GitHubProvider, GitLabProvider, GiteaProvider, LineRange, ProviderKind, ProviderRegistry,
+    ToRawFileUrl, ToWebUrl, WebLink, WebPage, WebTarget,
+};
+use git_url_parse::{GitUrl, GitUrlParseError};
+use log::debug;
+
+/// Https clone url of a repo on `host`, for providers built with their constructors
+fn repo_url(host: &str) -> GitUrl {
+    GitUrl::parse(&format!("https://{host}/owner/repo.git")).expect("URL parse failed")
+}
+
+/// Web urls of every target, in order: repo, tree, tree with path, blob, commit, compare, blame
+fn web_urls<P: ToWebUrl>(provider: &P, url: &GitUrl) -> Vec<Result<String, GitUrlParseError>> {
+    let lines = Some(LineRange::new(10, 20));
+
+    [
+        WebTarget::Repo,
+        WebTarget::Tree {
+            git_ref: "main",
+            path: None,
+        },
+        WebTarget::Tree {
+            git_ref: "v1.2",
+            path: Some("docs/api"),
+        },
+        WebTarget::Blob {
+            git_ref: "main",
+            path: "src/my file.rs",
+            lines,
+        },
+        WebTarget::Commit {
+            sha: "8f3e2a1c4b5d6e7f8091a2b3c4d5e6f708192a3b",
+        },
+        WebTarget::Compare {
+            base: "main",
+            head: "feature/x",
+        },
+        WebTarget::Blame {
+            git_ref: "main",
+            path: "README.md",
+            lines: Some(LineRange::line(5)),
+        },
+    ]
+    .into_iter()
+    .map(|target| {
+        let web_url = provider.web_url(url, target);
+        debug!("{target:?}: {web_url:?}");
+        web_url
+    })
+    .collect()
+}
+
+/// Unwrap all urls, to compare with expected strings
+fn ok_urls(urls: Vec<Result<String, GitUrlParseError>>) -> Vec<String> {
+    urls.into_iter()
+        .map(|url| url.expect("Web url expected"))
+        .collect()
+}
+
+#[test]
+fn line_range() {
+    let _ = env_logger::try_init();
+
+    let lines = LineRange::new(20, 10);
+    assert_eq!(lines.start(), 10);
+    assert_eq!(lines.end(), Some(20));
+    assert_eq!(lines.to_string(), "10-20");
+
+    let lines = LineRange::new(7, 7);
+    assert_eq!(lines, LineRange::line(7));
+    assert_eq!(lines.end(), None);
+    assert_eq!(lines.last(), 7);
+    assert_eq!(lines.to_string(), "7");
+}
+
+#[test]
+fn github_web_urls() {
+    let _ = env_logger::try_init();
+    let provider = GitHubProvider::new("owner", "repo").expect("Constructor failed");
+
+    assert_eq!(
+        ok_urls(web_urls(&provider, &repo_url("github.com"))),
+        [
+            "https://github.com/owner/repo",
+            "https://github.com/owner/repo/tree/main",
+            "https://github.com/owner/repo/tree/v1.2/docs/api",
+            "https://github.com/owner/repo/blob/main/src/my%20file.rs#L10-L20",
+            "https://github.com/owner/repo/commit/8f3e2a1c4b5d6e7f8091a2b3c4d5e6f708192a3b",
+            "https://github.com/owner/repo/compare/main...feature/x",
+            "https://github.com/owner/repo/blame/main/README.md#L5",
+        ]
+    );
+
+    let parsed = GitUrl::parse("https://github.com/owner/repo.wiki.git").expect("URL parse failed");
+    let provider: GitHubProvider = parsed
+        .provider_info()
+        .expect("Failed to parse provider info");
+    assert_eq!(
+        provider.web_url(&parsed, WebTarget::Repo),
+        Ok("https://github.com/owner/repo/wiki".to_string())
+    );
+    assert_eq!(
+        provider.web_url(&parsed, WebTarget::Commit { sha: "abc" }),
+        Err(GitUrlParseError::ProviderUnsupported)
+    );
+
+    let gists = [
+        (
+            "https://gist.github.com/aa5a315d61ae9438b18d.git",
+            "https://gist.github.com/aa5a315d61ae9438b18d",
+        ),
+        (
+            "https://ghe.corp.example/gist/aa5a315d61ae9438b18d.git",
+            "https://ghe.corp.example/gist/aa5a315d61ae9438b18d",
+        ),
+    ];
+    for (url, expected) in gists {
+        let parsed = GitUrl::parse(url).expect("URL parse failed");
+        let provider: GitHubProvider = parsed
+            .provider_info()
+            .expect("Failed to parse provider info");
+        assert_eq!(
+            provider.web_url(&parsed, WebTarget::Repo),
+            Ok(expected.to_string())
+        );
+    }
+}
+
+#[test]
+fn gitlab_web_urls() {
+    let _ = env_logger::try_init();
+    let provider = GitLabProvider::new("group", &["sub"], "repo").expect("Constructor failed");
+
+    assert_eq!(
+        ok_urls(web_urls(&provider, &repo_url("gitlab.corp.example"))),
+        [
+            "https://gitlab.corp.example/group/sub/repo",
+            "https://gitlab.corp.example/group/sub/repo/-/tree/main",
+            "https://gitlab.corp.example/group/sub/repo/-/tree/v1.2/docs/api",
+            "https://gitlab.corp.example/group/sub/repo/-/blob/main/src/my%20file.rs#L10-20",
+            "https://gitlab.corp.example/group/sub/repo/-/commit/8f3e2a1c4b5d6e7f8091a2b3c4d5e6f708192a3b",
+            "https://gitlab.corp.example/group/sub/repo/-/compare/main...feature/x",
+            "https://gitlab.corp.example/group/sub/repo/-/blame/main/README.md#L5",
+        ]
+    );
+
+    let parsed = GitUrl::parse("https://gitlab.com/snippets/123.git").expect("URL parse failed");
+    let provider: GitLabProvider = parsed
+        .provider_info()
+        .expect("Failed to parse provider info");
+    assert_eq!(
+        provider.web_url(&parsed, WebTarget::Repo),
+        Ok("https://gitlab.com/-/snippets/123".to_string())
+    );
+}
+
+#[test]
+fn azure_devops_web_urls() {
+    let _ = env_logger::try_init();
+    let provider = AzureDevOpsProvider::new("org", "project", "repo").expect("Constructor failed");
+
+    assert_eq!(
+        ok_urls(web_urls(&provider, &repo_url("dev.azure.com"))),
+        [
+            "https://dev.azure.com/org/project/_git/repo",
+            "https://dev.azure.com/org/project/_git/repo?path=/&version=GBmain",
+            "https://dev.azure.com/org/project/_git/repo?path=/docs/api&version=GBv1.2",
+            "https://dev.azure.com/org/project/_git/repo?path=/src/my%20file.rs&version=GBmain&line=10&lineEnd=21&lineStartColumn=1&lineEndColumn=1&lineStyle=plain&_a=contents",
+            "https://dev.azure.com/org/project/_git/repo/commit/8f3e2a1c4b5d6e7f8091a2b3c4d5e6f708192a3b",
+            "https://dev.azure.com/org/project/_git/repo/branchCompare?baseVersion=GBmain&targetVersion=GBfeature%2Fx",
+            "https://dev.azure.com/org/project/_git/repo?path=/README.md&version=GBmain&line=5&lineEnd=6&lineStartColumn=1&lineEndColumn=1&lineStyle=plain&_a=blame",
+        ]
+    );
+
+    let target = WebTarget::Tree {
+        git_ref: "refs/tags/v1.2",
+        path: None,
+    };
+    assert_eq!(
+        provider.web_url(&repo_url("dev.azure.com"), target),
+        Ok("https://dev.azure.com/org/project/_git/repo?path=/&version=GTv1.2".to_string())
+    );
+
+    let target = WebTarget::Tree {
+        git_ref: "8f3e2a1c4b5d6e7f8091a2b3c4d5e6f708192a3b",
+        path: None,
+    };
+    assert_eq!(
+        provider.web_url(&repo_url("dev.azure.com"), target),
+        Ok("https://dev.azure.com/org/project/_git/repo?path=/&version=GC8f3e2a1c4b5d6e7f8091a2b3c4d5e6f708192a3b".to_string())
+    );
+
+    let parsed = GitUrl::parse("https://tfs.corp.example/tfs/Collection/Project/_git/Repo")
+        .expect("URL parse failed");
+    let provider: AzureDevOpsProvider = parsed
+        .provider_info()
+        .expect("Failed to parse provider info");
+    assert_eq!(
+        provider.web_url(&parsed, WebTarget::Commit { sha: "8f3e2a1" }),
+        Ok("https://tfs.corp.example/tfs/Collection/Project/_git/Repo/commit/8f3e2a1".to_string())
+    );
+}
+
+#[test]
+fn bitbucket_web_urls() {
+    let _ = env_logger::try_init();
+    let provider = BitbucketCloudProvider::new("workspace", "repo").expect("Constructor failed");
+
+    assert_eq!(
+        ok_urls(web_urls(&provider, &repo_url("bitbucket.org"))),
+        [
+            "https://bitbucket.org/workspace/repo",
+            "https://bitbucket.org/workspace/repo/src/main/",
+            "https://bitbucket.org/workspace/repo/src/v1.2/docs/api",
+            "https://bitbucket.org/workspace/repo/src/main/src/my%20file.rs#lines-10:20",
+            "https://bitbucket.org/workspace/repo/commits/8f3e2a1c4b5d6e7f8091a2b3c4d5e6f708192a3b",
+            "https://bitbucket.org/workspace/repo/branches/compare/feature%2Fx%0Dmain",
+            "https://bitbucket.org/workspace/repo/annotate/main/README.md#lines-5",
+        ]
+    );
+
+    let provider = BitbucketServerProvider::new("PROJ", "repo").expect("Constructor failed");
+    let urls = web_urls(&provider, &repo_url("bitbucket.corp.example"));
+    assert_eq!(
+        urls[..6]
+            .iter()
+            .map(|url| url.as_deref().expect("Web url expected"))
+            .collect::<Vec<_>>(),
+        [
+            "https://bitbucket.corp.example/projects/PROJ/repos/repo/browse",
+            "https://bitbucket.corp.example/projects/PROJ/repos/repo/browse/?at=main",
+            "https://bitbucket.corp.example/projects/PROJ/repos/repo/browse/docs/api?at=v1.2",
+            "https://bitbucket.corp.example/projects/PROJ/repos/repo/browse/src/my%20file.rs?at=main#10-20",
+            "https://bitbucket.corp.example/projects/PROJ/repos/repo/commits/8f3e2a1c4b5d6e7f8091a2b3c4d5e6f708192a3b",
+            "https://bitbucket.corp.example/projects/PROJ/repos/repo/compare/diff?sourceBranch=feature%2Fx&targetBranch=main",
+        ]
+    );
+    assert_eq!(urls[6], Err(GitUrlParseError::ProviderUnsupported));
+
+    let provider = BitbucketServerProvider::new("~jdoe", "repo").expect("Constructor failed");
+    assert_eq!(
+        provider.web_url(&repo_url("bitbucket.corp.example"), WebTarget::Repo),
+        Ok("https://bitbucket.corp.example/users/jdoe/repos/repo/browse".to_string())
+    );
+
+    let parsed = GitUrl::parse("https://corp.example/bitbucket/scm/PROJ/repo.git")
+        .expect("URL parse failed");
+    let provider: BitbucketServerProvider = parsed
+        .provider_info()
+        .expect("Failed to parse provider info");
+    assert_eq!(
+        provider.web_url(&parsed, WebTarget::Repo),
+        Ok("https://corp.example/bitbucket/projects/PROJ/repos/repo/browse".to_string())
+    );
+}
+
+#[test]
+fn gitea_web_urls() {
+    let _ = env_logger::try_init();
+    let provider = GiteaProvider::new("owner", "repo").expect("Constructor failed");
+
+    assert_eq!(
+        ok_urls(web_urls(&provider, &repo_url("codeberg.org"))),
+        [
+            "https://codeberg.org/owner/repo",
+            "https://codeberg.org/owner/repo/src/branch/main",
+            "https://codeberg.org/owner/repo/src/branch/v1.2/docs/api",
+            "https://codeberg.org/owner/repo/src/branch/main/src/my%20file.rs#L10-L20",
+            "https://codeberg.org/owner/repo/commit/8f3e2a1c4b5d6e7f8091a2b3c4d5e6f708192a3b",
+            "https://codeberg.org/owner/repo/compare/main...feature/x",
+            "https://codeberg.org/owner/repo/blame/branch/main/README.md#L5",
+        ]
+    );
+
+    let parsed =
+        GitUrl::parse("https://corp.example/gitea/owner/repo.git").expect("URL parse failed");
+    let provider: GiteaProvider = parsed
+        .provider_info()
+        .expect("Failed to parse provider info");
+    let target = WebTarget::Tree {
+        git_ref: "refs/tags/v1.2",
+        path: None,
+    };
+    assert_eq!(
+        provider.web_url(&parsed, target),
+        Ok("https://corp.example/gitea/owner/repo/src/tag/v1.2".to_string())
+    );
+}
+
+#[test]
+fn web_urls_keep_port_and_scheme() {
+    let _ = env_logger::try_init();
+    let mut registry = ProviderRegistry::new();
+    registry
+        .register("gitea.lan", ProviderKind::Gitea)
+        .expect("Register failed");
+
+    let urls = [
+        (
+            "http://gitea.lan:3000/owner/repo.git",
+            "http://gitea.lan:3000/owner/repo",
+        ),
+        (
+            "https://gitlab.corp.example:8443/group/repo.git",
+            "https://gitlab.corp.example:8443/group/repo",
+        ),
+        (
+            "ssh://git@gitlab.corp.example:2222/group/repo.git",
+            "https://gitlab.corp.example/group/repo",
+        ),
+        (
+            "ssh://git@ssh.github.com:443/owner/repo.git",
+            "https://github.com/owner/repo",
+        ),
+        (
+            "git@ssh.dev.azure.com:v3/org/project/repo",
+            "https://dev.azure.com/org/project/_git/repo",
+        ),
+    ];
+
+    for (url, expected) in urls {
+        let parsed = GitUrl::parse(url).expect("URL parse failed");
+        let detected = parsed
+            .detect_provider_with(&registry)
+            .expect("Provider expected");
+
+        let web_url = match detected.provider() {
+            AnyProvider::GitHub(p) => p.web_url(&parsed, WebTarget::Repo),
+            AnyProvider::GitLab(p) => p.web_url(&parsed, WebTarget::Repo),
+            AnyProvider::AzureDevOps(p) => p.web_url(&parsed, WebTarget::Repo),
+            AnyProvider::Gitea(p) => p.web_url(&parsed, WebTarget::Repo),
+            other => panic!("Unexpected provider: {other:?}"),
+        };
+        debug!("{url}: {web_url:?}");
+        assert_eq!(web_url, Ok(expected.to_string()));
+    }
+
+    let provider = GitLabProvider::new("group", &[], "repo").expect("Constructor failed");
+    let parsed = GitUrl::parse("file:///srv/git/group/repo.git").expect("URL parse failed");
+    assert_eq!(
+        provider.web_url(&parsed, WebTarget::Repo),
+        Err(GitUrlParseError::ProviderUnsupported)
+    );
+}
+
+/// Parse `link` and compare clone url, provider kind, page, ref, path and lines
+fn assert_web_link(
+    link: &str,
+    clone_url: &str,
+    kind: ProviderKind,
+    page: WebPage,
+    git_ref: Option<&str>,
+    path: Option<&str>,
+    lines: Option<LineRange>,
+) {
+    let parsed = WebLink::parse(link).expect("Web link parse failed");
+    debug!("{link}: {:#?}", parsed);
+
+    assert_eq!(parsed.clone_url().to_string(), clone_url);
+    assert_eq!(parsed.provider().kind(), kind);
+    assert_eq!(parsed.page(), page);
+    assert_eq!(parsed.git_ref(), git_ref);
+    assert_eq!(parsed.path(), path);
+    assert_eq!(parsed.lines(), lines);
+}
+
+#[test]
+fn web_link_github() {
+    let _ = env_logger::try_init();
+
+    assert_web_link(
+        "https://github.com/o/r/blob/main/src/lib.rs#L5",
+        "https://github.com/o/r.git",
+        ProviderKind::GitHub,
+        WebPage::Blob,
+        Some("main"),
+        Some("src/lib.rs"),
+        Some(LineRange::line(5)),
+    );
+    assert_web_link(
+        "https://github.com/o/r/blame/v1.0/src/my%20file.rs#L10-L20",
+        "https://github.com/o/r.git",
+        ProviderKind::GitHub,
+        WebPage::Blame,
+        Some("v1.0"),
+        Some("src/my file.rs"),
+        Some(LineRange::new(10, 20)),
+    );
+    assert_web_link(
+        "https://github.com/o/r/tree/main",
+        "https://github.com/o/r.git",
+        ProviderKind::GitHub,
+        WebPage::Tree,
+        Some("main"),
+        None,
+        None,
+    );
+    assert_web_link(
+        "https://github.com/o/r/commit/8f3e2a1",
+        "https://github.com/o/r.git",
+        ProviderKind::GitHub,
+        WebPage::Commit,
+        Some("8f3e2a1"),
+        None,
+        None,
+    );
+    assert_web_link(
+        "https://github.com/o/r/issues/12",
+        "https://github.com/o/r.git",
+        ProviderKind::GitHub,
+        WebPage::Other,
+        None,
+        None,
+        None,
+    );
+    assert_web_link(
+        "https://github.com/o/r",
+        "https://github.com/o/r.git",
+        ProviderKind::GitHub,
+        WebPage::Repo,
+        None,
+        None,
+        None,
+    );
+}
+
+#[test]
+fn web_link_gitlab() {
+    let _ = env_logger::try_init();
+
+    assert_web_link(
+        "https://gitlab.com/g/s/r/-/tree/v1.2/docs",
+        "https://gitlab.com/g/s/r.git",
+        ProviderKind::GitLab,
+        WebPage::Tree,
+        Some("v1.2"),
+        Some("docs"),
+        None,
+    );
+    assert_web_link(
+        "https://gitlab.com/g/r/-/blob/main/README.md#L10-20",
+        "https://gitlab.com/g/r.git",
+        ProviderKind::GitLab,
+        WebPage::Blob,
+        Some("main"),
+        Some("README.md"),
+        Some(LineRange::new(10, 20)),
+    );
+    assert_web_link(
+        "https://gitlab.com/g/s/r",
+        "https://gitlab.com/g/s/r.git",
+        ProviderKind::GitLab,
+        WebPage::Repo,
+        None,
+        None,
+        None,
+    );
+}
+
+#[test]
+fn web_link_azure_devops() {
+    let _ = env_logger::try_init();
+
+    assert_web_link(
+        "https://dev.azure.com/org/project/_git/Repo?path=/x&version=GBdev",
+        "https://dev.azure.com/org/project/_git/Repo",
+        ProviderKind::AzureDevOps,
+        WebPage::Tree,
+        Some("dev"),
+        Some("x"),
+        None,
+    );
+    assert_web_link(
+        "https://dev.azure.com/org/project/_git/Repo?path=/src/my%20file.rs&version=GTv1.2&line=10&lineEnd=21&lineStartColumn=1&lineEndColumn=1&lineStyle=plain&_a=contents",
+        "https://dev.azure.com/org/project/_git/Repo",
+        ProviderKind::AzureDevOps,
+        WebPage::Blob,
+        Some("refs/tags/v1.2"),
+        Some("src/my file.rs"),
+        Some(LineRange::new(10, 20)),
+    );
+    assert_web_link(
+        "https://dev.azure.com/org/project/_git/Repo/commit/8f3e2a1",
+        "https://dev.azure.com/org/project/_git/Repo",
+        ProviderKind::AzureDevOps,
+        WebPage::Commit,
+        Some("8f3e2a1"),
+        None,
+        None,
+    );
+    assert_web_link(
+        "https://org.visualstudio.com/DefaultCollection/project/_git/Repo?path=/README.md&_a=blame",
+        "https://org.visualstudio.com/DefaultCollection/project/_git/Repo",
+        ProviderKind::AzureDevOps,
+        WebPage::Blame,
+        None,
+        Some("README.md"),
+        None,
+    );
+
+    // Encoded names are decoded for the provider, and kept encoded in the clone url
+    let link = WebLink::parse("https://dev.azure.com/org/My%20Project/_git/My%20Repo?path=/x")
+        .expect("Web link parse failed");
+    debug!("{:#?}", link);
+    assert_eq!(
+        link.clone_url().to_string(),
+        "https://dev.azure.com/org/My%20Project/_git/My%20Repo"
+    );
+    let AnyProvider::AzureDevOps(provider) = link.provider() else {
+        panic!("Azure DevOps provider expected");
+    };
+    assert_eq!(provider.project(), "My Project");
+    assert_eq!(provider.repo(), "My Repo");
+}
+
+#[test]
+fn web_link_bitbucket() {
+    let _ = env_logger::try_init();
+
+    assert_web_link(
+        "https://bitbucket.org/workspace/repo/src/main/src/lib.rs#lines-10:20",
+        "https://bitbucket.org/workspace/repo.git",
+        ProviderKind::BitbucketCloud,
+        WebPage::Blob,
+        Some("main"),
+        Some("src/lib.rs"),
+        Some(LineRange::new(10, 20)),
+    );
+    assert_web_link(
+        "https://bitbucket.org/workspace/repo/src/main/docs/",
+        "https://bitbucket.org/workspace/repo.git",
+        ProviderKind::BitbucketCloud,
+        WebPage::Tree,
+        Some("main"),
+        Some("docs"),
+        None,
+    );
+
+    let mut registry = ProviderRegistry::new();
+    registry
+        .register("bitbucket.corp.example", ProviderKind::BitbucketServer)
+        .expect("Register failed");
+
+    let link = WebLink::parse_with(
+        "https://bitbucket.corp.example/projects/PROJ/repos/repo/browse/src/lib.rs?at=refs%2Fheads%2Fmain#10-20",
+        &registry,
+    )
+    .expect("Web link parse failed");
+    debug!("{:#?}", link);
+
+    assert_eq!(
+        link.clone_url().to_string(),
+        "https://bitbucket.corp.example/scm/PROJ/repo.git"
+    );
+    assert_eq!(link.provider().kind(), ProviderKind::BitbucketServer);
+    assert_eq!(link.page(), WebPage::Blob);
+    assert_eq!(link.git_ref(), Some("main"));
+    assert_eq!(link.path(), Some("src/lib.rs"));
+    assert_eq!(link.lines(), Some(LineRange::new(10, 20)));
+}
+
+#[test]
+fn web_link_gitea() {
+    let _ = env_logger::try_init();
+
+    assert_web_link(
+        "https://codeberg.org/owner/repo/src/tag/v1.2/docs/api.md#L3",
+        "https://codeberg.org/owner/repo.git",
+        ProviderKind::Gitea,
+        WebPage::Blob,
+        Some("refs/tags/v1.2"),
+        Some("docs/api.md"),
+        Some(LineRange::line(3)),
+    );
+}
+
+#[test]
+fn web_link_unknown_host() {
+    let _ = env_logger::try_init();
+
+    // Picked from the path shape
+    assert_web_link(
+        "https://git.corp.example/g/s/r/-/blob/main/README.md",
+        "https://git.corp.example/g/s/r.git",
+        ProviderKind::GitLab,
+        WebPage::Blob,
+        Some("main"),
+        Some("README.md"),
+        None,
+    );
+    assert_web_link(
+        "https://tfs.corp.example/tfs/Collection/Project/_git/Repo?path=/x&version=GBdev",
+        "https://tfs.corp.example/tfs/Collection/Project/_git/Repo",
+        ProviderKind::AzureDevOps,
+        WebPage::Tree,
+        Some("dev"),
+        Some("x"),
+        None,
+    );
+    assert_web_link(
+        "https://corp.example/gitea/owner/repo/src/branch/main/README.md",
+        "https://corp.example/gitea/owner/repo.git",
+        ProviderKind::Gitea,
+        WebPage::Tree,
+        Some("main"),
+        Some("README.md"),
+        None,
+    );
+    assert_web_link(
+        "https://git.corp.example/owner/repo/blob/main/README.md",
+        "https://git.corp.example/owner/repo.git",
+        ProviderKind::GitHub,
+        WebPage::Blob,
+        Some("main"),
+        Some("README.md"),
+        None,
+    );
+
+    // IPv6 hosts, with and without a port
+    assert_web_link(
+        "https://[2001:db8::1]/g/s/r/-/blob/main/README.md",
+        "https://[2001:db8::1]/g/s/r.git",
+        ProviderKind::GitLab,
+        WebPage::Blob,
+        Some("main"),
+        Some("README.md"),
+        None,
+    );
+    assert_web_link(
+        "http://[::1]:3000/owner/repo/src/branch/main/README.md",
+        "http://[::1]:3000/owner/repo.git",
+        ProviderKind::Gitea,
+        WebPage::Tree,
+        Some("main"),
+        Some("README.md"),
+        None,
+    );
+
+    // Nothing to split
+    for link in [
+        "https://git.corp.example/owner/repo",
+        "git@github.com:owner/repo.git",
+        "https://github.com/owner",
+    ] {
+        let result = WebLink::parse(link);
+        debug!("{link}: {:?}", result);
+        assert!(result.is_err(), "{link} should not parse");
+    }
+}
+
+#[test]
+fn web_link_round_trip() {
+    let _ = env_logger::try_init();
+    let provider = GitLabProvider::new("group", &["sub"], "repo").expect("Constructor failed");
+    let target = WebTarget::Blob {
+        git_ref: "main",
+        path: "src/my file.rs",
+        lines: Some(LineRange::new(3, 7)),
+    };
+
+    let url = provider
+        .web_url(&repo_url("gitlab.com"), target)
+        .expect("Web url expected");
+    let link = WebLink::parse(&url).expect("Web link parse failed");
+
+    assert_eq!(link.git_ref(), Some("main"));
+    assert_eq!(link.path(), Some("src/my file.rs"));
+    assert_eq!(link.lines(), Some(LineRange::new(3, 7)));
+    assert_eq!(
+        link.clone_url().to_string(),
+        "https://gitlab.com/group/sub/repo.git"
+    );
+}
+
+#[test]
+fn raw_file_urls() {
+    let _ = env_logger::try_init();
+
+    let urls = [
+        (
+            "git@github.com:owner/repo.git",
+            "https://raw.githubusercontent.com/owner/repo/main/config/my%20app.toml",
+        ),
+        (
+            "https://raw.githubusercontent.com/owner/repo/v1.0/README.md",
+            "https://raw.githubusercontent.com/owner/repo/main/config/my%20app.toml",
+        ),
+        (
+            "https://api.github.com/repos/owner/repo",
+            "https://raw.githubusercontent.com/owner/repo/main/config/my%20app.toml",
+        ),
+        (
+            "https://GitHub.com/owner/repo.git",
+            "https://raw.githubusercontent.com/owner/repo/main/config/my%20app.toml",
+        ),
+        (
+            "https://ghe.corp.example/owner/repo.git",
+            "https://ghe.corp.example/raw/owner/repo/main/config/my%20app.toml",
+        ),
+        (
+            "https://gitlab.corp.example/group/sub/repo.git",
+            "https://gitlab.corp.example/group/sub/repo/-/raw/main/config/my%20app.toml",
+        ),
+        (
+            "https://bitbucket.org/workspace/repo.git",
+            "https://bitbucket.org/workspace/repo/raw/main/config/my%20app.toml",
+        ),
+        (
+            "https://bitbucket.corp.example/scm/PROJ/repo.git",
+            "https://bitbucket.corp.example/projects/PROJ/repos/repo/raw/config/my%20app.toml?at=main",
+        ),
+        (
+            "https://dev.azure.com/CompanyName/ProjectName/_git/RepoName",
+            "https://dev.azure.com/CompanyName/ProjectName/_apis/git/repositories/RepoName/items?path=/config/my%20app.toml&versionDescriptor.version=main&versionDescriptor.versionType=branch",
+        ),
+        (
+            "https://git.corp.example/gitea/owner/repo.git",
+            "https://git.corp.example/gitea/owner/repo/raw/branch/main/config/my%20app.toml",
+        ),
+        (
+            "https://bitbucket.corp.example/bitbucket/scm/PROJ/repo.git",
+            "https://bitbucket.corp.example/bitbucket/projects/PROJ/repos/repo/raw/config/my%20app.toml?at=main",
+        ),
+        (
+            "http://git.corp.example:3000/owner/repo.git",
+            "http://git.corp.example:3000/owner/repo/raw/branch/main/config/my%20app.toml",
+        ),
+        (
+            "https://ghe.corp.example:8443/owner/repo.git",
+            "https://ghe.corp.example:8443/raw/owner/repo/main/config/my%20app.toml",
+        ),
+        (
+            "ssh://git@gitlab.corp.example:2222/group/repo.git",
+            "https://gitlab.corp.example/group/repo/-/raw/main/config/my%20app.toml",
+        ),
+    ];
+
+    let mut registry = ProviderRegistry::new();
+    for (host, kind) in [
+        ("ghe.corp.example", ProviderKind::GitHub),
+        ("gitlab.corp.example", ProviderKind::GitLab),
+        ("bitbucket.corp.example", ProviderKind::BitbucketServer),
+        ("git.corp.example", ProviderKind::Gitea),
+    ] {
+        registry.register(host, kind).expect("Register failed");
+    }
+
+    for (url, expected) in urls {
+        let parsed = GitUrl::parse(url).expect("URL parse failed");
+        let detected = parsed
+            .detect_provider_with(&registry)
+            .expect("Provider expected");
+
+        let raw = match detected.provider() {
+            AnyProvider::GitHub(p) => p.raw_file_url(&parsed, "main", "config/my app.toml"),
+            AnyProvider::GitLab(p) => p.raw_file_url(&parsed, "main", "config/my app.toml"),
+            AnyProvider::BitbucketCloud(p) => p.raw_file_url(&parsed, "main", "config/my app.toml"),
+            AnyProvider::BitbucketServer(p) => {
+                p.raw_file_url(&parsed, "main", "config/my app.toml")
+            }
+            AnyProvider::AzureDevOps(p) => p.raw_file_url(&parsed, "main", "config/my app.toml"),
+            AnyProvider::Gitea(p) => p.raw_file_url(&parsed, "main", "config/my app.toml"),
+            other => panic!("Unexpected provider: {other:?}"),
+        };
+        debug!("{url}: {raw:?}");
+        assert_eq!(raw, Ok(expected.to_string()));
+    }
+
+    let parsed = GitUrl::parse("https://codeberg.org/owner/repo.git").expect("URL parse failed");
+    let provider: GiteaProvider = parsed
+        .provider_info()
+        .expect("Failed to parse provider info");
+    assert_eq!(
+        provider.raw_file_url(&parsed, "refs/tags/v1.0", "README.md"),
+        Ok("https://codeberg.org/owner/repo/raw/tag/v1.0/README.md".to_string())
+    );
+
+    let parsed = GitUrl::parse("https://github.com/owner/repo.wiki.git").expect("URL parse failed");
+    let provider: GitHubProvider = parsed
+        .provider_info()
+        .expect("Failed to parse provider info");
+    assert_eq!(
+        provider.raw_file_url(&parsed, "main", "Home.md"),
+        Err(GitUrlParseError::ProviderUnsupported)
+    );
+}